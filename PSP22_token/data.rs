@@ -1,11 +1,21 @@
 use crate::PSP22Error;
 use ink::prelude::string::String;
 use ink::{
+    env::hash::Blake2x256,
     prelude::{vec, vec::Vec},
     primitives::AccountId,
     storage::Mapping,
 };
 
+/// Domain separator prepended to every signed permit message.
+const PERMIT_DOMAIN: &[u8] = b"PSP22Permit";
+
+/// Account (`None` for the total supply) and index of a recorded snapshot value.
+type SnapshotKey = (Option<AccountId>, u32);
+
+/// Account (`None` for the total supply) and index of a votes checkpoint.
+type CheckpointKey = (Option<AccountId>, u32);
+
 /// Temporary type for events emitted during operations that change the
 /// state of PSP22Data struct.
 /// This is meant to be replaced with proper ink! events as soon as the
@@ -41,6 +51,16 @@ pub struct PSP22Data {
     total_supply: u128,
     balances: Mapping<AccountId, u128>,
     allowances: Mapping<(AccountId, AccountId), u128>,
+    nonces: Mapping<AccountId, u64>,
+    cap: Option<u128>,
+    // Id of the latest snapshot, 0 if no snapshot was taken yet
+    snapshot_id: u32,
+    // (account, index) -> (snapshot id, value), `None` account stands for the total supply.
+    // Values are recorded lazily: the entry of snapshot id `n` is written by the first
+    // change of the value after snapshot `n` was taken.
+    snapshots: Mapping<SnapshotKey, (u32, u128)>,
+    // Number of recorded snapshot entries of an account (or the total supply)
+    snapshot_counts: Mapping<Option<AccountId>, u32>,
 }
 
 impl PSP22Data {
@@ -50,11 +70,33 @@ impl PSP22Data {
             total_supply: supply,
             balances: Default::default(),
             allowances: Default::default(),
+            nonces: Default::default(),
+            cap: None,
+            snapshot_id: 0,
+            snapshots: Default::default(),
+            snapshot_counts: Default::default(),
         };
         data.balances.insert(creator, &supply);
         data
     }
 
+    /// Creates a token with `supply` balance, initially held by the `creator` account,
+    /// whose total supply can never be minted above `cap`.
+    ///
+    /// Fails with `CapExceeded` if `supply` is already above `cap`.
+    pub fn new_capped(
+        supply: u128,
+        creator: AccountId,
+        cap: u128,
+    ) -> Result<PSP22Data, PSP22Error> {
+        if supply > cap {
+            return Err(PSP22Error::CapExceeded);
+        }
+        let mut data = PSP22Data::new(supply, creator);
+        data.cap = Some(cap);
+        Ok(data)
+    }
+
     pub fn total_supply(&self) -> u128 {
         self.total_supply
     }
@@ -67,6 +109,33 @@ impl PSP22Data {
         self.allowances.get((owner, spender)).unwrap_or_default()
     }
 
+    /// Returns the maximal total supply, or `None` if the supply is not capped.
+    pub fn cap(&self) -> Option<u128> {
+        self.cap
+    }
+
+    /// Returns the number of permits already used by `owner`.
+    pub fn nonce(&self, owner: AccountId) -> u64 {
+        self.nonces.get(owner).unwrap_or_default()
+    }
+
+    /// Returns the hash `owner` has to sign to allow `spender` to spend `value` of its
+    /// tokens of the `token` contract with a permit, until the `deadline` block timestamp.
+    pub fn permit_message_hash(
+        token: AccountId,
+        owner: AccountId,
+        spender: AccountId,
+        value: u128,
+        nonce: u64,
+        deadline: u64,
+    ) -> [u8; 32] {
+        let message =
+            scale::Encode::encode(&(PERMIT_DOMAIN, token, owner, spender, value, nonce, deadline));
+        let mut hash = [0u8; 32];
+        ink::env::hash_bytes::<Blake2x256>(&message, &mut hash);
+        hash
+    }
+
     /// Transfers `value` tokens from `caller` to `to`.
     pub fn transfer(
         &mut self,
@@ -82,16 +151,10 @@ impl PSP22Data {
             return Err(PSP22Error::InsufficientBalance);
         }
 
-        if from_balance == value {
-            self.balances.remove(caller);
-        } else {
-            self.balances
-                .insert(caller, &(from_balance.saturating_sub(value)));
-        }
+        self.set_balance(caller, from_balance.saturating_sub(value));
         let to_balance = self.balance_of(to);
         // Total supply is limited by u128.MAX so no overflow is possible
-        self.balances
-            .insert(to, &(to_balance.saturating_add(value)));
+        self.set_balance(to, to_balance.saturating_add(value));
         Ok(vec![PSP22Event::Transfer {
             from: Some(caller),
             to: Some(to),
@@ -131,16 +194,10 @@ impl PSP22Data {
                 .insert((from, caller), &(allowance.saturating_sub(value)));
         }
 
-        if from_balance == value {
-            self.balances.remove(from);
-        } else {
-            self.balances
-                .insert(from, &(from_balance.saturating_sub(value)));
-        }
+        self.set_balance(from, from_balance.saturating_sub(value));
         let to_balance = self.balance_of(to);
         // Total supply is limited by u128.MAX so no overflow is possible
-        self.balances
-            .insert(to, &(to_balance.saturating_add(value)));
+        self.set_balance(to, to_balance.saturating_add(value));
         Ok(vec![
             PSP22Event::Approval {
                 owner: from,
@@ -178,6 +235,47 @@ impl PSP22Data {
         }])
     }
 
+    /// Sets a new `value` for allowance granted by `owner` to `spender`, authorized by
+    /// an ECDSA `signature` of `owner` over `permit_message_hash` with its current nonce.
+    ///
+    /// `token` is the address of the token contract the permit was signed for and `now`
+    /// the current block timestamp, which must not be after the `deadline`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn permit(
+        &mut self,
+        token: AccountId,
+        owner: AccountId,
+        spender: AccountId,
+        value: u128,
+        deadline: u64,
+        signature: [u8; 65],
+        now: u64,
+    ) -> Result<Vec<PSP22Event>, PSP22Error> {
+        if now > deadline {
+            return Err(PSP22Error::Custom(String::from("Permit expired")));
+        }
+        let nonce = self.nonce(owner);
+        let message_hash = Self::permit_message_hash(
+            token,
+            owner,
+            spender,
+            value,
+            nonce,
+            deadline,
+        );
+        let mut public_key = [0u8; 33];
+        ink::env::ecdsa_recover(&signature, &message_hash, &mut public_key)
+            .map_err(|_| PSP22Error::Custom(String::from("Invalid permit signature")))?;
+        // Accounts of ECDSA keys are the blake2 hash of the compressed public key
+        let mut signer = [0u8; 32];
+        ink::env::hash_bytes::<Blake2x256>(&public_key, &mut signer);
+        if AccountId::from(signer) != owner {
+            return Err(PSP22Error::Custom(String::from("Invalid permit signature")));
+        }
+        self.nonces.insert(owner, &(nonce.saturating_add(1)));
+        self.approve(owner, spender, value)
+    }
+
     /// Increases the allowance granted  by `owner` to `spender` by `delta_value`.
     pub fn increase_allowance(
         &mut self,
@@ -226,6 +324,8 @@ impl PSP22Data {
     }
 
     /// Mints a `value` of new tokens to `to` account.
+    ///
+    /// Fails with `CapExceeded` if the new total supply would be above the cap.
     pub fn mint(&mut self, to: AccountId, value: u128) -> Result<Vec<PSP22Event>, PSP22Error> {
         if value == 0 {
             return Ok(vec![]);
@@ -236,9 +336,12 @@ impl PSP22Data {
             .ok_or(PSP22Error::Custom(String::from(
                 "Max PSP22 supply exceeded. Max supply limited to 2^128-1.",
             )))?;
-        self.total_supply = new_supply;
+        if self.cap.is_some_and(|cap| new_supply > cap) {
+            return Err(PSP22Error::CapExceeded);
+        }
+        self.set_total_supply(new_supply);
         let new_balance = self.balance_of(to).saturating_add(value);
-        self.balances.insert(to, &new_balance);
+        self.set_balance(to, new_balance);
         Ok(vec![PSP22Event::Transfer {
             from: None,
             to: Some(to),
@@ -255,16 +358,384 @@ impl PSP22Data {
         if balance < value {
             return Err(PSP22Error::InsufficientBalance);
         }
-        if balance == value {
-            self.balances.remove(from);
-        } else {
-            self.balances.insert(from, &(balance.saturating_sub(value)));
-        }
-        self.total_supply = self.total_supply.saturating_sub(value);
+        self.set_balance(from, balance.saturating_sub(value));
+        self.set_total_supply(self.total_supply.saturating_sub(value));
         Ok(vec![PSP22Event::Transfer {
             from: Some(from),
             to: None,
             value,
         }])
     }
+
+    /// Burns `value` tokens from `from` account on behalf of `caller`, using and
+    /// decreasing the allowance given by `from` to `caller`.
+    pub fn burn_from(
+        &mut self,
+        caller: AccountId,
+        from: AccountId,
+        value: u128,
+    ) -> Result<Vec<PSP22Event>, PSP22Error> {
+        if value == 0 {
+            return Ok(vec![]);
+        }
+        if caller == from {
+            return self.burn(from, value);
+        }
+
+        let allowance = self.allowance(from, caller);
+        if allowance < value {
+            return Err(PSP22Error::InsufficientAllowance);
+        }
+        let mut events = self.burn(from, value)?;
+
+        if allowance == value {
+            self.allowances.remove((from, caller));
+        } else {
+            self.allowances
+                .insert((from, caller), &(allowance.saturating_sub(value)));
+        }
+        events.insert(
+            0,
+            PSP22Event::Approval {
+                owner: from,
+                spender: caller,
+                amount: allowance.saturating_sub(value),
+            },
+        );
+        Ok(events)
+    }
+
+    /// Takes a snapshot of all balances and of the total supply and returns its id.
+    /// Ids start at 1 and increase by 1 with every snapshot.
+    pub fn snapshot(&mut self) -> Result<u32, PSP22Error> {
+        let id = self
+            .snapshot_id
+            .checked_add(1)
+            .ok_or(PSP22Error::Custom(String::from("Max snapshot id exceeded")))?;
+        self.snapshot_id = id;
+        Ok(id)
+    }
+
+    /// Returns the id of the latest snapshot, 0 if no snapshot was taken yet.
+    pub fn current_snapshot_id(&self) -> u32 {
+        self.snapshot_id
+    }
+
+    /// Returns the balance of `owner` at the time snapshot `id` was taken,
+    /// or `None` if there is no such snapshot.
+    pub fn balance_of_at(&self, owner: AccountId, id: u32) -> Option<u128> {
+        self.value_at(Some(owner), id)
+            .map(|value| value.unwrap_or_else(|| self.balance_of(owner)))
+    }
+
+    /// Returns the total supply at the time snapshot `id` was taken,
+    /// or `None` if there is no such snapshot.
+    pub fn total_supply_at(&self, id: u32) -> Option<u128> {
+        self.value_at(None, id)
+            .map(|value| value.unwrap_or(self.total_supply))
+    }
+
+    // Returns `None` for a nonexistent snapshot and `Some(None)` if the value of `key`
+    // has not changed since snapshot `id` was taken.
+    fn value_at(&self, key: Option<AccountId>, id: u32) -> Option<Option<u128>> {
+        if id == 0 || id > self.snapshot_id {
+            return None;
+        }
+        // Binary search for the first entry recorded for a snapshot id >= `id`
+        let (mut low, mut high) = (0, self.snapshot_counts.get(key).unwrap_or_default());
+        while low < high {
+            let middle = low + (high - low) / 2;
+            match self.snapshots.get((key, middle)) {
+                Some((entry_id, _)) if entry_id < id => low = middle + 1,
+                _ => high = middle,
+            }
+        }
+        Some(self.snapshots.get((key, low)).map(|(_, value)| value))
+    }
+
+    // Records `current_value` of `key` for the latest snapshot, unless it is
+    // already recorded. Has to be called before the value changes.
+    fn update_snapshot(&mut self, key: Option<AccountId>, current_value: u128) {
+        if self.snapshot_id == 0 {
+            return;
+        }
+        let count = self.snapshot_counts.get(key).unwrap_or_default();
+        let last_id = match count.checked_sub(1) {
+            Some(last) => self.snapshots.get((key, last)).map(|(id, _)| id),
+            None => None,
+        };
+        if last_id.unwrap_or_default() < self.snapshot_id {
+            self.snapshots
+                .insert((key, count), &(self.snapshot_id, current_value));
+            self.snapshot_counts.insert(key, &(count.saturating_add(1)));
+        }
+    }
+
+    fn set_balance(&mut self, owner: AccountId, balance: u128) {
+        self.update_snapshot(Some(owner), self.balance_of(owner));
+        if balance == 0 {
+            self.balances.remove(owner);
+        } else {
+            self.balances.insert(owner, &balance);
+        }
+    }
+
+    fn set_total_supply(&mut self, total_supply: u128) {
+        self.update_snapshot(None, self.total_supply);
+        self.total_supply = total_supply;
+    }
+}
+
+/// Temporary type for events emitted when pausing or unpausing a token
+/// with PSP22PausableData, see `PSP22Event`.
+pub enum PSP22PausableEvent {
+    Paused { account: AccountId },
+    Unpaused { account: AccountId },
+}
+
+/// A class holding the paused state of a pausable PSP22 token.
+//
+/// It is meant to be stored next to PSP22Data: a contract calls
+/// `ensure_not_paused` before forwarding `transfer`, `transfer_from` and
+/// approval operations to PSP22Data.
+//
+/// Like `mint` and `burn` of PSP22Data, `pause` and `unpause` do not enforce
+/// any access control, restricting them is up to the contract.
+#[ink::storage_item]
+#[derive(Debug, Default)]
+pub struct PSP22PausableData {
+    paused: bool,
+}
+
+impl PSP22PausableData {
+    pub fn paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Fails with `Paused` if the token is paused.
+    pub fn ensure_not_paused(&self) -> Result<(), PSP22Error> {
+        if self.paused {
+            return Err(PSP22Error::Paused);
+        }
+        Ok(())
+    }
+
+    /// Pauses the token on behalf of `caller`.
+    pub fn pause(&mut self, caller: AccountId) -> Result<Vec<PSP22PausableEvent>, PSP22Error> {
+        self.ensure_not_paused()?;
+        self.paused = true;
+        Ok(vec![PSP22PausableEvent::Paused { account: caller }])
+    }
+
+    /// Unpauses the token on behalf of `caller`.
+    pub fn unpause(&mut self, caller: AccountId) -> Result<Vec<PSP22PausableEvent>, PSP22Error> {
+        if !self.paused {
+            return Err(PSP22Error::Custom(String::from("Token is not paused")));
+        }
+        self.paused = false;
+        Ok(vec![PSP22PausableEvent::Unpaused { account: caller }])
+    }
+}
+
+/// Temporary type for events emitted when voting power changes
+/// with PSP22VotesData, see `PSP22Event`.
+pub enum PSP22VotesEvent {
+    DelegateChanged {
+        delegator: AccountId,
+        from_delegate: Option<AccountId>,
+        to_delegate: AccountId,
+    },
+    DelegateVotesChanged {
+        delegate: AccountId,
+        previous_votes: u128,
+        new_votes: u128,
+    },
+}
+
+/// A class tracking the voting power of PSP22 token holders.
+//
+/// It is meant to be stored next to PSP22Data. Every holder can delegate
+/// the voting power of its whole balance to a delegate (possibly itself).
+/// Tokens of holders who have not delegated do not count as votes.
+//
+/// The votes of every delegate and the total supply are recorded in checkpoints
+/// (block timestamp, value), so past votes can be queried. The contract must call
+/// `move_voting_power` for every `Transfer` event returned by PSP22Data,
+/// i.e. on every transfer, mint and burn.
+#[ink::storage_item]
+#[derive(Debug, Default)]
+pub struct PSP22VotesData {
+    delegates: Mapping<AccountId, AccountId>,
+    // (delegate, index) -> (timestamp, votes), `None` delegate stands for the total supply
+    checkpoints: Mapping<CheckpointKey, (u64, u128)>,
+    // Number of checkpoints of a delegate (or the total supply)
+    checkpoint_counts: Mapping<Option<AccountId>, u32>,
+}
+
+impl PSP22VotesData {
+    /// Returns the delegate chosen by `account`, `None` if it has not delegated yet.
+    pub fn delegates(&self, account: AccountId) -> Option<AccountId> {
+        self.delegates.get(account)
+    }
+
+    /// Returns the current votes of `account`.
+    pub fn get_votes(&self, account: AccountId) -> u128 {
+        self.latest(Some(account))
+    }
+
+    /// Returns the votes of `account` at the end of the block with `timestamp`.
+    ///
+    /// Fails with `Custom (future lookup)` if `timestamp` is not in the past of `now`.
+    pub fn get_past_votes(
+        &self,
+        account: AccountId,
+        timestamp: u64,
+        now: u64,
+    ) -> Result<u128, PSP22Error> {
+        self.past(Some(account), timestamp, now)
+    }
+
+    /// Returns the total supply at the end of the block with `timestamp`.
+    ///
+    /// Fails with `Custom (future lookup)` if `timestamp` is not in the past of `now`.
+    pub fn get_past_total_supply(&self, timestamp: u64, now: u64) -> Result<u128, PSP22Error> {
+        self.past(None, timestamp, now)
+    }
+
+    /// Delegates the voting power of `delegator`, whose current balance is `balance`,
+    /// to `delegatee`.
+    pub fn delegate(
+        &mut self,
+        delegator: AccountId,
+        delegatee: AccountId,
+        balance: u128,
+        now: u64,
+    ) -> Vec<PSP22VotesEvent> {
+        let from_delegate = self.delegates(delegator);
+        self.delegates.insert(delegator, &delegatee);
+
+        let mut events = vec![PSP22VotesEvent::DelegateChanged {
+            delegator,
+            from_delegate,
+            to_delegate: delegatee,
+        }];
+        events.extend(self.move_delegate_votes(from_delegate, Some(delegatee), balance, now));
+        events
+    }
+
+    /// Moves the voting power of `value` tokens transferred from `from` to `to`,
+    /// where `None` stands for minting or burning.
+    pub fn move_voting_power(
+        &mut self,
+        from: Option<AccountId>,
+        to: Option<AccountId>,
+        value: u128,
+        now: u64,
+    ) -> Vec<PSP22VotesEvent> {
+        if from.is_none() {
+            // Total supply is limited by u128.MAX so no overflow is possible
+            let supply = self.latest(None).saturating_add(value);
+            self.push_checkpoint(None, supply, now);
+        }
+        if to.is_none() {
+            let supply = self.latest(None).saturating_sub(value);
+            self.push_checkpoint(None, supply, now);
+        }
+        let from_delegate = from.and_then(|account| self.delegates(account));
+        let to_delegate = to.and_then(|account| self.delegates(account));
+        self.move_delegate_votes(from_delegate, to_delegate, value, now)
+    }
+
+    fn move_delegate_votes(
+        &mut self,
+        from: Option<AccountId>,
+        to: Option<AccountId>,
+        value: u128,
+        now: u64,
+    ) -> Vec<PSP22VotesEvent> {
+        let mut events = vec![];
+        if from == to || value == 0 {
+            return events;
+        }
+        if let Some(delegate) = from {
+            let previous_votes = self.latest(Some(delegate));
+            let new_votes = previous_votes.saturating_sub(value);
+            self.push_checkpoint(Some(delegate), new_votes, now);
+            events.push(PSP22VotesEvent::DelegateVotesChanged {
+                delegate,
+                previous_votes,
+                new_votes,
+            });
+        }
+        if let Some(delegate) = to {
+            let previous_votes = self.latest(Some(delegate));
+            // Total supply is limited by u128.MAX so no overflow is possible
+            let new_votes = previous_votes.saturating_add(value);
+            self.push_checkpoint(Some(delegate), new_votes, now);
+            events.push(PSP22VotesEvent::DelegateVotesChanged {
+                delegate,
+                previous_votes,
+                new_votes,
+            });
+        }
+        events
+    }
+
+    fn latest(&self, key: Option<AccountId>) -> u128 {
+        match self
+            .checkpoint_counts
+            .get(key)
+            .unwrap_or_default()
+            .checked_sub(1)
+        {
+            Some(last) => self
+                .checkpoints
+                .get((key, last))
+                .map(|(_, value)| value)
+                .unwrap_or_default(),
+            None => 0,
+        }
+    }
+
+    fn past(&self, key: Option<AccountId>, timestamp: u64, now: u64) -> Result<u128, PSP22Error> {
+        if timestamp >= now {
+            return Err(PSP22Error::Custom(String::from("Future lookup")));
+        }
+        // Binary search for the first checkpoint written after `timestamp`
+        let (mut low, mut high) = (0, self.checkpoint_counts.get(key).unwrap_or_default());
+        while low < high {
+            let middle = low + (high - low) / 2;
+            match self.checkpoints.get((key, middle)) {
+                Some((checkpoint_timestamp, _)) if checkpoint_timestamp <= timestamp => {
+                    low = middle + 1
+                }
+                _ => high = middle,
+            }
+        }
+        match low.checked_sub(1) {
+            Some(index) => Ok(self
+                .checkpoints
+                .get((key, index))
+                .map(|(_, value)| value)
+                .unwrap_or_default()),
+            None => Ok(0),
+        }
+    }
+
+    // Records `value` of `key` at `now`, overwriting the last checkpoint if it was
+    // written in the same block.
+    fn push_checkpoint(&mut self, key: Option<AccountId>, value: u128, now: u64) {
+        let count = self.checkpoint_counts.get(key).unwrap_or_default();
+        if let Some(last) = count.checked_sub(1) {
+            if let Some((timestamp, _)) = self.checkpoints.get((key, last)) {
+                if timestamp == now {
+                    self.checkpoints.insert((key, last), &(now, value));
+                    return;
+                }
+            }
+        }
+        self.checkpoints.insert((key, count), &(now, value));
+        self.checkpoint_counts
+            .insert(key, &(count.saturating_add(1)));
+    }
 }
@@ -15,4 +15,8 @@ pub enum PSP22Error {
     ZeroSenderAddress,
     /// Returned if a safe transfer check failed [deprecated].
     SafeTransferCheckFailed(String),
+    /// Returned if minting would raise the total supply above the cap of a capped token.
+    CapExceeded,
+    /// Returned if the operation is not allowed because the token is paused.
+    Paused,
 }
@@ -2,12 +2,22 @@
 
 mod data;
 mod errors;
+mod pausable;
 mod testing;
 mod traits;
 
-pub use data::{PSP22Data, PSP22Event};
+pub use data::{
+    PSP22Data, PSP22Event, PSP22PausableData, PSP22PausableEvent, PSP22VotesData, PSP22VotesEvent,
+};
 pub use errors::PSP22Error;
-pub use traits::{PSP22Burnable, PSP22Metadata, PSP22Mintable, PSP22};
+pub use traits::{
+    PSP22Burnable, PSP22Capped, PSP22Metadata, PSP22Mintable, PSP22Pausable, PSP22Permit,
+    PSP22Snapshot, PSP22Votes, PSP22,
+};
+
+// Only one ink! contract can be compiled into a single crate.
+#[cfg(all(feature = "contract", feature = "pausable"))]
+compile_error!("only one of features \"contract\" and \"pausable\" can be enabled");
 
 // An example code of a smart contract using PSP22Data struct to implement
 // the functionality of PSP22 fungible token.
@@ -24,7 +34,7 @@ pub use traits::{PSP22Burnable, PSP22Metadata, PSP22Mintable, PSP22};
 #[cfg(feature = "contract")]
 #[ink::contract]
 mod token {
-    use crate::{PSP22Data, PSP22Error, PSP22Event, PSP22Metadata, PSP22};
+    use crate::{PSP22Data, PSP22Error, PSP22Event, PSP22Metadata, PSP22Permit, PSP22};
     use ink::prelude::{string::String, vec::Vec};
 
     #[ink(storage)]
@@ -188,9 +198,166 @@ mod token {
         }
     }
 
+    impl PSP22Permit for Token {
+        #[ink(message)]
+        fn nonces(&self, owner: AccountId) -> u64 {
+            self.data.nonce(owner)
+        }
+
+        #[ink(message)]
+        fn permit(
+            &mut self,
+            owner: AccountId,
+            spender: AccountId,
+            value: u128,
+            deadline: u64,
+            signature: [u8; 65],
+        ) -> Result<(), PSP22Error> {
+            let events = self.data.permit(
+                self.env().account_id(),
+                owner,
+                spender,
+                value,
+                deadline,
+                signature,
+                self.env().block_timestamp(),
+            )?;
+            self.emit_events(events);
+            Ok(())
+        }
+    }
+
     // (7)
     #[cfg(test)]
     mod tests {
         crate::tests!(Token, (|supply| Token::new(supply, None, None, 0)));
+
+        mod permit_unit_tests {
+            use super::super::*;
+            use ink::env::{hash::Blake2x256, test::*, DefaultEnvironment as E};
+            use secp256k1::{Message, PublicKey, Secp256k1, SecretKey};
+
+            const SECRET: [u8; 32] = [7; 32];
+
+            // Returns the account of the ECDSA key derived from `SECRET`
+            fn signer() -> AccountId {
+                let public_key = PublicKey::from_secret_key(
+                    &Secp256k1::new(),
+                    &SecretKey::from_slice(&SECRET).unwrap(),
+                );
+                let mut account = [0u8; 32];
+                ink::env::hash_bytes::<Blake2x256>(&public_key.serialize(), &mut account);
+                AccountId::from(account)
+            }
+
+            // Signs a permit of `signer()` for the currently executed token contract
+            fn sign_permit(spender: AccountId, value: u128, nonce: u64, deadline: u64) -> [u8; 65] {
+                let hash = PSP22Data::permit_message_hash(
+                    callee::<E>(),
+                    signer(),
+                    spender,
+                    value,
+                    nonce,
+                    deadline,
+                );
+                let (recovery_id, compact) = Secp256k1::new()
+                    .sign_ecdsa_recoverable(
+                        &Message::from_slice(&hash).unwrap(),
+                        &SecretKey::from_slice(&SECRET).unwrap(),
+                    )
+                    .serialize_compact();
+                let mut signature = [0u8; 65];
+                signature[..64].copy_from_slice(&compact);
+                signature[64] = recovery_id.to_i32() as u8;
+                signature
+            }
+
+            #[ink::test]
+            fn permit_works() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(signer());
+                let (supply, value) = (1000, 100);
+                let mut token = Token::new(supply, None, None, 0);
+
+                let signature = sign_permit(acc.bob, value, 0, 10);
+                set_caller::<E>(acc.charlie);
+                let start = recorded_events().count();
+                assert!(token
+                    .permit(signer(), acc.bob, value, 10, signature)
+                    .is_ok());
+
+                assert_eq!(token.allowance(signer(), acc.bob), value);
+                assert_eq!(token.nonces(signer()), 1);
+                assert_eq!(recorded_events().count(), start + 1);
+
+                set_caller::<E>(acc.bob);
+                assert!(token
+                    .transfer_from(signer(), acc.bob, value, vec![])
+                    .is_ok());
+                assert_eq!(token.balance_of(acc.bob), value);
+            }
+
+            #[ink::test]
+            fn permit_cannot_be_replayed() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(signer());
+                let mut token = Token::new(1000, None, None, 0);
+
+                let signature = sign_permit(acc.bob, 100, 0, 10);
+                assert!(token.permit(signer(), acc.bob, 100, 10, signature).is_ok());
+                assert!(token.approve(acc.bob, 0).is_ok());
+
+                assert_eq!(
+                    token.permit(signer(), acc.bob, 100, 10, signature),
+                    Err(PSP22Error::Custom(String::from("Invalid permit signature")))
+                );
+                assert_eq!(token.allowance(signer(), acc.bob), 0);
+            }
+
+            #[ink::test]
+            fn permit_fails_after_deadline() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(signer());
+                let mut token = Token::new(1000, None, None, 0);
+
+                let signature = sign_permit(acc.bob, 100, 0, 10);
+                set_block_timestamp::<E>(11);
+
+                assert_eq!(
+                    token.permit(signer(), acc.bob, 100, 10, signature),
+                    Err(PSP22Error::Custom(String::from("Permit expired")))
+                );
+                assert_eq!(token.nonces(signer()), 0);
+            }
+
+            #[ink::test]
+            fn permit_fails_for_another_owner() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let mut token = Token::new(1000, None, None, 0);
+
+                let signature = sign_permit(acc.bob, 100, 0, 10);
+
+                assert_eq!(
+                    token.permit(acc.alice, acc.bob, 100, 10, signature),
+                    Err(PSP22Error::Custom(String::from("Invalid permit signature")))
+                );
+                assert_eq!(token.allowance(acc.alice, acc.bob), 0);
+            }
+
+            #[ink::test]
+            fn permit_fails_for_another_value() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(signer());
+                let mut token = Token::new(1000, None, None, 0);
+
+                let signature = sign_permit(acc.bob, 100, 0, 10);
+
+                assert!(token
+                    .permit(signer(), acc.bob, 1000, 10, signature)
+                    .is_err());
+                assert_eq!(token.allowance(signer(), acc.bob), 0);
+            }
+        }
     }
 }
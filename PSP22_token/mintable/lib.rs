@@ -0,0 +1,409 @@
+// A variant of the example token contract implementing also the PSP22Mintable
+// and PSP22Burnable extensions.
+//
+// Minting is restricted to a single minter account (the creator of the token
+// at first), which can hand the role over with `set_minter`. Any holder can burn
+// its own tokens, and `burn_from` lets an approved spender burn tokens of an
+// owner using the owner's allowance. The total supply can optionally be capped,
+// in which case minting above the cap fails with `CapExceeded`.
+//
+// The minter can also take snapshots of all balances and of the total supply,
+// e.g. for voting or airdrops based on historical balances.
+//
+// Holders can delegate their voting power with the PSP22Votes extension,
+// which makes the token usable for on-chain governance like PANX.
+//
+// It is a contract crate of its own, depending on the psp22 crate, so it can be
+// built without features and deployed by end-to-end tests of other contracts:
+// $ cargo contract build --release --manifest-path mintable/Cargo.toml
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[ink::contract]
+pub mod mintable_token {
+    use psp22::{
+        PSP22Burnable, PSP22Capped, PSP22Data, PSP22Error, PSP22Event, PSP22Metadata,
+        PSP22Mintable, PSP22Snapshot, PSP22Votes, PSP22VotesData, PSP22VotesEvent, PSP22,
+    };
+    use ink::prelude::{string::String, vec::Vec};
+
+    #[ink(storage)]
+    pub struct MintableToken {
+        data: PSP22Data,
+        votes: PSP22VotesData,
+        name: Option<String>,
+        symbol: Option<String>,
+        decimals: u8,
+        minter: AccountId, // the only account allowed to mint tokens
+    }
+
+    impl MintableToken {
+        /// Creates a token with `supply` tokens held by the caller, which becomes the minter.
+        ///
+        /// If `cap` is given, the total supply can never be minted above it and the
+        /// constructor fails with `CapExceeded` if `supply` is already above it.
+        #[ink(constructor)]
+        pub fn new(
+            supply: u128,
+            name: Option<String>,
+            symbol: Option<String>,
+            decimals: u8,
+            cap: Option<u128>,
+        ) -> Result<Self, PSP22Error> {
+            let data = match cap {
+                Some(cap) => PSP22Data::new_capped(supply, Self::env().caller(), cap)?,
+                None => PSP22Data::new(supply, Self::env().caller()),
+            };
+            let mut token = Self {
+                data,
+                votes: Default::default(),
+                name,
+                symbol,
+                decimals,
+                minter: Self::env().caller(),
+            };
+            // The initial supply is not minted with a Transfer event, record it for votes
+            token.votes.move_voting_power(
+                None,
+                Some(Self::env().caller()),
+                supply,
+                Self::env().block_timestamp(),
+            );
+            Ok(token)
+        }
+
+        /// Returns the account allowed to mint tokens.
+        #[ink(message)]
+        pub fn minter(&self) -> AccountId {
+            self.minter
+        }
+
+        /// Hands the minter role over to `new_minter`.
+        ///
+        /// Reverts with `Custom (caller is not the minter)` if the caller is not the current minter.
+        #[ink(message)]
+        pub fn set_minter(&mut self, new_minter: AccountId) -> Result<(), PSP22Error> {
+            self.ensure_minter()?;
+            self.minter = new_minter;
+            Ok(())
+        }
+
+        /// Burns `value` tokens from `from` account, using the allowance given by `from` to the caller.
+        ///
+        /// On success an `Approval` event with the decreased allowance and a `Transfer` event with
+        /// `None` recipient are emitted. If the caller is `from`, it behaves like `burn`.
+        ///
+        /// No-op if `value` is zero, returns success and no events are emitted.
+        ///
+        /// Reverts with `InsufficientAllowance` if `value` exceeds the allowance, and with
+        /// `InsufficientBalance` if `value` exceeds the balance of `from`.
+        #[ink(message)]
+        pub fn burn_from(&mut self, from: AccountId, value: u128) -> Result<(), PSP22Error> {
+            let events = self.data.burn_from(self.env().caller(), from, value)?;
+            self.emit_events(events);
+            Ok(())
+        }
+
+        fn ensure_minter(&self) -> Result<(), PSP22Error> {
+            if self.env().caller() != self.minter {
+                return Err(PSP22Error::Custom(String::from("Caller is not the minter")));
+            }
+            Ok(())
+        }
+
+        fn emit_events(&mut self, events: Vec<PSP22Event>) {
+            for event in events {
+                match event {
+                    PSP22Event::Transfer { from, to, value } => {
+                        self.env().emit_event(Transfer { from, to, value });
+                        let votes_events = self.votes.move_voting_power(
+                            from,
+                            to,
+                            value,
+                            self.env().block_timestamp(),
+                        );
+                        self.emit_votes_events(votes_events);
+                    }
+                    PSP22Event::Approval {
+                        owner,
+                        spender,
+                        amount,
+                    } => self.env().emit_event(Approval {
+                        owner,
+                        spender,
+                        amount,
+                    }),
+                }
+            }
+        }
+
+        fn emit_votes_events(&self, events: Vec<PSP22VotesEvent>) {
+            for event in events {
+                match event {
+                    PSP22VotesEvent::DelegateChanged {
+                        delegator,
+                        from_delegate,
+                        to_delegate,
+                    } => self.env().emit_event(DelegateChanged {
+                        delegator,
+                        from_delegate,
+                        to_delegate,
+                    }),
+                    PSP22VotesEvent::DelegateVotesChanged {
+                        delegate,
+                        previous_votes,
+                        new_votes,
+                    } => self.env().emit_event(DelegateVotesChanged {
+                        delegate,
+                        previous_votes,
+                        new_votes,
+                    }),
+                }
+            }
+        }
+    }
+
+    #[ink(event)]
+    pub struct Approval {
+        #[ink(topic)]
+        owner: AccountId,
+        #[ink(topic)]
+        spender: AccountId,
+        amount: u128,
+    }
+
+    #[ink(event)]
+    pub struct Transfer {
+        #[ink(topic)]
+        from: Option<AccountId>,
+        #[ink(topic)]
+        to: Option<AccountId>,
+        value: u128,
+    }
+
+    #[ink(event)]
+    pub struct Snapshot {
+        id: u32,
+    }
+
+    #[ink(event)]
+    pub struct DelegateChanged {
+        #[ink(topic)]
+        delegator: AccountId,
+        #[ink(topic)]
+        from_delegate: Option<AccountId>,
+        #[ink(topic)]
+        to_delegate: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct DelegateVotesChanged {
+        #[ink(topic)]
+        delegate: AccountId,
+        previous_votes: u128,
+        new_votes: u128,
+    }
+
+    impl PSP22 for MintableToken {
+        #[ink(message)]
+        fn total_supply(&self) -> u128 {
+            self.data.total_supply()
+        }
+
+        #[ink(message)]
+        fn balance_of(&self, owner: AccountId) -> u128 {
+            self.data.balance_of(owner)
+        }
+
+        #[ink(message)]
+        fn allowance(&self, owner: AccountId, spender: AccountId) -> u128 {
+            self.data.allowance(owner, spender)
+        }
+
+        #[ink(message)]
+        fn transfer(
+            &mut self,
+            to: AccountId,
+            value: u128,
+            _data: Vec<u8>,
+        ) -> Result<(), PSP22Error> {
+            let events = self.data.transfer(self.env().caller(), to, value)?;
+            self.emit_events(events);
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            value: u128,
+            _data: Vec<u8>,
+        ) -> Result<(), PSP22Error> {
+            let events = self
+                .data
+                .transfer_from(self.env().caller(), from, to, value)?;
+            self.emit_events(events);
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn approve(&mut self, spender: AccountId, value: u128) -> Result<(), PSP22Error> {
+            let events = self.data.approve(self.env().caller(), spender, value)?;
+            self.emit_events(events);
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn increase_allowance(
+            &mut self,
+            spender: AccountId,
+            delta_value: u128,
+        ) -> Result<(), PSP22Error> {
+            let events = self
+                .data
+                .increase_allowance(self.env().caller(), spender, delta_value)?;
+            self.emit_events(events);
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn decrease_allowance(
+            &mut self,
+            spender: AccountId,
+            delta_value: u128,
+        ) -> Result<(), PSP22Error> {
+            let events = self
+                .data
+                .decrease_allowance(self.env().caller(), spender, delta_value)?;
+            self.emit_events(events);
+            Ok(())
+        }
+    }
+
+    impl PSP22Metadata for MintableToken {
+        #[ink(message)]
+        fn token_name(&self) -> Option<String> {
+            self.name.clone()
+        }
+        #[ink(message)]
+        fn token_symbol(&self) -> Option<String> {
+            self.symbol.clone()
+        }
+        #[ink(message)]
+        fn token_decimals(&self) -> u8 {
+            self.decimals
+        }
+    }
+
+    impl PSP22Mintable for MintableToken {
+        #[ink(message)]
+        fn mint(&mut self, value: u128) -> Result<(), PSP22Error> {
+            self.ensure_minter()?;
+            let events = self.data.mint(self.env().caller(), value)?;
+            self.emit_events(events);
+            Ok(())
+        }
+    }
+
+    impl PSP22Capped for MintableToken {
+        #[ink(message)]
+        fn cap(&self) -> Option<u128> {
+            self.data.cap()
+        }
+    }
+
+    impl PSP22Snapshot for MintableToken {
+        #[ink(message)]
+        fn snapshot(&mut self) -> Result<u32, PSP22Error> {
+            self.ensure_minter()?;
+            let id = self.data.snapshot()?;
+            self.env().emit_event(Snapshot { id });
+            Ok(id)
+        }
+
+        #[ink(message)]
+        fn current_snapshot_id(&self) -> u32 {
+            self.data.current_snapshot_id()
+        }
+
+        #[ink(message)]
+        fn balance_of_at(&self, owner: AccountId, id: u32) -> Option<u128> {
+            self.data.balance_of_at(owner, id)
+        }
+
+        #[ink(message)]
+        fn total_supply_at(&self, id: u32) -> Option<u128> {
+            self.data.total_supply_at(id)
+        }
+    }
+
+    impl PSP22Votes for MintableToken {
+        #[ink(message)]
+        fn delegates(&self, account: AccountId) -> Option<AccountId> {
+            self.votes.delegates(account)
+        }
+
+        #[ink(message)]
+        fn get_votes(&self, account: AccountId) -> u128 {
+            self.votes.get_votes(account)
+        }
+
+        #[ink(message)]
+        fn get_past_votes(&self, account: AccountId, timestamp: u64) -> Result<u128, PSP22Error> {
+            self.votes
+                .get_past_votes(account, timestamp, self.env().block_timestamp())
+        }
+
+        #[ink(message)]
+        fn get_past_total_supply(&self, timestamp: u64) -> Result<u128, PSP22Error> {
+            self.votes
+                .get_past_total_supply(timestamp, self.env().block_timestamp())
+        }
+
+        #[ink(message)]
+        fn delegate(&mut self, delegatee: AccountId) -> Result<(), PSP22Error> {
+            let caller = self.env().caller();
+            let events = self.votes.delegate(
+                caller,
+                delegatee,
+                self.data.balance_of(caller),
+                self.env().block_timestamp(),
+            );
+            self.emit_votes_events(events);
+            Ok(())
+        }
+    }
+
+    impl PSP22Burnable for MintableToken {
+        #[ink(message)]
+        fn burn(&mut self, value: u128) -> Result<(), PSP22Error> {
+            let events = self.data.burn(self.env().caller(), value)?;
+            self.emit_events(events);
+            Ok(())
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        psp22::tests!(
+            MintableToken,
+            (|supply| MintableToken::new(supply, None, None, 0, None).unwrap())
+        );
+        psp22::mintable_tests!(
+            MintableToken,
+            (|supply| MintableToken::new(supply, None, None, 0, None).unwrap())
+        );
+        psp22::snapshot_tests!(
+            MintableToken,
+            (|supply| MintableToken::new(supply, None, None, 0, None).unwrap())
+        );
+        psp22::votes_tests!(
+            MintableToken,
+            (|supply| MintableToken::new(supply, None, None, 0, None).unwrap())
+        );
+        psp22::capped_tests!(
+            MintableToken,
+            (|supply, cap| MintableToken::new(supply, None, None, 0, cap))
+        );
+    }
+}
@@ -0,0 +1,264 @@
+// A variant of the example token contract implementing also the PSP22Pausable
+// extension, meant for incident response.
+//
+// A single pauser account (the creator of the token at first), which can hand
+// the role over with `set_pauser`, can pause the token. While paused, all
+// transfers and allowance changes fail with `Paused`, queries keep working.
+//
+// Compile it with the `"pausable"` feature instead of the `"contract"` one:
+// $ cargo contract build --release --features "pausable"
+#[cfg(feature = "pausable")]
+#[ink::contract]
+mod pausable_token {
+    use crate::{
+        PSP22Data, PSP22Error, PSP22Event, PSP22Metadata, PSP22Pausable, PSP22PausableData,
+        PSP22PausableEvent, PSP22,
+    };
+    use ink::prelude::{string::String, vec::Vec};
+
+    #[ink(storage)]
+    pub struct PausableToken {
+        data: PSP22Data,
+        pausable: PSP22PausableData,
+        name: Option<String>,
+        symbol: Option<String>,
+        decimals: u8,
+        pauser: AccountId, // the only account allowed to pause and unpause the token
+    }
+
+    impl PausableToken {
+        #[ink(constructor)]
+        pub fn new(
+            supply: u128,
+            name: Option<String>,
+            symbol: Option<String>,
+            decimals: u8,
+        ) -> Self {
+            Self {
+                data: PSP22Data::new(supply, Self::env().caller()),
+                pausable: Default::default(),
+                name,
+                symbol,
+                decimals,
+                pauser: Self::env().caller(),
+            }
+        }
+
+        /// Returns the account allowed to pause and unpause the token.
+        #[ink(message)]
+        pub fn pauser(&self) -> AccountId {
+            self.pauser
+        }
+
+        /// Hands the pauser role over to `new_pauser`.
+        ///
+        /// Reverts with `Custom (caller is not the pauser)` if the caller is not the current pauser.
+        #[ink(message)]
+        pub fn set_pauser(&mut self, new_pauser: AccountId) -> Result<(), PSP22Error> {
+            self.ensure_pauser()?;
+            self.pauser = new_pauser;
+            Ok(())
+        }
+
+        fn ensure_pauser(&self) -> Result<(), PSP22Error> {
+            if self.env().caller() != self.pauser {
+                return Err(PSP22Error::Custom(String::from("Caller is not the pauser")));
+            }
+            Ok(())
+        }
+
+        fn emit_events(&self, events: Vec<PSP22Event>) {
+            for event in events {
+                match event {
+                    PSP22Event::Transfer { from, to, value } => {
+                        self.env().emit_event(Transfer { from, to, value })
+                    }
+                    PSP22Event::Approval {
+                        owner,
+                        spender,
+                        amount,
+                    } => self.env().emit_event(Approval {
+                        owner,
+                        spender,
+                        amount,
+                    }),
+                }
+            }
+        }
+
+        fn emit_pausable_events(&self, events: Vec<PSP22PausableEvent>) {
+            for event in events {
+                match event {
+                    PSP22PausableEvent::Paused { account } => {
+                        self.env().emit_event(Paused { account })
+                    }
+                    PSP22PausableEvent::Unpaused { account } => {
+                        self.env().emit_event(Unpaused { account })
+                    }
+                }
+            }
+        }
+    }
+
+    #[ink(event)]
+    pub struct Approval {
+        #[ink(topic)]
+        owner: AccountId,
+        #[ink(topic)]
+        spender: AccountId,
+        amount: u128,
+    }
+
+    #[ink(event)]
+    pub struct Transfer {
+        #[ink(topic)]
+        from: Option<AccountId>,
+        #[ink(topic)]
+        to: Option<AccountId>,
+        value: u128,
+    }
+
+    #[ink(event)]
+    pub struct Paused {
+        #[ink(topic)]
+        account: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct Unpaused {
+        #[ink(topic)]
+        account: AccountId,
+    }
+
+    impl PSP22 for PausableToken {
+        #[ink(message)]
+        fn total_supply(&self) -> u128 {
+            self.data.total_supply()
+        }
+
+        #[ink(message)]
+        fn balance_of(&self, owner: AccountId) -> u128 {
+            self.data.balance_of(owner)
+        }
+
+        #[ink(message)]
+        fn allowance(&self, owner: AccountId, spender: AccountId) -> u128 {
+            self.data.allowance(owner, spender)
+        }
+
+        #[ink(message)]
+        fn transfer(
+            &mut self,
+            to: AccountId,
+            value: u128,
+            _data: Vec<u8>,
+        ) -> Result<(), PSP22Error> {
+            self.pausable.ensure_not_paused()?;
+            let events = self.data.transfer(self.env().caller(), to, value)?;
+            self.emit_events(events);
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            value: u128,
+            _data: Vec<u8>,
+        ) -> Result<(), PSP22Error> {
+            self.pausable.ensure_not_paused()?;
+            let events = self
+                .data
+                .transfer_from(self.env().caller(), from, to, value)?;
+            self.emit_events(events);
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn approve(&mut self, spender: AccountId, value: u128) -> Result<(), PSP22Error> {
+            self.pausable.ensure_not_paused()?;
+            let events = self.data.approve(self.env().caller(), spender, value)?;
+            self.emit_events(events);
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn increase_allowance(
+            &mut self,
+            spender: AccountId,
+            delta_value: u128,
+        ) -> Result<(), PSP22Error> {
+            self.pausable.ensure_not_paused()?;
+            let events = self
+                .data
+                .increase_allowance(self.env().caller(), spender, delta_value)?;
+            self.emit_events(events);
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn decrease_allowance(
+            &mut self,
+            spender: AccountId,
+            delta_value: u128,
+        ) -> Result<(), PSP22Error> {
+            self.pausable.ensure_not_paused()?;
+            let events = self
+                .data
+                .decrease_allowance(self.env().caller(), spender, delta_value)?;
+            self.emit_events(events);
+            Ok(())
+        }
+    }
+
+    impl PSP22Metadata for PausableToken {
+        #[ink(message)]
+        fn token_name(&self) -> Option<String> {
+            self.name.clone()
+        }
+        #[ink(message)]
+        fn token_symbol(&self) -> Option<String> {
+            self.symbol.clone()
+        }
+        #[ink(message)]
+        fn token_decimals(&self) -> u8 {
+            self.decimals
+        }
+    }
+
+    impl PSP22Pausable for PausableToken {
+        #[ink(message)]
+        fn paused(&self) -> bool {
+            self.pausable.paused()
+        }
+
+        #[ink(message)]
+        fn pause(&mut self) -> Result<(), PSP22Error> {
+            self.ensure_pauser()?;
+            let events = self.pausable.pause(self.env().caller())?;
+            self.emit_pausable_events(events);
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn unpause(&mut self) -> Result<(), PSP22Error> {
+            self.ensure_pauser()?;
+            let events = self.pausable.unpause(self.env().caller())?;
+            self.emit_pausable_events(events);
+            Ok(())
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        crate::tests!(
+            PausableToken,
+            (|supply| PausableToken::new(supply, None, None, 0))
+        );
+        crate::pausable_tests!(
+            PausableToken,
+            (|supply| PausableToken::new(supply, None, None, 0))
+        );
+    }
+}
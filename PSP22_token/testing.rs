@@ -627,3 +627,874 @@ macro_rules! tests {
         }
     };
 }
+
+/// Inserts a suite of ink! unit tests intended for a contract implementing PSP22Mintable and
+/// PSP22Burnable traits with a single minter (the creator of the token), a `set_minter(AccountId)`
+/// message handing the minter role over, and a `burn_from(AccountId, u128)` message burning
+/// tokens with an allowance.
+/// Arguments and placement are the same as for the `tests!` macro.
+#[macro_export]
+macro_rules! mintable_tests {
+    ($contract:ident, $constructor:expr) => {
+        mod psp22_mintable_unit_tests {
+            use super::super::*;
+            use ink::env::{test::*, DefaultEnvironment as E};
+
+            type Event = <$contract as ink::reflect::ContractEventBase>::Type;
+
+            // Gathers all emitted events, skip `shift` first, decode the rest and return as vector
+            fn decode_events(shift: usize) -> Vec<Event> {
+                recorded_events()
+                    .skip(shift)
+                    .map(|e| <Event as scale::Decode>::decode(&mut &e.data[..]).unwrap())
+                    .collect()
+            }
+
+            // Asserts if the given event is a Transfer with particular from_, to_ and value_
+            fn assert_transfer(
+                event: &Event,
+                from_: Option<AccountId>,
+                to_: Option<AccountId>,
+                value_: u128,
+            ) {
+                if let Event::Transfer(Transfer { from, to, value }) = event {
+                    assert_eq!(*from, from_, "Transfer event: 'from' mismatch");
+                    assert_eq!(*to, to_, "Transfer event: 'to' mismatch");
+                    assert_eq!(*value, value_, "Transfer event: 'value' mismatch");
+                } else {
+                    panic!("Event is not Transfer")
+                }
+            }
+
+            // Asserts if the given event is a Approval with particular owner_, spender_ and amount_
+            fn assert_approval(
+                event: &Event,
+                owner_: AccountId,
+                spender_: AccountId,
+                amount_: u128,
+            ) {
+                if let Event::Approval(Approval {
+                    owner,
+                    spender,
+                    amount,
+                }) = event
+                {
+                    assert_eq!(*owner, owner_, "Approval event: 'owner' mismatch");
+                    assert_eq!(*spender, spender_, "Approval event: 'spender' mismatch");
+                    assert_eq!(*amount, amount_, "Approval event: 'amount' mismatch");
+                } else {
+                    panic!("Event is not Approval")
+                }
+            }
+
+            #[ink::test]
+            fn mint_works_and_emits_event() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let (supply, value) = (1000, 100);
+                let mut token = $constructor(supply);
+                let start = recorded_events().count();
+
+                assert!(token.mint(value).is_ok());
+
+                assert_eq!(token.total_supply(), supply + value);
+                assert_eq!(token.balance_of(acc.alice), supply + value);
+
+                let events = decode_events(start);
+                assert_eq!(events.len(), 1);
+                assert_transfer(&events[0], None, Some(acc.alice), value);
+            }
+
+            #[ink::test]
+            fn mint_0_is_no_op() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let supply = 1000;
+                let mut token = $constructor(supply);
+                let start = recorded_events().count();
+
+                assert!(token.mint(0).is_ok());
+
+                assert_eq!(token.total_supply(), supply);
+                let events = decode_events(start);
+                assert_eq!(events.len(), 0);
+            }
+
+            #[ink::test]
+            fn mint_by_not_minter_fails() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let supply = 1000;
+                let mut token = $constructor(supply);
+                let start = recorded_events().count();
+
+                set_caller::<E>(acc.bob);
+                assert!(matches!(token.mint(100), Err(PSP22Error::Custom(_))));
+
+                assert_eq!(token.total_supply(), supply);
+                assert_eq!(token.balance_of(acc.bob), 0);
+                let events = decode_events(start);
+                assert_eq!(events.len(), 0);
+            }
+
+            #[ink::test]
+            fn mint_above_max_supply_fails() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let supply = u128::MAX - 10;
+                let mut token = $constructor(supply);
+
+                assert!(matches!(token.mint(11), Err(PSP22Error::Custom(_))));
+                assert_eq!(token.total_supply(), supply);
+            }
+
+            #[ink::test]
+            fn set_minter_hands_over_the_minter_role() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let supply = 1000;
+                let mut token = $constructor(supply);
+
+                assert!(token.set_minter(acc.bob).is_ok());
+                assert!(matches!(token.mint(100), Err(PSP22Error::Custom(_))));
+                assert!(matches!(
+                    token.set_minter(acc.alice),
+                    Err(PSP22Error::Custom(_))
+                ));
+
+                set_caller::<E>(acc.bob);
+                assert!(token.mint(100).is_ok());
+                assert_eq!(token.balance_of(acc.bob), 100);
+                assert_eq!(token.total_supply(), supply + 100);
+            }
+
+            #[ink::test]
+            fn burn_works_and_emits_event() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let (supply, value) = (1000, 100);
+                let mut token = $constructor(supply);
+                let start = recorded_events().count();
+
+                assert!(token.burn(value).is_ok());
+
+                assert_eq!(token.total_supply(), supply - value);
+                assert_eq!(token.balance_of(acc.alice), supply - value);
+
+                let events = decode_events(start);
+                assert_eq!(events.len(), 1);
+                assert_transfer(&events[0], Some(acc.alice), None, value);
+            }
+
+            #[ink::test]
+            fn burn_0_is_no_op() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let supply = 1000;
+                let mut token = $constructor(supply);
+                let start = recorded_events().count();
+
+                assert!(token.burn(0).is_ok());
+
+                assert_eq!(token.total_supply(), supply);
+                let events = decode_events(start);
+                assert_eq!(events.len(), 0);
+            }
+
+            #[ink::test]
+            fn burn_above_balance_fails() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let supply = 1000;
+                let mut token = $constructor(supply);
+                let start = recorded_events().count();
+
+                assert_eq!(token.burn(supply + 1), Err(PSP22Error::InsufficientBalance));
+
+                assert_eq!(token.total_supply(), supply);
+                let events = decode_events(start);
+                assert_eq!(events.len(), 0);
+            }
+
+            #[ink::test]
+            fn burn_from_works_and_emits_events() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let (supply, allowance, value) = (1000, 300, 100);
+                let mut token = $constructor(supply);
+                assert!(token.approve(acc.bob, allowance).is_ok());
+                let start = recorded_events().count();
+
+                set_caller::<E>(acc.bob);
+                assert!(token.burn_from(acc.alice, value).is_ok());
+
+                assert_eq!(token.total_supply(), supply - value);
+                assert_eq!(token.balance_of(acc.alice), supply - value);
+                assert_eq!(token.allowance(acc.alice, acc.bob), allowance - value);
+
+                let events = decode_events(start);
+                assert_eq!(events.len(), 2);
+                assert_approval(&events[0], acc.alice, acc.bob, allowance - value);
+                assert_transfer(&events[1], Some(acc.alice), None, value);
+            }
+
+            #[ink::test]
+            fn burn_from_without_allowance_fails() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let supply = 1000;
+                let mut token = $constructor(supply);
+                assert!(token.approve(acc.bob, 50).is_ok());
+                let start = recorded_events().count();
+
+                set_caller::<E>(acc.bob);
+                assert_eq!(
+                    token.burn_from(acc.alice, 100),
+                    Err(PSP22Error::InsufficientAllowance)
+                );
+
+                assert_eq!(token.total_supply(), supply);
+                assert_eq!(token.allowance(acc.alice, acc.bob), 50);
+                let events = decode_events(start);
+                assert_eq!(events.len(), 0);
+            }
+
+            #[ink::test]
+            fn burn_from_above_balance_keeps_allowance() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let supply = 1000;
+                let mut token = $constructor(supply);
+                assert!(token.approve(acc.bob, 2 * supply).is_ok());
+
+                set_caller::<E>(acc.bob);
+                assert_eq!(
+                    token.burn_from(acc.alice, supply + 1),
+                    Err(PSP22Error::InsufficientBalance)
+                );
+
+                assert_eq!(token.total_supply(), supply);
+                assert_eq!(token.allowance(acc.alice, acc.bob), 2 * supply);
+            }
+        }
+    };
+}
+
+/// Inserts a suite of ink! unit tests intended for a mintable and burnable contract
+/// implementing also the PSP22Capped trait.
+/// The constructor expression takes an initial supply and an optional cap, and returns
+/// `Result<$contract, PSP22Error>`. Placement is the same as for the `tests!` macro.
+#[macro_export]
+macro_rules! capped_tests {
+    ($contract:ident, $constructor:expr) => {
+        mod psp22_capped_unit_tests {
+            use super::super::*;
+            use ink::env::{test::*, DefaultEnvironment as E};
+
+            #[ink::test]
+            fn uncapped_token_has_no_cap() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let token = $constructor(1000, None).unwrap();
+
+                assert_eq!(token.cap(), None);
+            }
+
+            #[ink::test]
+            fn cap_is_returned() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let token = $constructor(1000, Some(5000)).unwrap();
+
+                assert_eq!(token.cap(), Some(5000));
+                assert_eq!(token.total_supply(), 1000);
+            }
+
+            #[ink::test]
+            fn supply_above_cap_fails() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+
+                assert!(matches!(
+                    $constructor(1001, Some(1000)),
+                    Err(PSP22Error::CapExceeded)
+                ));
+            }
+
+            #[ink::test]
+            fn mint_up_to_cap_works() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let (supply, cap) = (1000, 5000);
+                let mut token = $constructor(supply, Some(cap)).unwrap();
+
+                assert!(token.mint(cap - supply).is_ok());
+
+                assert_eq!(token.total_supply(), cap);
+                assert_eq!(token.balance_of(acc.alice), cap);
+            }
+
+            #[ink::test]
+            fn mint_above_cap_fails() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let (supply, cap) = (1000, 5000);
+                let mut token = $constructor(supply, Some(cap)).unwrap();
+                let start = recorded_events().count();
+
+                assert_eq!(token.mint(cap - supply + 1), Err(PSP22Error::CapExceeded));
+
+                assert_eq!(token.total_supply(), supply);
+                assert_eq!(token.balance_of(acc.alice), supply);
+                assert_eq!(recorded_events().count(), start);
+            }
+
+            #[ink::test]
+            fn burn_makes_room_under_cap() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let cap = 5000;
+                let mut token = $constructor(cap, Some(cap)).unwrap();
+
+                assert_eq!(token.mint(1), Err(PSP22Error::CapExceeded));
+                assert!(token.burn(100).is_ok());
+                assert!(token.mint(100).is_ok());
+
+                assert_eq!(token.total_supply(), cap);
+                assert_eq!(token.mint(1), Err(PSP22Error::CapExceeded));
+            }
+        }
+    };
+}
+
+/// Inserts a suite of ink! unit tests intended for a contract implementing PSP22Pausable trait
+/// with a single pauser (the creator of the token) and a `set_pauser(AccountId)` message
+/// handing the pauser role over.
+/// Arguments and placement are the same as for the `tests!` macro.
+#[macro_export]
+macro_rules! pausable_tests {
+    ($contract:ident, $constructor:expr) => {
+        mod psp22_pausable_unit_tests {
+            use super::super::*;
+            use ink::env::{test::*, DefaultEnvironment as E};
+
+            type Event = <$contract as ink::reflect::ContractEventBase>::Type;
+
+            // Gathers all emitted events, skip `shift` first, decode the rest and return as vector
+            fn decode_events(shift: usize) -> Vec<Event> {
+                recorded_events()
+                    .skip(shift)
+                    .map(|e| <Event as scale::Decode>::decode(&mut &e.data[..]).unwrap())
+                    .collect()
+            }
+
+            #[ink::test]
+            fn token_is_not_paused_initially() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let token = $constructor(1000);
+
+                assert!(!token.paused());
+                assert_eq!(token.pauser(), acc.alice);
+            }
+
+            #[ink::test]
+            fn pause_works_and_emits_event() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let mut token = $constructor(1000);
+                let start = recorded_events().count();
+
+                assert!(token.pause().is_ok());
+
+                assert!(token.paused());
+                let events = decode_events(start);
+                assert_eq!(events.len(), 1);
+                if let Event::Paused(Paused { account }) = &events[0] {
+                    assert_eq!(*account, acc.alice, "Paused event: 'account' mismatch");
+                } else {
+                    panic!("Event is not Paused")
+                }
+            }
+
+            #[ink::test]
+            fn unpause_works_and_emits_event() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let mut token = $constructor(1000);
+                assert!(token.pause().is_ok());
+                let start = recorded_events().count();
+
+                assert!(token.unpause().is_ok());
+
+                assert!(!token.paused());
+                let events = decode_events(start);
+                assert_eq!(events.len(), 1);
+                if let Event::Unpaused(Unpaused { account }) = &events[0] {
+                    assert_eq!(*account, acc.alice, "Unpaused event: 'account' mismatch");
+                } else {
+                    panic!("Event is not Unpaused")
+                }
+            }
+
+            #[ink::test]
+            fn pause_twice_fails() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let mut token = $constructor(1000);
+
+                assert!(token.pause().is_ok());
+                assert_eq!(token.pause(), Err(PSP22Error::Paused));
+            }
+
+            #[ink::test]
+            fn unpause_not_paused_fails() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let mut token = $constructor(1000);
+
+                assert!(matches!(token.unpause(), Err(PSP22Error::Custom(_))));
+            }
+
+            #[ink::test]
+            fn pause_and_unpause_by_not_pauser_fail() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let mut token = $constructor(1000);
+
+                set_caller::<E>(acc.bob);
+                assert!(matches!(token.pause(), Err(PSP22Error::Custom(_))));
+                assert!(!token.paused());
+
+                set_caller::<E>(acc.alice);
+                assert!(token.pause().is_ok());
+                set_caller::<E>(acc.bob);
+                assert!(matches!(token.unpause(), Err(PSP22Error::Custom(_))));
+                assert!(token.paused());
+            }
+
+            #[ink::test]
+            fn operations_fail_when_paused() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let supply = 1000;
+                let mut token = $constructor(supply);
+                assert!(token.approve(acc.bob, 100).is_ok());
+                assert!(token.pause().is_ok());
+                let start = recorded_events().count();
+
+                assert_eq!(token.transfer(acc.bob, 10, vec![]), Err(PSP22Error::Paused));
+                assert_eq!(token.approve(acc.bob, 200), Err(PSP22Error::Paused));
+                assert_eq!(
+                    token.increase_allowance(acc.bob, 10),
+                    Err(PSP22Error::Paused)
+                );
+                assert_eq!(
+                    token.decrease_allowance(acc.bob, 10),
+                    Err(PSP22Error::Paused)
+                );
+                set_caller::<E>(acc.bob);
+                assert_eq!(
+                    token.transfer_from(acc.alice, acc.bob, 10, vec![]),
+                    Err(PSP22Error::Paused)
+                );
+
+                assert_eq!(token.balance_of(acc.alice), supply);
+                assert_eq!(token.balance_of(acc.bob), 0);
+                assert_eq!(token.allowance(acc.alice, acc.bob), 100);
+                assert_eq!(decode_events(start).len(), 0);
+            }
+
+            #[ink::test]
+            fn operations_work_after_unpause() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let supply = 1000;
+                let mut token = $constructor(supply);
+                assert!(token.pause().is_ok());
+                assert!(token.unpause().is_ok());
+
+                assert!(token.transfer(acc.bob, 10, vec![]).is_ok());
+                assert!(token.approve(acc.bob, 100).is_ok());
+                set_caller::<E>(acc.bob);
+                assert!(token.transfer_from(acc.alice, acc.bob, 10, vec![]).is_ok());
+
+                assert_eq!(token.balance_of(acc.alice), supply - 20);
+                assert_eq!(token.balance_of(acc.bob), 20);
+            }
+
+            #[ink::test]
+            fn set_pauser_hands_over_the_pauser_role() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let mut token = $constructor(1000);
+
+                assert!(token.set_pauser(acc.bob).is_ok());
+                assert!(matches!(token.pause(), Err(PSP22Error::Custom(_))));
+                assert!(matches!(
+                    token.set_pauser(acc.alice),
+                    Err(PSP22Error::Custom(_))
+                ));
+
+                set_caller::<E>(acc.bob);
+                assert!(token.pause().is_ok());
+                assert!(token.paused());
+            }
+        }
+    };
+}
+
+/// Inserts a suite of ink! unit tests intended for a mintable and burnable contract
+/// implementing also the PSP22Snapshot trait, where snapshots can be taken by the
+/// creator of the token.
+/// Arguments and placement are the same as for the `tests!` macro.
+#[macro_export]
+macro_rules! snapshot_tests {
+    ($contract:ident, $constructor:expr) => {
+        mod psp22_snapshot_unit_tests {
+            use super::super::*;
+            use ink::env::{test::*, DefaultEnvironment as E};
+
+            type Event = <$contract as ink::reflect::ContractEventBase>::Type;
+
+            // Gathers all emitted events, skip `shift` first, decode the rest and return as vector
+            fn decode_events(shift: usize) -> Vec<Event> {
+                recorded_events()
+                    .skip(shift)
+                    .map(|e| <Event as scale::Decode>::decode(&mut &e.data[..]).unwrap())
+                    .collect()
+            }
+
+            #[ink::test]
+            fn snapshot_works_and_emits_event() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let mut token = $constructor(1000);
+                assert_eq!(token.current_snapshot_id(), 0);
+                let start = recorded_events().count();
+
+                assert_eq!(token.snapshot(), Ok(1));
+                assert_eq!(token.snapshot(), Ok(2));
+
+                assert_eq!(token.current_snapshot_id(), 2);
+                let events = decode_events(start);
+                assert_eq!(events.len(), 2);
+                for (event, id_) in events.iter().zip(1..) {
+                    if let Event::Snapshot(Snapshot { id }) = event {
+                        assert_eq!(*id, id_, "Snapshot event: 'id' mismatch");
+                    } else {
+                        panic!("Event is not Snapshot")
+                    }
+                }
+            }
+
+            #[ink::test]
+            fn snapshot_by_not_authorized_account_fails() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let mut token = $constructor(1000);
+
+                set_caller::<E>(acc.bob);
+                assert!(matches!(token.snapshot(), Err(PSP22Error::Custom(_))));
+                assert_eq!(token.current_snapshot_id(), 0);
+            }
+
+            #[ink::test]
+            fn nonexistent_snapshot_returns_none() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let mut token = $constructor(1000);
+
+                assert_eq!(token.balance_of_at(acc.alice, 0), None);
+                assert_eq!(token.balance_of_at(acc.alice, 1), None);
+                assert_eq!(token.total_supply_at(1), None);
+
+                assert!(token.snapshot().is_ok());
+                assert_eq!(token.balance_of_at(acc.alice, 2), None);
+                assert_eq!(token.total_supply_at(0), None);
+                assert_eq!(token.total_supply_at(2), None);
+            }
+
+            #[ink::test]
+            fn unchanged_values_are_current_values() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let supply = 1000;
+                let mut token = $constructor(supply);
+                assert!(token.snapshot().is_ok());
+
+                assert_eq!(token.balance_of_at(acc.alice, 1), Some(supply));
+                assert_eq!(token.balance_of_at(acc.bob, 1), Some(0));
+                assert_eq!(token.total_supply_at(1), Some(supply));
+            }
+
+            #[ink::test]
+            fn balances_are_recorded_at_snapshots() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let supply = 1000;
+                let mut token = $constructor(supply);
+
+                // Changes before the first snapshot are not recorded
+                assert!(token.transfer(acc.bob, 100, vec![]).is_ok());
+                assert!(token.snapshot().is_ok());
+                assert!(token.transfer(acc.bob, 200, vec![]).is_ok());
+                assert!(token.transfer(acc.bob, 300, vec![]).is_ok());
+                // Snapshot 2 is taken without any change before snapshot 3
+                assert!(token.snapshot().is_ok());
+                assert!(token.snapshot().is_ok());
+                assert!(token.approve(acc.charlie, 50).is_ok());
+                set_caller::<E>(acc.charlie);
+                assert!(token
+                    .transfer_from(acc.alice, acc.charlie, 50, vec![])
+                    .is_ok());
+
+                assert_eq!(token.balance_of_at(acc.alice, 1), Some(900));
+                assert_eq!(token.balance_of_at(acc.bob, 1), Some(100));
+                assert_eq!(token.balance_of_at(acc.charlie, 1), Some(0));
+                assert_eq!(token.balance_of_at(acc.alice, 2), Some(400));
+                assert_eq!(token.balance_of_at(acc.bob, 2), Some(600));
+                assert_eq!(token.balance_of_at(acc.alice, 3), Some(400));
+                assert_eq!(token.balance_of_at(acc.charlie, 3), Some(0));
+                assert_eq!(token.balance_of(acc.alice), 350);
+                assert_eq!(token.balance_of(acc.charlie), 50);
+                for id in 1..=3 {
+                    assert_eq!(token.total_supply_at(id), Some(supply));
+                }
+            }
+
+            #[ink::test]
+            fn total_supply_is_recorded_at_snapshots() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let supply = 1000;
+                let mut token = $constructor(supply);
+                assert!(token.snapshot().is_ok());
+                assert!(token.mint(500).is_ok());
+                assert!(token.snapshot().is_ok());
+                assert!(token.burn(200).is_ok());
+                assert!(token.burn(100).is_ok());
+
+                assert_eq!(token.total_supply_at(1), Some(supply));
+                assert_eq!(token.total_supply_at(2), Some(supply + 500));
+                assert_eq!(token.total_supply(), supply + 200);
+                assert_eq!(token.balance_of_at(acc.alice, 1), Some(supply));
+                assert_eq!(token.balance_of_at(acc.alice, 2), Some(supply + 500));
+            }
+        }
+    };
+}
+
+/// Inserts a suite of ink! unit tests intended for a mintable and burnable contract
+/// implementing also the PSP22Votes trait.
+/// Arguments and placement are the same as for the `tests!` macro.
+#[macro_export]
+macro_rules! votes_tests {
+    ($contract:ident, $constructor:expr) => {
+        mod psp22_votes_unit_tests {
+            use super::super::*;
+            use ink::env::{test::*, DefaultEnvironment as E};
+
+            type Event = <$contract as ink::reflect::ContractEventBase>::Type;
+
+            // Gathers all emitted events, skip `shift` first, decode the rest and return as vector
+            fn decode_events(shift: usize) -> Vec<Event> {
+                recorded_events()
+                    .skip(shift)
+                    .map(|e| <Event as scale::Decode>::decode(&mut &e.data[..]).unwrap())
+                    .collect()
+            }
+
+            // Asserts if the given event is a DelegateVotesChanged with particular delegate_,
+            // previous_votes_ and new_votes_
+            fn assert_delegate_votes_changed(
+                event: &Event,
+                delegate_: AccountId,
+                previous_votes_: u128,
+                new_votes_: u128,
+            ) {
+                if let Event::DelegateVotesChanged(DelegateVotesChanged {
+                    delegate,
+                    previous_votes,
+                    new_votes,
+                }) = event
+                {
+                    assert_eq!(
+                        *delegate, delegate_,
+                        "DelegateVotesChanged event: 'delegate' mismatch"
+                    );
+                    assert_eq!(
+                        *previous_votes, previous_votes_,
+                        "DelegateVotesChanged event: 'previous_votes' mismatch"
+                    );
+                    assert_eq!(
+                        *new_votes, new_votes_,
+                        "DelegateVotesChanged event: 'new_votes' mismatch"
+                    );
+                } else {
+                    panic!("Event is not DelegateVotesChanged")
+                }
+            }
+
+            #[ink::test]
+            fn balances_are_not_votes_until_delegated() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let token = $constructor(1000);
+
+                assert_eq!(token.delegates(acc.alice), None);
+                assert_eq!(token.get_votes(acc.alice), 0);
+            }
+
+            #[ink::test]
+            fn delegate_works_and_emits_events() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let supply = 1000;
+                let mut token = $constructor(supply);
+                let start = recorded_events().count();
+
+                assert!(token.delegate(acc.bob).is_ok());
+
+                assert_eq!(token.delegates(acc.alice), Some(acc.bob));
+                assert_eq!(token.get_votes(acc.bob), supply);
+                assert_eq!(token.get_votes(acc.alice), 0);
+
+                let events = decode_events(start);
+                assert_eq!(events.len(), 2);
+                if let Event::DelegateChanged(DelegateChanged {
+                    delegator,
+                    from_delegate,
+                    to_delegate,
+                }) = &events[0]
+                {
+                    assert_eq!(
+                        *delegator, acc.alice,
+                        "DelegateChanged event: 'delegator' mismatch"
+                    );
+                    assert_eq!(
+                        *from_delegate, None,
+                        "DelegateChanged event: 'from_delegate' mismatch"
+                    );
+                    assert_eq!(
+                        *to_delegate, acc.bob,
+                        "DelegateChanged event: 'to_delegate' mismatch"
+                    );
+                } else {
+                    panic!("Event is not DelegateChanged")
+                }
+                assert_delegate_votes_changed(&events[1], acc.bob, 0, supply);
+            }
+
+            #[ink::test]
+            fn redelegate_moves_votes() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let supply = 1000;
+                let mut token = $constructor(supply);
+                assert!(token.delegate(acc.bob).is_ok());
+                let start = recorded_events().count();
+
+                assert!(token.delegate(acc.alice).is_ok());
+
+                assert_eq!(token.get_votes(acc.bob), 0);
+                assert_eq!(token.get_votes(acc.alice), supply);
+                let events = decode_events(start);
+                assert_eq!(events.len(), 3);
+                assert_delegate_votes_changed(&events[1], acc.bob, supply, 0);
+                assert_delegate_votes_changed(&events[2], acc.alice, 0, supply);
+            }
+
+            #[ink::test]
+            fn transfers_move_votes_between_delegates() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let supply = 1000;
+                let mut token = $constructor(supply);
+                assert!(token.delegate(acc.alice).is_ok());
+                set_caller::<E>(acc.bob);
+                assert!(token.delegate(acc.charlie).is_ok());
+                set_caller::<E>(acc.alice);
+                let start = recorded_events().count();
+
+                assert!(token.transfer(acc.bob, 300, vec![]).is_ok());
+
+                assert_eq!(token.get_votes(acc.alice), supply - 300);
+                assert_eq!(token.get_votes(acc.charlie), 300);
+                let events = decode_events(start);
+                assert_eq!(events.len(), 3);
+                assert_delegate_votes_changed(&events[1], acc.alice, supply, supply - 300);
+                assert_delegate_votes_changed(&events[2], acc.charlie, 0, 300);
+
+                // Tokens of an account without a delegate are not votes
+                assert!(token.transfer(acc.django, 100, vec![]).is_ok());
+                assert_eq!(token.get_votes(acc.alice), supply - 400);
+                assert_eq!(token.get_votes(acc.django), 0);
+            }
+
+            #[ink::test]
+            fn mint_and_burn_change_votes() {
+                let acc = default_accounts::<E>();
+                set_caller::<E>(acc.alice);
+                let supply = 1000;
+                let mut token = $constructor(supply);
+                assert!(token.delegate(acc.bob).is_ok());
+
+                assert!(token.mint(500).is_ok());
+                assert_eq!(token.get_votes(acc.bob), supply + 500);
+
+                assert!(token.burn(200).is_ok());
+                assert_eq!(token.get_votes(acc.bob), supply + 300);
+            }
+
+            #[ink::test]
+            fn past_votes_and_total_supply_are_recorded() {
+                let acc = default_accounts::<E>();
+                set_block_timestamp::<E>(1000);
+                set_caller::<E>(acc.alice);
+                let supply = 1000;
+                let mut token = $constructor(supply);
+                set_block_timestamp::<E>(2000);
+                assert!(token.delegate(acc.alice).is_ok());
+                set_block_timestamp::<E>(3000);
+                assert!(token.mint(500).is_ok());
+                // Changes in the same block are recorded in a single checkpoint
+                assert!(token.transfer(acc.bob, 100, vec![]).is_ok());
+                assert!(token.transfer(acc.bob, 100, vec![]).is_ok());
+                set_block_timestamp::<E>(4000);
+                assert!(token.burn(300).is_ok());
+                set_block_timestamp::<E>(5000);
+
+                assert_eq!(token.get_past_votes(acc.alice, 999), Ok(0));
+                assert_eq!(token.get_past_votes(acc.alice, 1999), Ok(0));
+                assert_eq!(token.get_past_votes(acc.alice, 2000), Ok(supply));
+                assert_eq!(token.get_past_votes(acc.alice, 2999), Ok(supply));
+                assert_eq!(token.get_past_votes(acc.alice, 3000), Ok(supply + 300));
+                assert_eq!(token.get_past_votes(acc.alice, 4500), Ok(supply));
+                assert_eq!(token.get_votes(acc.alice), supply);
+
+                assert_eq!(token.get_past_total_supply(999), Ok(0));
+                assert_eq!(token.get_past_total_supply(1000), Ok(supply));
+                assert_eq!(token.get_past_total_supply(3000), Ok(supply + 500));
+                assert_eq!(token.get_past_total_supply(4000), Ok(supply + 200));
+            }
+
+            #[ink::test]
+            fn future_lookup_fails() {
+                let acc = default_accounts::<E>();
+                set_block_timestamp::<E>(1000);
+                set_caller::<E>(acc.alice);
+                let token = $constructor(1000);
+
+                assert!(matches!(
+                    token.get_past_votes(acc.alice, 1000),
+                    Err(PSP22Error::Custom(_))
+                ));
+                assert!(matches!(
+                    token.get_past_total_supply(2000),
+                    Err(PSP22Error::Custom(_))
+                ));
+            }
+        }
+    };
+}
@@ -171,6 +171,198 @@ pub trait PSP22Mintable {
     ///
     /// Reverts with `Custom (max supply exceeded)` if the total supply increased by
     /// `value` exceeds maximal value of `u128` type.
+    ///
+    /// Reverts with `CapExceeded` if the token is capped and the total supply increased
+    /// by `value` exceeds the cap.
     #[ink(message)]
     fn mint(&mut self, value: u128) -> Result<(), PSP22Error>;
 }
+
+#[ink::trait_definition]
+pub trait PSP22Capped {
+    /// Returns the maximal total supply of the token.
+    ///
+    /// The selector for this message is `0xf40366b4` (first 4 bytes of `blake2b_256("PSP22Capped::cap")`).
+    ///
+    /// Returns `None` if the supply is not capped.
+    #[ink(message)]
+    fn cap(&self) -> Option<u128>;
+}
+
+#[ink::trait_definition]
+pub trait PSP22Permit {
+    /// Returns the number of permits already used by `owner`.
+    ///
+    /// The next permit of `owner` has to be signed with this nonce.
+    #[ink(message)]
+    fn nonces(&self, owner: AccountId) -> u64;
+
+    /// Allows `spender` to withdraw from the `owner`'s account multiple times, up to
+    /// the total amount of `value`, with an ECDSA `signature` of `owner` instead of
+    /// a transaction sent by `owner`.
+    ///
+    /// The selector for this message is `0x84d6348f` (first 4 bytes of `blake2b_256("PSP22Permit::permit")`).
+    ///
+    /// The signed message is the `blake2b_256` hash of the SCALE encoded tuple
+    /// `(b"PSP22Permit", token, owner, spender, value, nonce, deadline)`, where `token` is the
+    /// token contract address and `nonce` is `nonces(owner)`. Each permit can be used only once.
+    ///
+    /// # Events
+    ///
+    /// An `Approval` event is emitted.
+    ///
+    /// No-op if `owner` and `spender` is the same address, returns success and no events are emitted.
+    ///
+    /// # Errors
+    ///
+    /// Reverts with `Custom (permit expired)` if the block timestamp is past `deadline`.
+    ///
+    /// Reverts with `Custom (invalid permit signature)` if `signature` is not a signature of `owner`
+    /// over the message above.
+    #[ink(message)]
+    fn permit(
+        &mut self,
+        owner: AccountId,
+        spender: AccountId,
+        value: u128,
+        deadline: u64,
+        signature: [u8; 65],
+    ) -> Result<(), PSP22Error>;
+}
+
+#[ink::trait_definition]
+pub trait PSP22Pausable {
+    /// Returns `true` if the token is paused.
+    ///
+    /// The selector for this message is `0xe08fffe1` (first 4 bytes of `blake2b_256("PSP22Pausable::paused")`).
+    #[ink(message)]
+    fn paused(&self) -> bool;
+
+    /// Pauses the token. While paused, `transfer`, `transfer_from`, `approve`,
+    /// `increase_allowance` and `decrease_allowance` revert with `Paused`.
+    ///
+    /// The selector for this message is `0xeba4b142` (first 4 bytes of `blake2b_256("PSP22Pausable::pause")`).
+    ///
+    /// # Events
+    ///
+    /// On success a `Paused` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Reverts with `Paused` if the token is already paused.
+    ///
+    /// Implementations restrict this message to an authorized account and revert with
+    /// a `Custom` error for any other caller.
+    #[ink(message)]
+    fn pause(&mut self) -> Result<(), PSP22Error>;
+
+    /// Unpauses the token.
+    ///
+    /// The selector for this message is `0x70f8a5b7` (first 4 bytes of `blake2b_256("PSP22Pausable::unpause")`).
+    ///
+    /// # Events
+    ///
+    /// On success an `Unpaused` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Reverts with `Custom (token is not paused)` if the token is not paused.
+    ///
+    /// Implementations restrict this message to an authorized account and revert with
+    /// a `Custom` error for any other caller.
+    #[ink(message)]
+    fn unpause(&mut self) -> Result<(), PSP22Error>;
+}
+
+#[ink::trait_definition]
+pub trait PSP22Snapshot {
+    /// Takes a snapshot of all balances and of the total supply and returns its id.
+    /// Snapshot ids start at 1 and increase by 1 with every snapshot.
+    ///
+    /// The selector for this message is `0x92f7ee55` (first 4 bytes of `blake2b_256("PSP22Snapshot::snapshot")`).
+    ///
+    /// # Events
+    ///
+    /// On success a `Snapshot` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Implementations restrict this message to an authorized account and revert with
+    /// a `Custom` error for any other caller.
+    #[ink(message)]
+    fn snapshot(&mut self) -> Result<u32, PSP22Error>;
+
+    /// Returns the id of the latest snapshot, `0` if no snapshot was taken yet.
+    ///
+    /// The selector for this message is `0xb83db056` (first 4 bytes of `blake2b_256("PSP22Snapshot::current_snapshot_id")`).
+    #[ink(message)]
+    fn current_snapshot_id(&self) -> u32;
+
+    /// Returns the balance of `owner` at the time snapshot `id` was taken.
+    ///
+    /// The selector for this message is `0x0b510f10` (first 4 bytes of `blake2b_256("PSP22Snapshot::balance_of_at")`).
+    ///
+    /// Returns `None` if there is no snapshot with the given `id`.
+    #[ink(message)]
+    fn balance_of_at(&self, owner: AccountId, id: u32) -> Option<u128>;
+
+    /// Returns the total supply at the time snapshot `id` was taken.
+    ///
+    /// The selector for this message is `0xfece54da` (first 4 bytes of `blake2b_256("PSP22Snapshot::total_supply_at")`).
+    ///
+    /// Returns `None` if there is no snapshot with the given `id`.
+    #[ink(message)]
+    fn total_supply_at(&self, id: u32) -> Option<u128>;
+}
+
+#[ink::trait_definition]
+pub trait PSP22Votes {
+    /// Returns the delegate chosen by `account`.
+    ///
+    /// The selector for this message is `0x36f54650` (first 4 bytes of `blake2b_256("PSP22Votes::delegates")`).
+    ///
+    /// Returns `None` if `account` has not delegated its voting power.
+    #[ink(message)]
+    fn delegates(&self, account: AccountId) -> Option<AccountId>;
+
+    /// Returns the current votes of `account`, i.e. the sum of balances of all
+    /// accounts delegating to it.
+    ///
+    /// The selector for this message is `0xc0a57050` (first 4 bytes of `blake2b_256("PSP22Votes::get_votes")`).
+    #[ink(message)]
+    fn get_votes(&self, account: AccountId) -> u128;
+
+    /// Returns the votes of `account` at the end of the block with `timestamp`
+    /// (in milliseconds).
+    ///
+    /// The selector for this message is `0x30c44fba` (first 4 bytes of `blake2b_256("PSP22Votes::get_past_votes")`).
+    ///
+    /// # Errors
+    ///
+    /// Reverts with `Custom (future lookup)` if `timestamp` is not before the current block timestamp.
+    #[ink(message)]
+    fn get_past_votes(&self, account: AccountId, timestamp: u64) -> Result<u128, PSP22Error>;
+
+    /// Returns the total supply at the end of the block with `timestamp` (in milliseconds).
+    ///
+    /// The selector for this message is `0xbff85b3b` (first 4 bytes of `blake2b_256("PSP22Votes::get_past_total_supply")`).
+    ///
+    /// # Errors
+    ///
+    /// Reverts with `Custom (future lookup)` if `timestamp` is not before the current block timestamp.
+    #[ink(message)]
+    fn get_past_total_supply(&self, timestamp: u64) -> Result<u128, PSP22Error>;
+
+    /// Delegates the voting power of the caller's whole balance, now and after
+    /// future balance changes, to `delegatee`. Tokens count as votes only once delegated,
+    /// holders can delegate to themselves.
+    ///
+    /// The selector for this message is `0x3ffd72aa` (first 4 bytes of `blake2b_256("PSP22Votes::delegate")`).
+    ///
+    /// # Events
+    ///
+    /// On success a `DelegateChanged` event is emitted, followed by `DelegateVotesChanged`
+    /// events for the previous and the new delegate if the caller's balance is not zero.
+    #[ink(message)]
+    fn delegate(&mut self, delegatee: AccountId) -> Result<(), PSP22Error>;
+}
@@ -0,0 +1,123 @@
+//! Execution of arbitrary encoded cross-contract calls, shared by the governor,
+//! timelock and multi_sig contracts.
+//!
+//! A message returning an `Err` reverts its state changes but still returns its
+//! encoded `Result` as a successful call would, so the outcome of such a message
+//! is only known from the tag of the returned `Result`. Calls therefore tell
+//! whether their message returns a `Result`, in which case an `Err` fails the call.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink::env::{
+    call::{
+        build_call,
+        ExecutionInput,
+        Selector,
+    },
+    CallFlags,
+    DefaultEnvironment,
+};
+use ink::primitives::AccountId;
+
+/// Already SCALE encoded arguments of a call.
+struct CallInput<'a>(&'a [u8]);
+
+impl scale::Encode for CallInput<'_> {
+    fn encode_to<T: scale::Output + ?Sized>(&self, dest: &mut T) {
+        dest.write(self.0);
+    }
+}
+
+/// Outcome of a message returning a `Result`, decoded from the tag of the
+/// `Result` only since the types it wraps are not known.
+#[derive(Debug, PartialEq, Eq)]
+pub enum CallOutcome {
+    Ok,
+    Err,
+}
+
+impl scale::Decode for CallOutcome {
+    fn decode<I: scale::Input>(input: &mut I) -> Result<Self, scale::Error> {
+        match input.read_byte()? {
+            0 => Ok(CallOutcome::Ok),
+            1 => Ok(CallOutcome::Err),
+            _ => Err("invalid Result tag".into()),
+        }
+    }
+}
+
+/// Calls the message with `selector` of `callee` with already encoded `input`,
+/// transferring `transferred_value` AZERO. Returns whether the call succeeded, i.e.
+/// the callee did not trap and, if `returns_result`, the message returned `Ok`.
+pub fn execute_call(
+    callee: AccountId,
+    selector: [u8; 4],
+    input: &[u8],
+    transferred_value: u128,
+    returns_result: bool,
+) -> bool {
+    let call = build_call::<DefaultEnvironment>()
+        .call(callee)
+        .transferred_value(transferred_value)
+        // Calls may target the calling contract itself, e.g. to change its own settings
+        .call_flags(CallFlags::default().set_allow_reentry(true))
+        .exec_input(ExecutionInput::new(Selector::new(selector)).push_arg(CallInput(input)));
+
+    if returns_result {
+        matches!(
+            call.returns::<CallOutcome>().try_invoke(),
+            Ok(Ok(CallOutcome::Ok))
+        )
+    } else {
+        matches!(call.returns::<()>().try_invoke(), Ok(Ok(())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ink::primitives::MessageResult;
+    use scale::{
+        Decode,
+        Encode,
+    };
+
+    fn decode_output(output: &[u8]) -> MessageResult<CallOutcome> {
+        MessageResult::<CallOutcome>::decode(&mut &output[..]).unwrap()
+    }
+
+    #[test]
+    fn ok_results_succeed() {
+        let output = Ok::<Result<u128, u8>, ink::LangError>(Ok(42)).encode();
+
+        assert_eq!(decode_output(&output), Ok(CallOutcome::Ok));
+    }
+
+    #[test]
+    fn err_results_fail() {
+        let output = Ok::<Result<u128, u8>, ink::LangError>(Err(3)).encode();
+
+        // Decoded as a message returning nothing, the reverted call would look successful
+        assert_eq!(
+            MessageResult::<()>::decode(&mut &output[..]).unwrap(),
+            Ok(())
+        );
+        assert_eq!(decode_output(&output), Ok(CallOutcome::Err));
+    }
+
+    #[test]
+    fn lang_errors_fail() {
+        let output =
+            Err::<Result<(), u8>, ink::LangError>(ink::LangError::CouldNotReadInput).encode();
+
+        assert_eq!(
+            decode_output(&output),
+            Err(ink::LangError::CouldNotReadInput)
+        );
+    }
+
+    #[test]
+    fn invalid_result_tags_are_rejected() {
+        assert!(MessageResult::<CallOutcome>::decode(&mut &[0u8, 2][..]).is_err());
+        assert!(MessageResult::<CallOutcome>::decode(&mut &[0u8][..]).is_err());
+    }
+}
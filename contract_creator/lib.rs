@@ -4,16 +4,25 @@
 #[ink::contract]
 pub mod pair_creator {
 
+    use ink::codegen::EmitEvent;
     use ink::LangError;
+    use ink::ToAccountId;
+    use ink::prelude::vec::Vec;
+    use ink::storage::Mapping;
+    use multi_sig::multi_sig::{MultiSigErrors, MultiSigRef};
     use trading_pair_azero::trading_pair_azero::TradingPairAzeroRef;
 
     #[ink(storage)]
-    pub struct PairCreator {}
+    pub struct PairCreator {
+        // Multi sig wallets deployed by each creator
+        multi_sigs: Mapping<AccountId, Vec<AccountId>>,
+    }
 
     #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
     #[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
     pub enum PairCreatorErrors {
         InstantiatingFailed,
+        MultiSig(MultiSigErrors), // Error code returned by the multi sig wallet constructor
     }
 
     impl From<ink::env::Error> for PairCreatorErrors {
@@ -28,11 +37,19 @@ pub mod pair_creator {
         }
     }
 
+    impl From<MultiSigErrors> for PairCreatorErrors {
+        fn from(cause: MultiSigErrors) -> Self {
+            PairCreatorErrors::MultiSig(cause)
+        }
+    }
+
     #[ink(event)]
     pub struct NewTPA {
         caller: AccountId,
         psp22_address: AccountId,
         lp_fee: Balance,
+        #[ink(topic)]
+        pair_address: AccountId,
     }
 
     #[ink(event)]
@@ -43,10 +60,21 @@ pub mod pair_creator {
         lp_fee: Balance,
     }
 
+    #[ink(event)]
+    pub struct NewMultiSig {
+        caller: AccountId,
+        owners: Vec<AccountId>,
+        threshold: u32,
+        #[ink(topic)]
+        multi_sig_address: AccountId,
+    }
+
     impl PairCreator {
         #[ink(constructor)]
         pub fn new() -> Self {
-            Self {}
+            Self {
+                multi_sigs: Mapping::default(),
+            }
         }
 
         #[ink(message, payable)]
@@ -79,12 +107,147 @@ pub mod pair_creator {
 
             let new_pair_address = trading_pair.get_account_id();
 
+            EmitEvent::<PairCreator>::emit_event(self.env(), NewTPA {
+                caller: deployer,
+                psp22_address: psp22_addrr,
+                lp_fee: fee,
+                pair_address: new_pair_address,
+            });
+
             Ok(new_pair_address)
         }
 
+        #[ink(message)]
+        pub fn create_multi_sig(
+            &mut self,
+            multi_sig_hash: Hash,
+            owners: Vec<AccountId>,
+            threshold: u32,
+            salt: u32,
+        ) -> Result<AccountId, PairCreatorErrors> {
+            let deployer = self.env().caller();
+
+            // Salt with the deployer so that creators can not take each other addresses
+            let mut salt_bytes: Vec<u8> = AsRef::<[u8]>::as_ref(&deployer).to_vec();
+            salt_bytes.extend_from_slice(&salt.to_le_bytes());
+
+            let multi_sig = MultiSigRef::new(owners.clone(), threshold)
+                .endowment(0)
+                .code_hash(multi_sig_hash)
+                .salt_bytes(salt_bytes)
+                .try_instantiate()???;
+
+            let new_multi_sig_address = multi_sig.to_account_id();
+
+            let mut created_multi_sigs = self.multi_sigs.get(deployer).unwrap_or_default();
+            created_multi_sigs.push(new_multi_sig_address);
+            self.multi_sigs.insert(deployer, &created_multi_sigs);
+
+            EmitEvent::<PairCreator>::emit_event(self.env(), NewMultiSig {
+                caller: deployer,
+                owners,
+                threshold,
+                multi_sig_address: new_multi_sig_address,
+            });
+
+            Ok(new_multi_sig_address)
+        }
+
+        /// Returns the multi sig wallets deployed by `creator`.
+        #[ink(message)]
+        pub fn get_multi_sigs(&self, creator: AccountId) -> Vec<AccountId> {
+            self.multi_sigs.get(creator).unwrap_or_default()
+        }
+    }
+
+    /// ink! end-to-end (E2E) tests
+    ///
+    /// cargo test --features e2e-tests -- --nocapture
+    #[cfg(all(test, feature = "e2e-tests"))]
+    mod e2e_tests {
+        use super::*;
+        use ink_e2e::build_message;
 
+        type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
 
+        fn get_alice_account_id() -> AccountId {
+            ink_e2e::account_id(ink_e2e::AccountKeyring::Alice)
+        }
 
+        fn get_bob_account_id() -> AccountId {
+            ink_e2e::account_id(ink_e2e::AccountKeyring::Bob)
+        }
 
+        /// Tests included in "create_multi_sig_works":
+        /// 1. create_multi_sig of an invalid wallet fails with the error of the wallet constructor
+        /// 2. create_multi_sig with the same salt gives different addresses to different creators
+        /// 3. get_multi_sigs returns the wallets deployed by each creator
+        #[ink_e2e::test(additional_contracts = "../multi_sig/Cargo.toml")]
+        async fn create_multi_sig_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let multi_sig_hash = client
+                .upload("multi_sig", &ink_e2e::alice(), None)
+                .await
+                .expect("upload failed")
+                .code_hash;
+
+            let creator_constructor = PairCreatorRef::new();
+            let creator_acc_id = client
+                .instantiate("contract_creator", &ink_e2e::alice(), creator_constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            // A threshold above the number of owners is rejected by the wallet constructor
+            let create_invalid_multi_sig = build_message::<PairCreatorRef>(creator_acc_id.clone())
+                .call(|creator| {
+                    creator.create_multi_sig(multi_sig_hash, vec![get_alice_account_id()], 2, 0)
+                });
+            let create_invalid_multi_sig_res = client
+                .call(&ink_e2e::alice(), create_invalid_multi_sig, 0, None)
+                .await
+                .expect("create_multi_sig failed");
+            assert_eq!(
+                create_invalid_multi_sig_res.return_value(),
+                Err(PairCreatorErrors::MultiSig(MultiSigErrors::InvalidThreshold))
+            );
+
+            // Alice and bob both create a wallet with the salt 0
+            let owners = vec![get_alice_account_id(), get_bob_account_id()];
+            let create_multi_sig = build_message::<PairCreatorRef>(creator_acc_id.clone())
+                .call(|creator| creator.create_multi_sig(multi_sig_hash, owners.clone(), 2, 0));
+            let alice_multi_sig = client
+                .call(&ink_e2e::alice(), create_multi_sig, 0, None)
+                .await
+                .expect("create_multi_sig failed")
+                .return_value()
+                .expect("alice multi sig not created");
+
+            let create_multi_sig = build_message::<PairCreatorRef>(creator_acc_id.clone())
+                .call(|creator| creator.create_multi_sig(multi_sig_hash, owners.clone(), 2, 0));
+            let bob_multi_sig = client
+                .call(&ink_e2e::bob(), create_multi_sig, 0, None)
+                .await
+                .expect("create_multi_sig failed")
+                .return_value()
+                .expect("bob multi sig not created");
+
+            assert_ne!(alice_multi_sig, bob_multi_sig);
+
+            let get_alice_multi_sigs = build_message::<PairCreatorRef>(creator_acc_id.clone())
+                .call(|creator| creator.get_multi_sigs(get_alice_account_id()));
+            let get_alice_multi_sigs_res = client
+                .call_dry_run(&ink_e2e::alice(), &get_alice_multi_sigs, 0, None)
+                .await;
+            assert_eq!(get_alice_multi_sigs_res.return_value(), vec![alice_multi_sig]);
+
+            let get_bob_multi_sigs = build_message::<PairCreatorRef>(creator_acc_id.clone())
+                .call(|creator| creator.get_multi_sigs(get_bob_account_id()));
+            let get_bob_multi_sigs_res = client
+                .call_dry_run(&ink_e2e::alice(), &get_bob_multi_sigs, 0, None)
+                .await;
+            assert_eq!(get_bob_multi_sigs_res.return_value(), vec![bob_multi_sig]);
+
+            Ok(())
+        }
     }
 }
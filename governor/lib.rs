@@ -0,0 +1,904 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[ink::contract]
+pub mod governor {
+
+    use call_executor::execute_call;
+    use ink::codegen::EmitEvent;
+    use ink::prelude::{
+        string::String,
+        vec::Vec,
+    };
+    use ink::storage::{
+        Lazy,
+        Mapping,
+    };
+    use psp22::PSP22Votes;
+
+    // Maximal number of calls a single proposal can execute
+    const MAX_PROPOSAL_CALLS: usize = 10;
+    // Time a queued proposal stays executable after its eta, in milliseconds (14 days)
+    const EXECUTION_GRACE_PERIOD: u64 = 14 * 24 * 60 * 60 * 1000;
+
+    /// A call executed by a passed proposal as (callee, message selector, SCALE encoded arguments, transferred AZERO,
+    /// whether the message returns a `Result`, whose `Err` then fails the call).
+    pub type ProposalCall = (AccountId, [u8; 4], Vec<u8>, Balance, bool);
+    /// A proposal as (proposer, voting start timestamp, voting end timestamp).
+    pub type Proposal = (AccountId, u64, u64);
+    /// Votes of a proposal as (for, against, abstain).
+    pub type ProposalVotes = (Balance, Balance, Balance);
+    /// Execution status of a proposal as (eta, executed, canceled), eta is 0 until queued.
+    pub type ProposalStatus = (u64, bool, bool);
+    /// Governance parameters as (voting delay, voting period, proposal threshold, quorum percentage,
+    /// timelock delay).
+    pub type GovernorParameters = (u64, u64, Balance, u128, u64);
+
+    #[ink(storage)]
+    pub struct Governor {
+        // PSP22Votes token (PANX) whose delegated votes are used for voting
+        token: AccountId,
+        // Voting delay and period and timelock delay in milliseconds, votes a proposer needs and percentage (0-100)
+        // of the token total supply which has to vote for or abstain for a proposal to pass.
+        // Lazy so an update by an executed proposal is not overwritten once execute stores the contract
+        parameters: Lazy<GovernorParameters>,
+        // Id of the next proposal to be created
+        next_proposal_id: u64,
+        // Proposer, voting start and voting end of each proposal
+        proposals: Mapping<u64, Proposal>,
+        // Calls executed by each proposal once passed
+        proposal_calls: Mapping<u64, Vec<ProposalCall>>,
+        // Votes for, against and abstaining of each proposal
+        proposal_votes: Mapping<u64, ProposalVotes>,
+        // Eta and executed and canceled flags of each proposal
+        proposal_status: Mapping<u64, ProposalStatus>,
+        // Accounts that already voted on a proposal
+        has_voted: Mapping<(u64, AccountId), ()>,
+    }
+
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum ProposalState {
+        Pending,   // Voting did not start yet
+        Active,    // Voting is ongoing
+        Canceled,  // Canceled by the proposer
+        Defeated,  // Voting ended without reaching quorum or majority
+        Succeeded, // Voting passed, the proposal can be queued
+        Queued,    // Waiting for the timelock delay to pass
+        Expired,   // Not executed within the grace period after its eta
+        Executed,  // All of the proposal calls were executed
+    }
+
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum VoteType {
+        Against,
+        For,
+        Abstain,
+    }
+
+    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum GovernorErrors {
+        CallerNotGovernor,      // Error code for changing parameters other than through a proposal
+        CallerNotProposer,      // Error code for canceling a proposal of another account
+        InvalidParameters,      // Error code for a zero voting period or a quorum percentage above 100
+        EmptyProposal,          // Error code for a proposal without calls
+        TooManyCalls,           // Error code for a proposal with more than MAX_PROPOSAL_CALLS calls
+        BelowProposalThreshold, // Error code for a proposer without enough votes
+        ProposalNotFound,       // Error code for a proposal id that does not exist
+        ProposalNotActive,      // Error code for voting outside of the voting period
+        ProposalNotSucceeded,   // Error code for queueing a proposal that did not pass
+        ProposalNotQueued,      // Error code for executing a proposal that is not queued or expired
+        ProposalNotCancelable,  // Error code for canceling a proposal whose voting already started
+        TimelockNotPassed,      // Error code for executing a proposal before its eta
+        AlreadyVoted,           // Error code for voting twice on the same proposal
+        VotesLookupFailed,      // Error code for a failed votes or total supply lookup on the token
+        CallFailed,             // Error code for a failed proposal call
+        Overflow,               // Error code for overflow
+    }
+
+    #[ink(event)]
+    pub struct ProposalCreated {
+        #[ink(topic)]
+        proposer: AccountId, // Account that created the proposal
+        proposal_id: u64,         // Id of the new proposal
+        calls: Vec<ProposalCall>, // Calls executed once the proposal passes
+        description: String,      // Human readable description of the proposal
+        vote_start: u64,          // Voting start timestamp in milliseconds
+        vote_end: u64,            // Voting end timestamp in milliseconds
+    }
+
+    #[ink(event)]
+    pub struct VoteCast {
+        #[ink(topic)]
+        voter: AccountId, // Account that voted
+        proposal_id: u64,  // Id of the proposal
+        support: VoteType, // Vote of the account
+        weight: Balance,   // Votes of the account at the voting start
+    }
+
+    #[ink(event)]
+    pub struct ProposalQueued {
+        proposal_id: u64, // Id of the queued proposal
+        eta: u64,         // Timestamp from which the proposal can be executed, in milliseconds
+    }
+
+    #[ink(event)]
+    pub struct ProposalExecuted {
+        proposal_id: u64, // Id of the executed proposal
+    }
+
+    #[ink(event)]
+    pub struct ProposalCanceled {
+        proposal_id: u64, // Id of the canceled proposal
+    }
+
+    #[ink(event)]
+    pub struct ParametersUpdated {
+        voting_delay: u64,           // New voting delay in milliseconds
+        voting_period: u64,          // New voting period in milliseconds
+        proposal_threshold: Balance, // New proposal threshold
+        quorum_percentage: u128,     // New quorum percentage
+        timelock_delay: u64,         // New timelock delay in milliseconds
+    }
+
+    impl Governor {
+        #[ink(constructor)]
+        pub fn new(
+            token: AccountId,            // Address of the PSP22Votes token contract (PANX)
+            voting_delay: u64,           // Voting delay in milliseconds
+            voting_period: u64,          // Voting period in milliseconds
+            proposal_threshold: Balance, // Votes needed to create a proposal
+            quorum_percentage: u128,     // Quorum as a percentage of the total supply
+            timelock_delay: u64,         // Timelock delay in milliseconds
+        ) -> Result<Self, GovernorErrors> {
+            Self::check_parameters(voting_period, quorum_percentage)?;
+
+            let mut instance = Self {
+                token,
+                parameters: Lazy::default(),
+                next_proposal_id: 0,
+                proposals: Mapping::default(),
+                proposal_calls: Mapping::default(),
+                proposal_votes: Mapping::default(),
+                proposal_status: Mapping::default(),
+                has_voted: Mapping::default(),
+            };
+            instance.parameters.set(&(
+                voting_delay,
+                voting_period,
+                proposal_threshold,
+                quorum_percentage,
+                timelock_delay,
+            ));
+
+            Ok(instance)
+        }
+
+        /// Creates a proposal executing `calls` once passed. The caller needs at least
+        /// `proposal_threshold` votes at the previous block.
+        #[ink(message)]
+        pub fn propose(
+            &mut self,
+            calls: Vec<ProposalCall>,
+            description: String,
+        ) -> Result<u64, GovernorErrors> {
+            if calls.is_empty() {
+                return Err(GovernorErrors::EmptyProposal);
+            }
+            if calls.len() > MAX_PROPOSAL_CALLS {
+                return Err(GovernorErrors::TooManyCalls);
+            }
+
+            let proposer = self.env().caller();
+            let now = self.get_current_timestamp();
+            let (voting_delay, voting_period, proposal_threshold, _, _) = self.get_parameters();
+
+            let proposer_votes = self.get_past_votes(proposer, now.saturating_sub(1))?;
+            if proposer_votes < proposal_threshold {
+                return Err(GovernorErrors::BelowProposalThreshold);
+            }
+
+            let vote_start = match now.checked_add(voting_delay) {
+                Some(result) => result,
+                None => return Err(GovernorErrors::Overflow),
+            };
+            let vote_end = match vote_start.checked_add(voting_period) {
+                Some(result) => result,
+                None => return Err(GovernorErrors::Overflow),
+            };
+
+            let proposal_id = self.next_proposal_id;
+            self.next_proposal_id = match proposal_id.checked_add(1) {
+                Some(result) => result,
+                None => return Err(GovernorErrors::Overflow),
+            };
+
+            self.proposals
+                .insert(proposal_id, &(proposer, vote_start, vote_end));
+            self.proposal_calls.insert(proposal_id, &calls);
+            self.proposal_votes.insert(proposal_id, &(0, 0, 0));
+            self.proposal_status.insert(proposal_id, &(0, false, false));
+
+            EmitEvent::<Governor>::emit_event(self.env(), ProposalCreated {
+                proposer,
+                proposal_id,
+                calls,
+                description,
+                vote_start,
+                vote_end,
+            });
+
+            Ok(proposal_id)
+        }
+
+        /// Votes on an active proposal with the caller's votes at the voting start.
+        #[ink(message)]
+        pub fn cast_vote(
+            &mut self,
+            proposal_id: u64,
+            support: VoteType,
+        ) -> Result<Balance, GovernorErrors> {
+            if self.state(proposal_id)? != ProposalState::Active {
+                return Err(GovernorErrors::ProposalNotActive);
+            }
+
+            let voter = self.env().caller();
+            if self.has_voted.contains((proposal_id, voter)) {
+                return Err(GovernorErrors::AlreadyVoted);
+            }
+
+            let (_, vote_start, _) = self.get_proposal_or_error(proposal_id)?;
+            let weight = self.get_past_votes(voter, vote_start)?;
+
+            let votes = add_vote(
+                self.proposal_votes.get(proposal_id).unwrap_or_default(),
+                support,
+                weight,
+            );
+            self.proposal_votes.insert(proposal_id, &votes);
+            self.has_voted.insert((proposal_id, voter), &());
+
+            EmitEvent::<Governor>::emit_event(self.env(), VoteCast {
+                voter,
+                proposal_id,
+                support,
+                weight,
+            });
+
+            Ok(weight)
+        }
+
+        /// Queues a succeeded proposal, which can be executed after `timelock_delay`.
+        #[ink(message)]
+        pub fn queue(&mut self, proposal_id: u64) -> Result<u64, GovernorErrors> {
+            if self.state(proposal_id)? != ProposalState::Succeeded {
+                return Err(GovernorErrors::ProposalNotSucceeded);
+            }
+
+            let (_, _, _, _, timelock_delay) = self.get_parameters();
+            let eta = match self.get_current_timestamp().checked_add(timelock_delay) {
+                Some(result) => result,
+                None => return Err(GovernorErrors::Overflow),
+            };
+            self.proposal_status
+                .insert(proposal_id, &(eta, false, false));
+
+            EmitEvent::<Governor>::emit_event(self.env(), ProposalQueued { proposal_id, eta });
+
+            Ok(eta)
+        }
+
+        /// Executes the calls of a queued proposal once its eta passed.
+        /// Fails, reverting all of the calls, if any of them fails.
+        #[ink(message, payable)]
+        pub fn execute(&mut self, proposal_id: u64) -> Result<(), GovernorErrors> {
+            if self.state(proposal_id)? != ProposalState::Queued {
+                return Err(GovernorErrors::ProposalNotQueued);
+            }
+
+            let (eta, _, _) = self.proposal_status.get(proposal_id).unwrap_or_default();
+            if self.get_current_timestamp() < eta {
+                return Err(GovernorErrors::TimelockNotPassed);
+            }
+
+            // Mark the proposal as executed before the calls so it can not be executed again by reentrancy
+            self.proposal_status
+                .insert(proposal_id, &(eta, true, false));
+
+            for (callee, selector, input, transferred_value, returns_result) in
+                self.proposal_calls.get(proposal_id).unwrap_or_default()
+            {
+                if !execute_call(callee, selector, &input, transferred_value, returns_result) {
+                    return Err(GovernorErrors::CallFailed);
+                }
+            }
+
+            EmitEvent::<Governor>::emit_event(self.env(), ProposalExecuted { proposal_id });
+
+            Ok(())
+        }
+
+        /// Cancels a proposal of the caller whose voting did not start yet. Once voting
+        /// started the outcome belongs to the voters, so the proposer can not withdraw it.
+        #[ink(message)]
+        pub fn cancel(&mut self, proposal_id: u64) -> Result<(), GovernorErrors> {
+            let (proposer, _, _) = self.get_proposal_or_error(proposal_id)?;
+            if self.env().caller() != proposer {
+                return Err(GovernorErrors::CallerNotProposer);
+            }
+
+            if self.state(proposal_id)? != ProposalState::Pending {
+                return Err(GovernorErrors::ProposalNotCancelable);
+            }
+
+            self.proposal_status.insert(proposal_id, &(0, false, true));
+
+            EmitEvent::<Governor>::emit_event(self.env(), ProposalCanceled { proposal_id });
+
+            Ok(())
+        }
+
+        /// Updates the governance parameters. Only callable by the governor itself,
+        /// i.e. by executing a passed proposal.
+        #[ink(message)]
+        pub fn set_parameters(
+            &mut self,
+            voting_delay: u64,
+            voting_period: u64,
+            proposal_threshold: Balance,
+            quorum_percentage: u128,
+            timelock_delay: u64,
+        ) -> Result<(), GovernorErrors> {
+            if self.env().caller() != self.env().account_id() {
+                return Err(GovernorErrors::CallerNotGovernor);
+            }
+            Self::check_parameters(voting_period, quorum_percentage)?;
+
+            self.parameters.set(&(
+                voting_delay,
+                voting_period,
+                proposal_threshold,
+                quorum_percentage,
+                timelock_delay,
+            ));
+
+            EmitEvent::<Governor>::emit_event(self.env(), ParametersUpdated {
+                voting_delay,
+                voting_period,
+                proposal_threshold,
+                quorum_percentage,
+                timelock_delay,
+            });
+
+            Ok(())
+        }
+
+        /// Returns the current state of a proposal.
+        #[ink(message)]
+        pub fn state(&self, proposal_id: u64) -> Result<ProposalState, GovernorErrors> {
+            let (_, vote_start, vote_end) = self.get_proposal_or_error(proposal_id)?;
+
+            proposal_state(
+                self.get_current_timestamp(),
+                vote_start,
+                vote_end,
+                self.proposal_status.get(proposal_id).unwrap_or_default(),
+                || self.proposal_passed(proposal_id, vote_start),
+            )
+        }
+
+        /// Returns the quorum for a voting starting at `timestamp`.
+        #[ink(message)]
+        pub fn quorum(&self, timestamp: u64) -> Result<Balance, GovernorErrors> {
+            let token: ink::contract_ref!(PSP22Votes) = self.token.into();
+            let total_supply = token
+                .get_past_total_supply(timestamp)
+                .map_err(|_| GovernorErrors::VotesLookupFailed)?;
+
+            let (_, _, _, quorum_percentage, _) = self.get_parameters();
+            match total_supply.checked_mul(quorum_percentage) {
+                Some(result) => Ok(result / 100),
+                None => Err(GovernorErrors::Overflow),
+            }
+        }
+
+        /// Returns a proposal as (proposer, voting start timestamp, voting end timestamp).
+        #[ink(message)]
+        pub fn get_proposal(&self, proposal_id: u64) -> Option<Proposal> {
+            self.proposals.get(proposal_id)
+        }
+
+        /// Returns the calls of a proposal.
+        #[ink(message)]
+        pub fn get_proposal_calls(&self, proposal_id: u64) -> Vec<ProposalCall> {
+            self.proposal_calls.get(proposal_id).unwrap_or_default()
+        }
+
+        /// Returns the votes of a proposal as (for, against, abstain).
+        #[ink(message)]
+        pub fn get_proposal_votes(&self, proposal_id: u64) -> ProposalVotes {
+            self.proposal_votes.get(proposal_id).unwrap_or_default()
+        }
+
+        /// Returns the eta of a proposal, 0 if it is not queued.
+        #[ink(message)]
+        pub fn get_proposal_eta(&self, proposal_id: u64) -> u64 {
+            let (eta, _, _) = self.proposal_status.get(proposal_id).unwrap_or_default();
+            eta
+        }
+
+        #[ink(message)]
+        pub fn has_voted(&self, proposal_id: u64, account: AccountId) -> bool {
+            self.has_voted.contains((proposal_id, account))
+        }
+
+        /// Returns the governance parameters as (voting delay, voting period, proposal threshold,
+        /// quorum percentage, timelock delay).
+        #[ink(message)]
+        pub fn get_parameters(&self) -> GovernorParameters {
+            self.parameters.get().unwrap_or_default()
+        }
+
+        #[ink(message)]
+        pub fn get_token(&self) -> AccountId {
+            self.token
+        }
+
+        #[ink(message)]
+        pub fn get_next_proposal_id(&self) -> u64 {
+            self.next_proposal_id
+        }
+
+        #[ink(message)]
+        pub fn get_current_timestamp(&self) -> u64 {
+            self.env().block_timestamp()
+        }
+
+        fn check_parameters(
+            voting_period: u64,
+            quorum_percentage: u128,
+        ) -> Result<(), GovernorErrors> {
+            if voting_period == 0 || quorum_percentage > 100 {
+                return Err(GovernorErrors::InvalidParameters);
+            }
+            Ok(())
+        }
+
+        fn get_proposal_or_error(&self, proposal_id: u64) -> Result<Proposal, GovernorErrors> {
+            match self.proposals.get(proposal_id) {
+                Some(proposal) => Ok(proposal),
+                None => Err(GovernorErrors::ProposalNotFound),
+            }
+        }
+
+        fn get_past_votes(
+            &self,
+            account: AccountId,
+            timestamp: u64,
+        ) -> Result<Balance, GovernorErrors> {
+            let token: ink::contract_ref!(PSP22Votes) = self.token.into();
+            token
+                .get_past_votes(account, timestamp)
+                .map_err(|_| GovernorErrors::VotesLookupFailed)
+        }
+
+        // Returns whether the voting of a proposal, which started at vote_start, passed
+        fn proposal_passed(
+            &self,
+            proposal_id: u64,
+            vote_start: u64,
+        ) -> Result<bool, GovernorErrors> {
+            let votes = self.proposal_votes.get(proposal_id).unwrap_or_default();
+
+            // The quorum is only looked up for a proposal with more votes for than against
+            let (for_votes, against_votes, _) = votes;
+            if for_votes <= against_votes {
+                return Ok(false);
+            }
+
+            Ok(votes_passed(votes, self.quorum(vote_start)?))
+        }
+    }
+
+    /// Returns the votes of a proposal once `weight` votes were cast with `support`.
+    fn add_vote(votes: ProposalVotes, support: VoteType, weight: Balance) -> ProposalVotes {
+        let (for_votes, against_votes, abstain_votes) = votes;
+        // Votes are limited by the token total supply so no overflow is possible
+        match support {
+            VoteType::For => (for_votes.saturating_add(weight), against_votes, abstain_votes),
+            VoteType::Against => (for_votes, against_votes.saturating_add(weight), abstain_votes),
+            VoteType::Abstain => (for_votes, against_votes, abstain_votes.saturating_add(weight)),
+        }
+    }
+
+    // A proposal passes with more votes for than against, and for and abstain votes reaching the quorum
+    fn votes_passed(votes: ProposalVotes, quorum: Balance) -> bool {
+        let (for_votes, against_votes, abstain_votes) = votes;
+        for_votes > against_votes && for_votes.saturating_add(abstain_votes) >= quorum
+    }
+
+    /// Returns the state at `now` of a proposal voted on between `vote_start` and `vote_end`.
+    /// `passed` is only called once the voting ended, as it looks the quorum up on the token.
+    fn proposal_state(
+        now: u64,
+        vote_start: u64,
+        vote_end: u64,
+        status: ProposalStatus,
+        passed: impl FnOnce() -> Result<bool, GovernorErrors>,
+    ) -> Result<ProposalState, GovernorErrors> {
+        let (eta, executed, canceled) = status;
+
+        if executed {
+            return Ok(ProposalState::Executed);
+        }
+        if canceled {
+            return Ok(ProposalState::Canceled);
+        }
+        if now <= vote_start {
+            return Ok(ProposalState::Pending);
+        }
+        if now <= vote_end {
+            return Ok(ProposalState::Active);
+        }
+        if !passed()? {
+            return Ok(ProposalState::Defeated);
+        }
+        if eta == 0 {
+            return Ok(ProposalState::Succeeded);
+        }
+        if now >= eta.saturating_add(EXECUTION_GRACE_PERIOD) {
+            return Ok(ProposalState::Expired);
+        }
+        Ok(ProposalState::Queued)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink::env::DefaultEnvironment;
+        use ink::env::test::{
+            default_accounts,
+            set_block_timestamp,
+            set_callee,
+            set_caller,
+        };
+
+        fn governor() -> Governor {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            Governor::new(accounts.django, 1000, 10000, 100, 4, 2000).unwrap()
+        }
+
+        #[ink::test]
+        fn invalid_parameters_are_rejected() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+
+            assert!(matches!(
+                Governor::new(accounts.django, 1000, 0, 100, 4, 2000),
+                Err(GovernorErrors::InvalidParameters)
+            ));
+            assert!(matches!(
+                Governor::new(accounts.django, 1000, 10000, 100, 101, 2000),
+                Err(GovernorErrors::InvalidParameters)
+            ));
+        }
+
+        #[ink::test]
+        fn parameters_can_only_be_set_by_the_governor() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let governor_address = accounts.charlie;
+            set_callee::<DefaultEnvironment>(governor_address);
+            let mut governor = governor();
+
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(
+                governor.set_parameters(0, 5000, 10, 10, 0),
+                Err(GovernorErrors::CallerNotGovernor)
+            );
+
+            set_caller::<DefaultEnvironment>(governor_address);
+            assert_eq!(
+                governor.set_parameters(0, 0, 10, 10, 0),
+                Err(GovernorErrors::InvalidParameters)
+            );
+            assert_eq!(governor.set_parameters(0, 5000, 10, 10, 0), Ok(()));
+            assert_eq!(governor.get_parameters(), (0, 5000, 10, 10, 0));
+        }
+
+        #[ink::test]
+        fn empty_and_oversized_proposals_are_rejected() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut governor = governor();
+            let call: ProposalCall = (accounts.eve, [0; 4], Vec::new(), 0, true);
+
+            assert_eq!(
+                governor.propose(Vec::new(), String::from("Nothing")),
+                Err(GovernorErrors::EmptyProposal)
+            );
+            assert_eq!(
+                governor.propose(vec![call; MAX_PROPOSAL_CALLS + 1], String::from("Too much")),
+                Err(GovernorErrors::TooManyCalls)
+            );
+            assert_eq!(governor.get_next_proposal_id(), 0);
+        }
+
+        #[ink::test]
+        fn unknown_proposals_are_rejected() {
+            let mut governor = governor();
+
+            assert_eq!(governor.state(0), Err(GovernorErrors::ProposalNotFound));
+            assert_eq!(
+                governor.cast_vote(0, VoteType::For),
+                Err(GovernorErrors::ProposalNotFound)
+            );
+            assert_eq!(governor.queue(0), Err(GovernorErrors::ProposalNotFound));
+            assert_eq!(governor.execute(0), Err(GovernorErrors::ProposalNotFound));
+            assert_eq!(governor.cancel(0), Err(GovernorErrors::ProposalNotFound));
+        }
+
+        #[ink::test]
+        fn proposals_can_only_be_canceled_while_pending() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut governor = governor();
+
+            // Proposed by alice at 5000 with the test parameters: voting from 6000 to 16000
+            for proposal_id in 0..2 {
+                governor
+                    .proposals
+                    .insert(proposal_id, &(accounts.alice, 6000, 16000));
+                governor
+                    .proposal_status
+                    .insert(proposal_id, &(0, false, false));
+            }
+
+            set_block_timestamp::<DefaultEnvironment>(5500);
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(governor.cancel(0), Err(GovernorErrors::CallerNotProposer));
+
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(governor.cancel(0), Ok(()));
+            assert_eq!(governor.state(0), Ok(ProposalState::Canceled));
+            assert_eq!(governor.cancel(0), Err(GovernorErrors::ProposalNotCancelable));
+
+            set_block_timestamp::<DefaultEnvironment>(6001);
+            assert_eq!(governor.state(1), Ok(ProposalState::Active));
+            assert_eq!(governor.cancel(1), Err(GovernorErrors::ProposalNotCancelable));
+            assert_eq!(governor.state(1), Ok(ProposalState::Active));
+        }
+
+        #[ink::test]
+        fn proposal_goes_through_voting_queueing_and_execution() {
+            // Proposed at 5000 with the test parameters: voting from 6000 to 16000, then a 2000 timelock
+            let (vote_start, vote_end) = (6000, 16000);
+            let not_looked_up = || -> Result<bool, GovernorErrors> { panic!("voting not ended") };
+
+            assert_eq!(
+                proposal_state(5500, vote_start, vote_end, (0, false, false), not_looked_up),
+                Ok(ProposalState::Pending)
+            );
+            assert_eq!(
+                proposal_state(6001, vote_start, vote_end, (0, false, false), not_looked_up),
+                Ok(ProposalState::Active)
+            );
+
+            let votes = add_vote((0, 0, 0), VoteType::For, 60);
+            let votes = add_vote(votes, VoteType::Against, 30);
+            let votes = add_vote(votes, VoteType::Abstain, 10);
+            assert_eq!(votes, (60, 30, 10));
+
+            // 4% quorum of a 1000 total supply
+            let passed = || Ok(votes_passed(votes, 40));
+            assert_eq!(
+                proposal_state(16001, vote_start, vote_end, (0, false, false), passed),
+                Ok(ProposalState::Succeeded)
+            );
+
+            // Queued at 16001, executable from its eta
+            let eta = 16001 + 2000;
+            assert_eq!(
+                proposal_state(17000, vote_start, vote_end, (eta, false, false), passed),
+                Ok(ProposalState::Queued)
+            );
+            assert_eq!(
+                proposal_state(eta, vote_start, vote_end, (eta, false, false), passed),
+                Ok(ProposalState::Queued)
+            );
+            assert_eq!(
+                proposal_state(eta, vote_start, vote_end, (eta, true, false), passed),
+                Ok(ProposalState::Executed)
+            );
+        }
+
+        #[ink::test]
+        fn failed_proposals_are_defeated_expired_or_canceled() {
+            let (vote_start, vote_end) = (6000, 16000);
+
+            // Majority and quorum are both needed, abstain votes only count for the quorum
+            assert!(!votes_passed((30, 30, 100), 0));
+            assert!(!votes_passed((50, 10, 0), 61));
+            assert!(votes_passed((50, 10, 11), 61));
+
+            assert_eq!(
+                proposal_state(16001, vote_start, vote_end, (0, false, false), || Ok(false)),
+                Ok(ProposalState::Defeated)
+            );
+            assert_eq!(
+                proposal_state(16001, vote_start, vote_end, (0, false, false), || {
+                    Err(GovernorErrors::VotesLookupFailed)
+                }),
+                Err(GovernorErrors::VotesLookupFailed)
+            );
+
+            let eta = 18001;
+            assert_eq!(
+                proposal_state(
+                    eta + EXECUTION_GRACE_PERIOD - 1,
+                    vote_start,
+                    vote_end,
+                    (eta, false, false),
+                    || Ok(true)
+                ),
+                Ok(ProposalState::Queued)
+            );
+            assert_eq!(
+                proposal_state(
+                    eta + EXECUTION_GRACE_PERIOD,
+                    vote_start,
+                    vote_end,
+                    (eta, false, false),
+                    || Ok(true)
+                ),
+                Ok(ProposalState::Expired)
+            );
+            assert_eq!(
+                proposal_state(6001, vote_start, vote_end, (0, false, true), || Ok(true)),
+                Ok(ProposalState::Canceled)
+            );
+        }
+    }
+
+    /// ink! end-to-end (E2E) tests
+    ///
+    /// cargo test --features e2e-tests -- --nocapture
+    #[cfg(all(test, feature = "e2e-tests"))]
+    mod e2e_tests {
+        use super::*;
+        use ink_e2e::build_message;
+        use psp22_mintable::mintable_token::MintableTokenRef;
+        use scale::Encode;
+        use std::{
+            thread,
+            time::Duration,
+        };
+
+        type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+        // Voting period and timelock delay of the tested governor, in milliseconds
+        const VOTING_PERIOD: u64 = 4000;
+        const TIMELOCK_DELAY: u64 = 2000;
+
+        fn get_alice_account_id() -> AccountId {
+            ink_e2e::account_id(ink_e2e::AccountKeyring::Alice)
+        }
+
+        // A call of the governor to its own set_parameters message
+        fn set_parameters_call(
+            governor: AccountId,
+            parameters: GovernorParameters,
+        ) -> ProposalCall {
+            (
+                governor,
+                ink::selector_bytes!("set_parameters"),
+                parameters.encode(),
+                0,
+                true,
+            )
+        }
+
+        /// Tests included in "proposals_change_the_governor_parameters":
+        /// 1. delegate on the PSP22Votes token gives alice her votes
+        /// 2. propose, cast_vote, queue and execute of a set_parameters proposal update get_parameters
+        /// 3. execute of a proposal whose call returns Err fails with CallFailed and keeps it queued
+        #[ink_e2e::test(additional_contracts = "../PSP22_token/mintable/Cargo.toml")]
+        async fn proposals_change_the_governor_parameters(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            // Alice holds the whole supply and delegates her votes to herself
+            let token_constructor = MintableTokenRef::new(1000000, None, None, 12, None);
+            let token_acc_id = client
+                .instantiate("psp22_mintable", &ink_e2e::alice(), token_constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let delegate = build_message::<MintableTokenRef>(token_acc_id.clone())
+                .call(|token| token.delegate(get_alice_account_id()));
+            client
+                .call(&ink_e2e::alice(), delegate, 0, None)
+                .await
+                .expect("delegate failed");
+
+            let governor_constructor =
+                GovernorRef::new(token_acc_id, 0, VOTING_PERIOD, 100, 4, TIMELOCK_DELAY);
+            let governor_acc_id = client
+                .instantiate("governor", &ink_e2e::alice(), governor_constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            // A zero voting period is rejected by set_parameters with InvalidParameters
+            let new_parameters = (0, 6000, 50, 10, 1000);
+            let calls = vec![
+                vec![set_parameters_call(governor_acc_id, (0, 0, 50, 10, 1000))],
+                vec![set_parameters_call(governor_acc_id, new_parameters)],
+            ];
+            for (proposal_id, proposal_calls) in calls.into_iter().enumerate() {
+                let propose = build_message::<GovernorRef>(governor_acc_id.clone()).call(|governor| {
+                    governor.propose(proposal_calls.clone(), String::from("Parameters"))
+                });
+                let propose_res = client
+                    .call(&ink_e2e::alice(), propose, 0, None)
+                    .await
+                    .expect("propose failed");
+                assert_eq!(propose_res.return_value(), Ok(proposal_id as u64));
+            }
+
+            // Without a voting delay the voting starts with the next block
+            for proposal_id in 0..2 {
+                let cast_vote = build_message::<GovernorRef>(governor_acc_id.clone())
+                    .call(|governor| governor.cast_vote(proposal_id, VoteType::For));
+                let cast_vote_res = client
+                    .call(&ink_e2e::alice(), cast_vote, 0, None)
+                    .await
+                    .expect("cast_vote failed");
+                assert_eq!(cast_vote_res.return_value(), Ok(1000000));
+            }
+
+            thread::sleep(Duration::from_millis(VOTING_PERIOD + 1000));
+            for proposal_id in 0..2 {
+                let queue = build_message::<GovernorRef>(governor_acc_id.clone())
+                    .call(|governor| governor.queue(proposal_id));
+                let queue_res = client
+                    .call(&ink_e2e::alice(), queue, 0, None)
+                    .await
+                    .expect("queue failed");
+                assert!(queue_res.return_value().is_ok());
+            }
+
+            thread::sleep(Duration::from_millis(TIMELOCK_DELAY + 1000));
+            let execute_invalid = build_message::<GovernorRef>(governor_acc_id.clone())
+                .call(|governor| governor.execute(0));
+            let execute_invalid_res = client
+                .call(&ink_e2e::alice(), execute_invalid, 0, None)
+                .await
+                .expect("execute failed");
+            assert_eq!(
+                execute_invalid_res.return_value(),
+                Err(GovernorErrors::CallFailed)
+            );
+
+            // The failed execution was reverted, so the proposal is still queued
+            let state = build_message::<GovernorRef>(governor_acc_id.clone())
+                .call(|governor| governor.state(0));
+            let state_res = client.call_dry_run(&ink_e2e::alice(), &state, 0, None).await;
+            assert_eq!(state_res.return_value(), Ok(ProposalState::Queued));
+
+            let execute = build_message::<GovernorRef>(governor_acc_id.clone())
+                .call(|governor| governor.execute(1));
+            let execute_res = client
+                .call(&ink_e2e::alice(), execute, 0, None)
+                .await
+                .expect("execute failed");
+            assert_eq!(execute_res.return_value(), Ok(()));
+
+            let get_parameters = build_message::<GovernorRef>(governor_acc_id.clone())
+                .call(|governor| governor.get_parameters());
+            let get_parameters_res = client
+                .call_dry_run(&ink_e2e::alice(), &get_parameters, 0, None)
+                .await;
+            assert_eq!(get_parameters_res.return_value(), new_parameters);
+
+            Ok(())
+        }
+    }
+}
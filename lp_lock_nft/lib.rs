@@ -0,0 +1,571 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[openbrush::implementation(PSP34, PSP34Metadata)]
+#[openbrush::contract]
+pub mod lp_lock_nft {
+
+    use ink::prelude::format; // Importing format from ink prelude
+    use ink::prelude::vec; // Importing vec from ink prelude
+    use ink::storage::Mapping; // Importing Mapping from ink storage
+    use openbrush::{
+        contracts::traits::psp22::PSP22Ref,
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct LpLockNft {
+        #[storage_field]
+        psp34: psp34::Data,
+        #[storage_field]
+        metadata: metadata::Data,
+        // Id of the next lock certificate to be minted
+        next_lock_id: u128,
+        // Locked LP position of each certificate as (trading pair, LP amount, unlock timestamp in seconds)
+        locks: Mapping<u128, (AccountId, Balance, u64)>,
+        // Admin account address (allowed to manage the allowed trading pairs)
+        admin: AccountId,
+        // Trading pairs whose LP tokens can be locked
+        allowed_pairs: Mapping<AccountId, ()>,
+    }
+
+    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+    pub enum LpLockNftErrors {
+        ZeroLockedLp,            // Error code for locking zero LP tokens
+        PairNotAllowed,          // Error code for locking LP tokens of a pair not allowed by the admin
+        CallerNotAdmin,          // Error code for admin only functions called by non admin
+        InvalidUnlockTimestamp,  // Error code for a lock shortening or ending in the past
+        LpStillLocked,           // Error code for unlocking LP tokens before the unlock timestamp
+        LockNotFound,            // Error code for a certificate id that does not exist
+        CallerNotLockOwner,      // Error code for managing a lock certificate owned by another account
+        PSP22TransferFromFailed, // Error code for failed LP tokens transferFrom
+        PSP22TransferFailed,     // Error code for failed LP tokens transfer
+        PSP34MintFailed,         // Error code for failed lock certificate mint
+        PSP34BurnFailed,         // Error code for failed lock certificate burn
+        Overflow,                // Error code for overflow
+    }
+
+    #[ink(event)]
+    pub struct Transfer {
+        #[ink(topic)]
+        from: Option<AccountId>, // Certificate sender (`None` when minted)
+        #[ink(topic)]
+        to: Option<AccountId>, // Certificate recipient (`None` when burnt)
+        #[ink(topic)]
+        id: Id, // Id of the transferred certificate
+    }
+
+    #[ink(event)]
+    pub struct Approval {
+        #[ink(topic)]
+        from: AccountId, // Owner of the certificates
+        #[ink(topic)]
+        to: AccountId, // Account allowed to transfer the certificates
+        #[ink(topic)]
+        id: Option<Id>, // Approved certificate (`None` for all of the owner certificates)
+        approved: bool, // Whether the approval was given or revoked
+    }
+
+    #[ink(event)]
+    pub struct LpLocked {
+        #[ink(topic)]
+        owner: AccountId, // Account the lock certificate was minted to
+        #[ink(topic)]
+        pair: AccountId, // Trading pair whose LP tokens were locked
+        lock_id: u128, // Id of the minted lock certificate
+        amount: Balance, // Amount of LP tokens locked
+        unlock_timestamp: u64, // Unlock timestamp in seconds
+    }
+
+    #[ink(event)]
+    pub struct LpLockExtended {
+        #[ink(topic)]
+        owner: AccountId, // Owner of the lock certificate
+        lock_id: u128, // Id of the extended lock certificate
+        unlock_timestamp: u64, // New unlock timestamp in seconds
+    }
+
+    #[ink(event)]
+    pub struct PairAllowanceChanged {
+        #[ink(topic)]
+        pair: AccountId, // Trading pair allowed or disallowed by the admin
+        allowed: bool, // Whether LP tokens of the pair can be locked
+    }
+
+    #[ink(event)]
+    pub struct LpUnlocked {
+        #[ink(topic)]
+        owner: AccountId, // Owner who redeemed the lock certificate
+        #[ink(topic)]
+        pair: AccountId, // Trading pair whose LP tokens were released
+        lock_id: u128, // Id of the burnt lock certificate
+        amount: Balance, // Amount of LP tokens sent back to the owner
+    }
+
+    #[overrider(psp34::Internal)]
+    fn _emit_transfer_event(&self, from: Option<AccountId>, to: Option<AccountId>, id: Id) {
+        Self::env().emit_event(Transfer { from, to, id });
+    }
+
+    #[overrider(psp34::Internal)]
+    fn _emit_approval_event(&self, from: AccountId, to: AccountId, id: Option<Id>, approved: bool) {
+        Self::env().emit_event(Approval {
+            from,
+            to,
+            id,
+            approved,
+        });
+    }
+
+    impl LpLockNft {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut instance = Self::default();
+
+            instance.admin = Self::env().caller();
+
+            // Collection metadata displayed by explorers and wallets
+            let collection_id = psp34::PSP34::collection_id(&instance);
+            metadata::Internal::_set_attribute(
+                &mut instance,
+                collection_id.clone(),
+                String::from("name"),
+                String::from("Panorama LP Lock"),
+            );
+            metadata::Internal::_set_attribute(
+                &mut instance,
+                collection_id,
+                String::from("symbol"),
+                String::from("PLOCK"),
+            );
+
+            instance
+        }
+
+        /// function to lock `amount` of LP tokens of `pair` until `unlock_timestamp` (in seconds).
+        /// The LP tokens are held by this contract and a transferable lock certificate is minted to the caller.
+        /// The caller must approve this contract over the LP tokens first.
+        /// Only pairs allowed by the admin can be locked, so certificates can not be minted over any
+        /// PSP22 contract posing as a trading pair.
+        #[ink(message)]
+        pub fn lock(
+            &mut self,
+            pair: AccountId,
+            amount: Balance,
+            unlock_timestamp: u64,
+        ) -> Result<u128, LpLockNftErrors> {
+            let caller = self.env().caller();
+
+            if !self.is_pair_allowed(pair) {
+                return Err(LpLockNftErrors::PairNotAllowed)
+            }
+
+            if amount == 0 {
+                return Err(LpLockNftErrors::ZeroLockedLp)
+            }
+
+            // the lock must end in the future
+            if unlock_timestamp <= self.get_current_timestamp() {
+                return Err(LpLockNftErrors::InvalidUnlockTimestamp)
+            }
+
+            let lock_id = self.next_lock_id;
+
+            match lock_id.checked_add(1) {
+                Some(result) => {
+                    self.next_lock_id = result;
+                }
+                None => return Err(LpLockNftErrors::Overflow),
+            };
+
+            // cross contract call to the trading pair to transfer the LP tokens to this contract
+            if PSP22Ref::transfer_from(&pair, caller, Self::env().account_id(), amount, vec![])
+                .is_err()
+            {
+                return Err(LpLockNftErrors::PSP22TransferFromFailed)
+            }
+
+            if psp34::Internal::_mint_to(self, caller, Id::U128(lock_id)).is_err() {
+                return Err(LpLockNftErrors::PSP34MintFailed)
+            }
+
+            self.locks.insert(lock_id, &(pair, amount, unlock_timestamp));
+
+            self.set_lock_attributes(lock_id, pair, amount, unlock_timestamp);
+
+            Self::env().emit_event(LpLocked {
+                owner: caller,
+                pair,
+                lock_id,
+                amount,
+                unlock_timestamp,
+            });
+
+            Ok(lock_id)
+        }
+
+        /// function to extend the lock of certificate `lock_id` until `new_unlock_timestamp`.
+        /// Only the certificate owner can extend it, and a lock can never be shortened.
+        #[ink(message)]
+        pub fn extend_lock(
+            &mut self,
+            lock_id: u128,
+            new_unlock_timestamp: u64,
+        ) -> Result<(), LpLockNftErrors> {
+            let caller = self.env().caller();
+
+            let (pair, amount, unlock_timestamp) = self.get_owned_lock(caller, lock_id)?;
+
+            // the lock must end in the future and can never be shortened
+            if new_unlock_timestamp <= self.get_current_timestamp()
+                || new_unlock_timestamp < unlock_timestamp
+            {
+                return Err(LpLockNftErrors::InvalidUnlockTimestamp)
+            }
+
+            self.locks
+                .insert(lock_id, &(pair, amount, new_unlock_timestamp));
+
+            self.set_lock_attributes(lock_id, pair, amount, new_unlock_timestamp);
+
+            Self::env().emit_event(LpLockExtended {
+                owner: caller,
+                lock_id,
+                unlock_timestamp: new_unlock_timestamp,
+            });
+
+            Ok(())
+        }
+
+        /// function to burn certificate `lock_id` after its unlock timestamp and send the locked LP tokens
+        /// to the certificate owner.
+        #[ink(message)]
+        pub fn unlock(&mut self, lock_id: u128) -> Result<(), LpLockNftErrors> {
+            let caller = self.env().caller();
+
+            let (pair, amount, unlock_timestamp) = self.get_owned_lock(caller, lock_id)?;
+
+            if self.get_current_timestamp() < unlock_timestamp {
+                return Err(LpLockNftErrors::LpStillLocked)
+            }
+
+            if psp34::Internal::_burn_from(self, caller, Id::U128(lock_id)).is_err() {
+                return Err(LpLockNftErrors::PSP34BurnFailed)
+            }
+
+            self.locks.remove(lock_id);
+
+            // cross contract call to the trading pair to transfer the LP tokens back to the owner
+            if PSP22Ref::transfer(&pair, caller, amount, vec![]).is_err() {
+                return Err(LpLockNftErrors::PSP22TransferFailed)
+            }
+
+            Self::env().emit_event(LpUnlocked {
+                owner: caller,
+                pair,
+                lock_id,
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// function to get the locked position of certificate `lock_id` as
+        /// (trading pair, LP amount, unlock timestamp in seconds)
+        #[ink(message)]
+        pub fn get_lock(&self, lock_id: u128) -> Option<(AccountId, Balance, u64)> {
+            self.locks.get(lock_id)
+        }
+
+        /// function for the admin to allow or disallow locking LP tokens of `pair`.
+        /// Disallowing a pair does not affect its existing locks.
+        #[ink(message)]
+        pub fn set_pair_allowed(
+            &mut self,
+            pair: AccountId,
+            allowed: bool,
+        ) -> Result<(), LpLockNftErrors> {
+            if self.env().caller() != self.admin {
+                return Err(LpLockNftErrors::CallerNotAdmin)
+            }
+
+            if allowed {
+                self.allowed_pairs.insert(pair, &());
+            } else {
+                self.allowed_pairs.remove(pair);
+            }
+
+            Self::env().emit_event(PairAllowanceChanged { pair, allowed });
+
+            Ok(())
+        }
+
+        /// function to check whether LP tokens of `pair` can be locked
+        #[ink(message)]
+        pub fn is_pair_allowed(&self, pair: AccountId) -> bool {
+            self.allowed_pairs.contains(pair)
+        }
+
+        /// function to get the admin
+        #[ink(message)]
+        pub fn get_admin(&self) -> AccountId {
+            self.admin
+        }
+
+        /// function for the admin to hand over the admin role
+        #[ink(message)]
+        pub fn set_admin(&mut self, new_admin: AccountId) -> Result<(), LpLockNftErrors> {
+            if self.env().caller() != self.admin {
+                return Err(LpLockNftErrors::CallerNotAdmin)
+            }
+
+            self.admin = new_admin;
+
+            Ok(())
+        }
+
+        /// function to get the id the next lock certificate will be minted with
+        #[ink(message)]
+        pub fn get_next_lock_id(&self) -> u128 {
+            self.next_lock_id
+        }
+
+        /// function to get current timpstamp in seconds
+        #[ink(message)]
+        pub fn get_current_timestamp(&self) -> u64 {
+            let time_stamp_in_seconds = self.env().block_timestamp() / 1000;
+            time_stamp_in_seconds
+        }
+
+        /// function to get a lock position, making sure `caller` owns its certificate
+        fn get_owned_lock(
+            &self,
+            caller: AccountId,
+            lock_id: u128,
+        ) -> Result<(AccountId, Balance, u64), LpLockNftErrors> {
+            let lock = match self.locks.get(lock_id) {
+                Some(lock) => lock,
+                None => return Err(LpLockNftErrors::LockNotFound),
+            };
+
+            if psp34::PSP34::owner_of(self, Id::U128(lock_id)) != Some(caller) {
+                return Err(LpLockNftErrors::CallerNotLockOwner)
+            }
+
+            Ok(lock)
+        }
+
+        /// function to publish a lock position as certificate attributes (lock proof for explorers)
+        fn set_lock_attributes(
+            &mut self,
+            lock_id: u128,
+            pair: AccountId,
+            amount: Balance,
+            unlock_timestamp: u64,
+        ) {
+            let pair_bytes: &[u8; 32] = pair.as_ref();
+            let pair_hex: String = pair_bytes
+                .iter()
+                .map(|byte| format!("{:02x}", byte))
+                .collect();
+
+            metadata::Internal::_set_attribute(
+                self,
+                Id::U128(lock_id),
+                String::from("pair"),
+                format!("0x{}", pair_hex),
+            );
+            metadata::Internal::_set_attribute(
+                self,
+                Id::U128(lock_id),
+                String::from("amount"),
+                format!("{}", amount),
+            );
+            metadata::Internal::_set_attribute(
+                self,
+                Id::U128(lock_id),
+                String::from("unlock_timestamp"),
+                format!("{}", unlock_timestamp),
+            );
+        }
+    }
+
+    /// ink! end-to-end (E2E) tests
+    ///
+    /// cargo test --features e2e-tests -- --nocapture
+    #[cfg(all(test, feature = "e2e-tests"))]
+    mod e2e_tests {
+        use super::*;
+        use ink::primitives::AccountId;
+        use ink_e2e::build_message;
+        use my_psp22::my_psp22::MyPsp22Ref;
+        use openbrush::contracts::psp22::psp22_external::PSP22;
+        use openbrush::contracts::psp34::{
+            extensions::metadata::psp34metadata_external::PSP34Metadata,
+            psp34_external::PSP34,
+        };
+
+        type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+        fn get_alice_account_id() -> AccountId {
+            let alice = ink_e2e::alice::<ink_e2e::PolkadotConfig>();
+            let alice_account_id_32 = alice.account_id();
+            let alice_account_id = AccountId::try_from(alice_account_id_32.as_ref()).unwrap();
+
+            alice_account_id
+        }
+
+        fn get_bob_account_id() -> AccountId {
+            let bob = ink_e2e::bob::<ink_e2e::PolkadotConfig>();
+            let bob_account_id_32 = bob.account_id();
+            let bob_account_id = AccountId::try_from(bob_account_id_32.as_ref()).unwrap();
+
+            bob_account_id
+        }
+
+        /// Tests included in 'lock_works'
+        /// 1. lock (of a pair not allowed by the admin)
+        /// 2. set_pair_allowed (by the admin and by another account)
+        /// 3. lock
+        /// 4. get_lock
+        /// 5. owner_of
+        /// 6. get_attribute
+        /// 7. transfer (certificate)
+        /// 8. unlock (before the unlock timestamp)
+        #[ink_e2e::test(additional_contracts = "../my_psp22/Cargo.toml")]
+        async fn lock_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            // Create a PSP22 contract instance standing in for the LP tokens of a trading pair
+            let psp22_constructor = MyPsp22Ref::new(
+                10000000000000000,
+                Some(String::from("AZERO-TKN-LP").into()),
+                Some(String::from("AZERO-TKN-LP").into()),
+                12,
+            );
+            let pair_acc_id = client
+                .instantiate("my_psp22", &ink_e2e::alice(), psp22_constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            // Create a LpLockNft contract instance
+            let lock_nft_constructor = LpLockNftRef::new();
+            let lock_nft_acc_id = client
+                .instantiate("lp_lock_nft", &ink_e2e::alice(), lock_nft_constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            // Approve the lock contract over Alice LP tokens
+            let approve = build_message::<MyPsp22Ref>(pair_acc_id.clone())
+                .call(|my_psp22| my_psp22.approve(lock_nft_acc_id.clone(), 1000000000000));
+            client
+                .call(&ink_e2e::alice(), approve, 0, None)
+                .await
+                .expect("calling `approve` failed");
+
+            // Locking fails until the admin allows the pair
+            let unlock_timestamp: u64 = 4102444800;
+            let lock = build_message::<LpLockNftRef>(lock_nft_acc_id.clone()).call(|lp_lock_nft| {
+                lp_lock_nft.lock(pair_acc_id.clone(), 1000000000000, unlock_timestamp)
+            });
+            let lock_res = client
+                .call_dry_run(&ink_e2e::alice(), &lock, 0, None)
+                .await;
+            assert_eq!(lock_res.return_value(), Err(LpLockNftErrors::PairNotAllowed));
+
+            // Only the admin (Alice, the deployer) can allow a pair
+            let set_pair_allowed = build_message::<LpLockNftRef>(lock_nft_acc_id.clone())
+                .call(|lp_lock_nft| lp_lock_nft.set_pair_allowed(pair_acc_id.clone(), true));
+            let set_pair_allowed_res = client
+                .call_dry_run(&ink_e2e::bob(), &set_pair_allowed, 0, None)
+                .await;
+            assert_eq!(
+                set_pair_allowed_res.return_value(),
+                Err(LpLockNftErrors::CallerNotAdmin)
+            );
+            client
+                .call(&ink_e2e::alice(), set_pair_allowed, 0, None)
+                .await
+                .expect("calling `set_pair_allowed` failed");
+
+            // Lock Alice LP tokens far in the future
+            client
+                .call(&ink_e2e::alice(), lock, 0, None)
+                .await
+                .expect("calling `lock` failed");
+
+            // Fetch the locked position of the certificate
+            let get_lock = build_message::<LpLockNftRef>(lock_nft_acc_id.clone())
+                .call(|lp_lock_nft| lp_lock_nft.get_lock(0));
+            let get_lock_res = client
+                .call(&ink_e2e::alice(), get_lock, 0, None)
+                .await
+                .expect("get_lock failed");
+            assert_eq!(
+                get_lock_res.return_value(),
+                Some((pair_acc_id.clone(), 1000000000000, unlock_timestamp))
+            );
+
+            // Alice owns the certificate
+            let owner_of = build_message::<LpLockNftRef>(lock_nft_acc_id.clone())
+                .call(|lp_lock_nft| lp_lock_nft.owner_of(Id::U128(0)));
+            let owner_of_res = client
+                .call(&ink_e2e::alice(), owner_of, 0, None)
+                .await
+                .expect("owner_of failed");
+            assert_eq!(owner_of_res.return_value(), Some(get_alice_account_id()));
+
+            // The lock amount is published as a certificate attribute
+            let get_attribute = build_message::<LpLockNftRef>(lock_nft_acc_id.clone()).call(
+                |lp_lock_nft| lp_lock_nft.get_attribute(Id::U128(0), String::from("amount")),
+            );
+            let get_attribute_res = client
+                .call(&ink_e2e::alice(), get_attribute, 0, None)
+                .await
+                .expect("get_attribute failed");
+            assert_eq!(
+                get_attribute_res.return_value(),
+                Some(String::from("1000000000000"))
+            );
+
+            // The locked LP tokens are held by the lock contract
+            let balance_of = build_message::<MyPsp22Ref>(pair_acc_id.clone())
+                .call(|my_psp22| my_psp22.balance_of(lock_nft_acc_id.clone()));
+            let balance_of_res = client
+                .call(&ink_e2e::alice(), balance_of, 0, None)
+                .await
+                .expect("balance_of failed");
+            assert_eq!(balance_of_res.return_value(), 1000000000000);
+
+            // Transfer the certificate to Bob
+            let transfer = build_message::<LpLockNftRef>(lock_nft_acc_id.clone()).call(
+                |lp_lock_nft| lp_lock_nft.transfer(get_bob_account_id(), Id::U128(0), vec![]),
+            );
+            client
+                .call(&ink_e2e::alice(), transfer, 0, None)
+                .await
+                .expect("calling `transfer` failed");
+
+            // The previous owner can not unlock the certificate
+            let unlock = build_message::<LpLockNftRef>(lock_nft_acc_id.clone())
+                .call(|lp_lock_nft| lp_lock_nft.unlock(0));
+            let unlock_res = client
+                .call_dry_run(&ink_e2e::alice(), &unlock, 0, None)
+                .await;
+            assert_eq!(
+                unlock_res.return_value(),
+                Err(LpLockNftErrors::CallerNotLockOwner)
+            );
+
+            // Bob can not unlock before the unlock timestamp
+            let unlock_res = client.call_dry_run(&ink_e2e::bob(), &unlock, 0, None).await;
+            assert_eq!(unlock_res.return_value(), Err(LpLockNftErrors::LpStillLocked));
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,687 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[ink::contract]
+pub mod multi_sig {
+
+    use call_executor::execute_call;
+    use ink::prelude::vec::Vec;
+    use ink::storage::{
+        Lazy,
+        Mapping,
+    };
+
+    // Maximal number of owners of a wallet
+    pub const MAX_OWNERS: u32 = 50;
+
+    #[derive(Debug, PartialEq, Eq, Clone, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum Transaction {
+        // Cross-contract call as (callee, message selector, SCALE encoded arguments, transferred AZERO,
+        // whether the message returns a `Result`, whose `Err` then fails the call)
+        Call(AccountId, [u8; 4], Vec<u8>, Balance, bool),
+        // Native AZERO transfer as (recipient, amount)
+        Transfer(AccountId, Balance),
+    }
+
+    #[ink(storage)]
+    pub struct MultiSig {
+        // Owners allowed to submit, confirm and execute transactions.
+        // Owners and threshold are Lazy so changes by an executed transaction are not overwritten
+        // once execute_transaction stores the wallet
+        owners: Lazy<Vec<AccountId>>,
+        // Number of owner confirmations needed to execute a transaction
+        threshold: Lazy<u32>,
+        // Id of the next transaction to be submitted
+        next_transaction_id: u64,
+        // Submitted transactions which were not executed yet
+        transactions: Mapping<u64, Transaction>,
+        // Owner confirmations of each transaction
+        confirmations: Mapping<(u64, AccountId), ()>,
+    }
+
+    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum MultiSigErrors {
+        CallerNotOwner,         // Error code for owner only functions called by non owner
+        CallerNotWallet,        // Error code for owner management other than through a wallet transaction
+        InvalidOwners,          // Error code for no owners, duplicated owners or more than MAX_OWNERS owners
+        InvalidThreshold,       // Error code for a zero threshold or a threshold above the number of owners
+        AlreadyOwner,           // Error code for adding an account that is already an owner
+        NotOwner,               // Error code for removing or replacing an account that is not an owner
+        TransactionNotFound,    // Error code for a transaction id that does not exist or was executed
+        AlreadyConfirmed,       // Error code for confirming a transaction twice
+        NotConfirmed,           // Error code for revoking a confirmation that was not given
+        NotEnoughConfirmations, // Error code for executing a transaction below the threshold
+        CallFailed,             // Error code for a failed cross-contract call
+        TransferFailed,         // Error code for a failed AZERO transfer
+        Overflow,               // Error code for overflow
+    }
+
+    #[ink(event)]
+    pub struct Submission {
+        #[ink(topic)]
+        transaction_id: u64, // Id of the submitted transaction
+        #[ink(topic)]
+        owner: AccountId, // Owner that submitted the transaction
+        transaction: Transaction, // Submitted transaction
+    }
+
+    #[ink(event)]
+    pub struct Confirmation {
+        #[ink(topic)]
+        transaction_id: u64, // Id of the confirmed transaction
+        #[ink(topic)]
+        owner: AccountId, // Owner that confirmed the transaction
+    }
+
+    #[ink(event)]
+    pub struct Revocation {
+        #[ink(topic)]
+        transaction_id: u64, // Id of the transaction
+        #[ink(topic)]
+        owner: AccountId, // Owner that revoked the confirmation
+    }
+
+    #[ink(event)]
+    pub struct Execution {
+        #[ink(topic)]
+        transaction_id: u64, // Id of the executed transaction
+        #[ink(topic)]
+        owner: AccountId, // Owner that executed the transaction
+    }
+
+    #[ink(event)]
+    pub struct Deposit {
+        #[ink(topic)]
+        sender: AccountId, // Account that deposited AZERO
+        value: Balance, // Amount of AZERO deposited
+    }
+
+    #[ink(event)]
+    pub struct OwnerAddition {
+        #[ink(topic)]
+        owner: AccountId, // New owner
+    }
+
+    #[ink(event)]
+    pub struct OwnerRemoval {
+        #[ink(topic)]
+        owner: AccountId, // Removed owner
+    }
+
+    #[ink(event)]
+    pub struct ThresholdChange {
+        threshold: u32, // New threshold
+    }
+
+    impl MultiSig {
+        #[ink(constructor)]
+        pub fn new(
+            owners: Vec<AccountId>, // Initial owners of the wallet
+            threshold: u32,         // Number of owner confirmations needed to execute a transaction
+        ) -> Result<Self, MultiSigErrors> {
+            let mut sorted_owners = owners.clone();
+            sorted_owners.sort_unstable();
+            sorted_owners.dedup();
+            if owners.is_empty()
+                || sorted_owners.len() != owners.len()
+                || owners.len() > MAX_OWNERS as usize
+            {
+                return Err(MultiSigErrors::InvalidOwners);
+            }
+            Self::check_threshold(threshold, owners.len())?;
+
+            let mut instance = Self {
+                owners: Lazy::default(),
+                threshold: Lazy::default(),
+                next_transaction_id: 0,
+                transactions: Mapping::default(),
+                confirmations: Mapping::default(),
+            };
+            instance.owners.set(&owners);
+            instance.threshold.set(&threshold);
+
+            Ok(instance)
+        }
+
+        /// Submits a transaction, confirmed by the submitting owner.
+        #[ink(message)]
+        pub fn submit_transaction(
+            &mut self,
+            transaction: Transaction,
+        ) -> Result<u64, MultiSigErrors> {
+            let owner = self.env().caller();
+            self.ensure_owner(owner)?;
+
+            let transaction_id = self.next_transaction_id;
+            self.next_transaction_id = match transaction_id.checked_add(1) {
+                Some(result) => result,
+                None => return Err(MultiSigErrors::Overflow),
+            };
+
+            self.transactions.insert(transaction_id, &transaction);
+
+            self.env().emit_event(Submission {
+                transaction_id,
+                owner,
+                transaction,
+            });
+
+            self.confirm_transaction(transaction_id)?;
+
+            Ok(transaction_id)
+        }
+
+        #[ink(message)]
+        pub fn confirm_transaction(&mut self, transaction_id: u64) -> Result<(), MultiSigErrors> {
+            let owner = self.env().caller();
+            self.ensure_owner(owner)?;
+            self.ensure_transaction(transaction_id)?;
+
+            if self.confirmations.contains((transaction_id, owner)) {
+                return Err(MultiSigErrors::AlreadyConfirmed);
+            }
+            self.confirmations.insert((transaction_id, owner), &());
+
+            self.env().emit_event(Confirmation {
+                transaction_id,
+                owner,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn revoke_confirmation(&mut self, transaction_id: u64) -> Result<(), MultiSigErrors> {
+            let owner = self.env().caller();
+            self.ensure_owner(owner)?;
+            self.ensure_transaction(transaction_id)?;
+
+            if !self.confirmations.contains((transaction_id, owner)) {
+                return Err(MultiSigErrors::NotConfirmed);
+            }
+            self.confirmations.remove((transaction_id, owner));
+
+            self.env().emit_event(Revocation {
+                transaction_id,
+                owner,
+            });
+
+            Ok(())
+        }
+
+        /// Executes a transaction confirmed by at least `threshold` of the current owners.
+        /// Fails, keeping the transaction pending, if the call or transfer fails.
+        #[ink(message, payable)]
+        pub fn execute_transaction(&mut self, transaction_id: u64) -> Result<(), MultiSigErrors> {
+            let owner = self.env().caller();
+            self.ensure_owner(owner)?;
+            let transaction = self.ensure_transaction(transaction_id)?;
+
+            if self.get_confirmation_count(transaction_id) < self.get_threshold() {
+                return Err(MultiSigErrors::NotEnoughConfirmations);
+            }
+
+            // Remove the transaction before executing it so it can not be executed again by reentrancy
+            self.transactions.remove(transaction_id);
+
+            match transaction {
+                Transaction::Call(callee, selector, input, transferred_value, returns_result) => {
+                    if !execute_call(callee, selector, &input, transferred_value, returns_result) {
+                        return Err(MultiSigErrors::CallFailed);
+                    }
+                }
+                Transaction::Transfer(to, value) => {
+                    if self.env().transfer(to, value).is_err() {
+                        return Err(MultiSigErrors::TransferFailed);
+                    }
+                }
+            }
+
+            self.env().emit_event(Execution {
+                transaction_id,
+                owner,
+            });
+
+            Ok(())
+        }
+
+        /// Deposits the transferred AZERO to the wallet.
+        #[ink(message, payable)]
+        pub fn deposit(&mut self) {
+            self.env().emit_event(Deposit {
+                sender: self.env().caller(),
+                value: self.env().transferred_value(),
+            });
+        }
+
+        /// Adds a new owner. Only callable by the wallet itself, i.e. through a confirmed transaction.
+        #[ink(message)]
+        pub fn add_owner(&mut self, new_owner: AccountId) -> Result<(), MultiSigErrors> {
+            self.ensure_wallet()?;
+            if self.is_owner(new_owner) {
+                return Err(MultiSigErrors::AlreadyOwner);
+            }
+            let mut owners = self.get_owners();
+            if owners.len() >= MAX_OWNERS as usize {
+                return Err(MultiSigErrors::InvalidOwners);
+            }
+
+            owners.push(new_owner);
+            self.owners.set(&owners);
+
+            self.env().emit_event(OwnerAddition { owner: new_owner });
+
+            Ok(())
+        }
+
+        /// Removes an owner, lowering the threshold if it would be above the number of owners.
+        /// Only callable by the wallet itself, i.e. through a confirmed transaction.
+        #[ink(message)]
+        pub fn remove_owner(&mut self, owner: AccountId) -> Result<(), MultiSigErrors> {
+            self.ensure_wallet()?;
+            let index = self.owner_index(owner)?;
+            let mut owners = self.get_owners();
+            if owners.len() == 1 {
+                return Err(MultiSigErrors::InvalidOwners);
+            }
+
+            owners.swap_remove(index);
+            self.owners.set(&owners);
+
+            self.env().emit_event(OwnerRemoval { owner });
+
+            if self.get_threshold() as usize > owners.len() {
+                let threshold = owners.len() as u32;
+                self.threshold.set(&threshold);
+                self.env().emit_event(ThresholdChange { threshold });
+            }
+
+            Ok(())
+        }
+
+        /// Replaces `old_owner` with `new_owner`. Only callable by the wallet itself,
+        /// i.e. through a confirmed transaction.
+        #[ink(message)]
+        pub fn replace_owner(
+            &mut self,
+            old_owner: AccountId,
+            new_owner: AccountId,
+        ) -> Result<(), MultiSigErrors> {
+            self.ensure_wallet()?;
+            let index = self.owner_index(old_owner)?;
+            if self.is_owner(new_owner) {
+                return Err(MultiSigErrors::AlreadyOwner);
+            }
+
+            let mut owners = self.get_owners();
+            owners[index] = new_owner;
+            self.owners.set(&owners);
+
+            self.env().emit_event(OwnerRemoval { owner: old_owner });
+            self.env().emit_event(OwnerAddition { owner: new_owner });
+
+            Ok(())
+        }
+
+        /// Changes the threshold. Only callable by the wallet itself, i.e. through a confirmed transaction.
+        #[ink(message)]
+        pub fn change_threshold(&mut self, threshold: u32) -> Result<(), MultiSigErrors> {
+            self.ensure_wallet()?;
+            Self::check_threshold(threshold, self.get_owners().len())?;
+
+            self.threshold.set(&threshold);
+
+            self.env().emit_event(ThresholdChange { threshold });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get_owners(&self) -> Vec<AccountId> {
+            self.owners.get().unwrap_or_default()
+        }
+
+        #[ink(message)]
+        pub fn is_owner(&self, account: AccountId) -> bool {
+            self.get_owners().contains(&account)
+        }
+
+        #[ink(message)]
+        pub fn get_threshold(&self) -> u32 {
+            self.threshold.get().unwrap_or_default()
+        }
+
+        /// Returns a transaction which was submitted and not executed yet.
+        #[ink(message)]
+        pub fn get_transaction(&self, transaction_id: u64) -> Option<Transaction> {
+            self.transactions.get(transaction_id)
+        }
+
+        #[ink(message)]
+        pub fn is_confirmed_by(&self, transaction_id: u64, owner: AccountId) -> bool {
+            self.confirmations.contains((transaction_id, owner))
+        }
+
+        /// Returns the number of confirmations of a transaction given by current owners.
+        #[ink(message)]
+        pub fn get_confirmation_count(&self, transaction_id: u64) -> u32 {
+            self.get_owners()
+                .iter()
+                .filter(|owner| self.confirmations.contains((transaction_id, **owner)))
+                .count() as u32
+        }
+
+        #[ink(message)]
+        pub fn get_next_transaction_id(&self) -> u64 {
+            self.next_transaction_id
+        }
+
+        fn check_threshold(threshold: u32, owners: usize) -> Result<(), MultiSigErrors> {
+            if threshold == 0 || threshold as usize > owners {
+                return Err(MultiSigErrors::InvalidThreshold);
+            }
+            Ok(())
+        }
+
+        fn ensure_owner(&self, account: AccountId) -> Result<(), MultiSigErrors> {
+            if !self.is_owner(account) {
+                return Err(MultiSigErrors::CallerNotOwner);
+            }
+            Ok(())
+        }
+
+        fn ensure_wallet(&self) -> Result<(), MultiSigErrors> {
+            if self.env().caller() != self.env().account_id() {
+                return Err(MultiSigErrors::CallerNotWallet);
+            }
+            Ok(())
+        }
+
+        fn ensure_transaction(&self, transaction_id: u64) -> Result<Transaction, MultiSigErrors> {
+            match self.transactions.get(transaction_id) {
+                Some(transaction) => Ok(transaction),
+                None => Err(MultiSigErrors::TransactionNotFound),
+            }
+        }
+
+        fn owner_index(&self, owner: AccountId) -> Result<usize, MultiSigErrors> {
+            match self.get_owners().iter().position(|account| *account == owner) {
+                Some(index) => Ok(index),
+                None => Err(MultiSigErrors::NotOwner),
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink::env::DefaultEnvironment;
+        use ink::env::test::{
+            default_accounts,
+            get_account_balance,
+            set_account_balance,
+            set_callee,
+            set_caller,
+        };
+
+        // Wallet at django's address owned by alice, bob and charlie with a threshold of 2
+        fn wallet() -> MultiSig {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_callee::<DefaultEnvironment>(accounts.django);
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            MultiSig::new(vec![accounts.alice, accounts.bob, accounts.charlie], 2).unwrap()
+        }
+
+        #[ink::test]
+        fn invalid_owners_and_threshold_are_rejected() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+
+            assert!(matches!(
+                MultiSig::new(Vec::new(), 1),
+                Err(MultiSigErrors::InvalidOwners)
+            ));
+            assert!(matches!(
+                MultiSig::new(vec![accounts.alice, accounts.alice], 1),
+                Err(MultiSigErrors::InvalidOwners)
+            ));
+            assert!(matches!(
+                MultiSig::new(vec![accounts.alice, accounts.bob], 0),
+                Err(MultiSigErrors::InvalidThreshold)
+            ));
+            assert!(matches!(
+                MultiSig::new(vec![accounts.alice, accounts.bob], 3),
+                Err(MultiSigErrors::InvalidThreshold)
+            ));
+        }
+
+        #[ink::test]
+        fn submit_confirm_and_revoke_work() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut wallet = wallet();
+            let transaction = Transaction::Transfer(accounts.eve, 100);
+
+            let transaction_id = wallet.submit_transaction(transaction.clone()).unwrap();
+            assert_eq!(wallet.get_transaction(transaction_id), Some(transaction));
+            assert_eq!(wallet.get_confirmation_count(transaction_id), 1);
+            assert_eq!(
+                wallet.confirm_transaction(transaction_id),
+                Err(MultiSigErrors::AlreadyConfirmed)
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(wallet.confirm_transaction(transaction_id), Ok(()));
+            assert_eq!(wallet.get_confirmation_count(transaction_id), 2);
+            assert_eq!(wallet.revoke_confirmation(transaction_id), Ok(()));
+            assert_eq!(
+                wallet.revoke_confirmation(transaction_id),
+                Err(MultiSigErrors::NotConfirmed)
+            );
+            assert_eq!(wallet.get_confirmation_count(transaction_id), 1);
+
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            assert_eq!(
+                wallet.submit_transaction(Transaction::Transfer(accounts.eve, 1)),
+                Err(MultiSigErrors::CallerNotOwner)
+            );
+            assert_eq!(
+                wallet.confirm_transaction(transaction_id),
+                Err(MultiSigErrors::CallerNotOwner)
+            );
+        }
+
+        #[ink::test]
+        fn transfer_is_executed_with_enough_confirmations() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut wallet = wallet();
+            set_account_balance::<DefaultEnvironment>(accounts.django, 1000);
+            let eve_balance = get_account_balance::<DefaultEnvironment>(accounts.eve).unwrap();
+
+            let transaction_id = wallet
+                .submit_transaction(Transaction::Transfer(accounts.eve, 100))
+                .unwrap();
+            assert_eq!(
+                wallet.execute_transaction(transaction_id),
+                Err(MultiSigErrors::NotEnoughConfirmations)
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.charlie);
+            assert_eq!(wallet.confirm_transaction(transaction_id), Ok(()));
+            assert_eq!(wallet.execute_transaction(transaction_id), Ok(()));
+
+            assert_eq!(
+                get_account_balance::<DefaultEnvironment>(accounts.eve),
+                Ok(eve_balance + 100)
+            );
+            assert_eq!(wallet.get_transaction(transaction_id), None);
+            assert_eq!(
+                wallet.execute_transaction(transaction_id),
+                Err(MultiSigErrors::TransactionNotFound)
+            );
+        }
+
+        #[ink::test]
+        fn owners_can_only_be_managed_by_the_wallet() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut wallet = wallet();
+
+            assert_eq!(
+                wallet.add_owner(accounts.eve),
+                Err(MultiSigErrors::CallerNotWallet)
+            );
+            assert_eq!(
+                wallet.change_threshold(1),
+                Err(MultiSigErrors::CallerNotWallet)
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.django);
+            assert_eq!(wallet.add_owner(accounts.eve), Ok(()));
+            assert_eq!(
+                wallet.add_owner(accounts.eve),
+                Err(MultiSigErrors::AlreadyOwner)
+            );
+            assert_eq!(wallet.replace_owner(accounts.eve, accounts.frank), Ok(()));
+            assert!(wallet.is_owner(accounts.frank));
+            assert!(!wallet.is_owner(accounts.eve));
+            assert_eq!(
+                wallet.remove_owner(accounts.eve),
+                Err(MultiSigErrors::NotOwner)
+            );
+            assert_eq!(
+                wallet.change_threshold(5),
+                Err(MultiSigErrors::InvalidThreshold)
+            );
+            assert_eq!(wallet.change_threshold(4), Ok(()));
+            assert_eq!(wallet.get_threshold(), 4);
+        }
+
+        #[ink::test]
+        fn removing_owners_lowers_threshold_and_drops_confirmations() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut wallet = wallet();
+            let transaction_id = wallet
+                .submit_transaction(Transaction::Transfer(accounts.eve, 100))
+                .unwrap();
+
+            set_caller::<DefaultEnvironment>(accounts.django);
+            assert_eq!(wallet.change_threshold(3), Ok(()));
+            assert_eq!(wallet.remove_owner(accounts.alice), Ok(()));
+
+            assert_eq!(wallet.get_threshold(), 2);
+            assert_eq!(wallet.get_owners().len(), 2);
+            // Confirmations of removed owners do not count
+            assert_eq!(wallet.get_confirmation_count(transaction_id), 0);
+        }
+    }
+
+    /// ink! end-to-end (E2E) tests
+    ///
+    /// cargo test --features e2e-tests -- --nocapture
+    #[cfg(all(test, feature = "e2e-tests"))]
+    mod e2e_tests {
+        use super::*;
+        use ink_e2e::build_message;
+        use scale::Encode;
+
+        type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+        fn get_alice_account_id() -> AccountId {
+            ink_e2e::account_id(ink_e2e::AccountKeyring::Alice)
+        }
+
+        fn get_bob_account_id() -> AccountId {
+            ink_e2e::account_id(ink_e2e::AccountKeyring::Bob)
+        }
+
+        /// Tests included in "calls_to_the_wallet_work":
+        /// 1. execute_transaction of a call returning Err fails with CallFailed and keeps the transaction pending
+        /// 2. execute_transaction of a call returning Ok executes it
+        /// 3. the owner added by the executed call is kept by the wallet
+        #[ink_e2e::test]
+        async fn calls_to_the_wallet_work(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            // Create a wallet owned by alice only
+            let wallet_constructor = MultiSigRef::new(vec![get_alice_account_id()], 1);
+
+            let wallet_acc_id = client
+                .instantiate("multi_sig", &ink_e2e::alice(), wallet_constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            // change_threshold(2) returns Err(InvalidThreshold) with a single owner
+            let invalid_threshold_call = Transaction::Call(
+                wallet_acc_id,
+                ink::selector_bytes!("change_threshold"),
+                2u32.encode(),
+                0,
+                true,
+            );
+            let submit_invalid_threshold = build_message::<MultiSigRef>(wallet_acc_id.clone())
+                .call(|wallet| wallet.submit_transaction(invalid_threshold_call.clone()));
+            client
+                .call(&ink_e2e::alice(), submit_invalid_threshold, 0, None)
+                .await
+                .expect("submit_transaction failed");
+
+            let execute_invalid_threshold = build_message::<MultiSigRef>(wallet_acc_id.clone())
+                .call(|wallet| wallet.execute_transaction(0));
+            let execute_invalid_threshold_res = client
+                .call(&ink_e2e::alice(), execute_invalid_threshold, 0, None)
+                .await
+                .expect("execute_transaction failed");
+            assert_eq!(
+                execute_invalid_threshold_res.return_value(),
+                Err(MultiSigErrors::CallFailed)
+            );
+
+            // The failed execution was reverted, so the transaction is still pending
+            let get_transaction = build_message::<MultiSigRef>(wallet_acc_id.clone())
+                .call(|wallet| wallet.get_transaction(0));
+            let get_transaction_res = client
+                .call_dry_run(&ink_e2e::alice(), &get_transaction, 0, None)
+                .await;
+            assert_eq!(
+                get_transaction_res.return_value(),
+                Some(invalid_threshold_call)
+            );
+
+            // add_owner(bob) returns Ok
+            let add_owner_call = Transaction::Call(
+                wallet_acc_id,
+                ink::selector_bytes!("add_owner"),
+                get_bob_account_id().encode(),
+                0,
+                true,
+            );
+            let submit_add_owner = build_message::<MultiSigRef>(wallet_acc_id.clone())
+                .call(|wallet| wallet.submit_transaction(add_owner_call.clone()));
+            client
+                .call(&ink_e2e::alice(), submit_add_owner, 0, None)
+                .await
+                .expect("submit_transaction failed");
+
+            let execute_add_owner = build_message::<MultiSigRef>(wallet_acc_id.clone())
+                .call(|wallet| wallet.execute_transaction(1));
+            let execute_add_owner_res = client
+                .call(&ink_e2e::alice(), execute_add_owner, 0, None)
+                .await
+                .expect("execute_transaction failed");
+            assert_eq!(execute_add_owner_res.return_value(), Ok(()));
+
+            let get_owners = build_message::<MultiSigRef>(wallet_acc_id.clone())
+                .call(|wallet| wallet.get_owners());
+            let get_owners_res = client
+                .call_dry_run(&ink_e2e::alice(), &get_owners, 0, None)
+                .await;
+            assert_eq!(
+                get_owners_res.return_value(),
+                vec![get_alice_account_id(), get_bob_account_id()]
+            );
+
+            Ok(())
+        }
+    }
+}
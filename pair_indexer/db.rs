@@ -0,0 +1,223 @@
+//! SQLite schema and storage helpers.
+//!
+//! Balances are `u128` and do not fit SQLite integers, so every amount is
+//! stored as a decimal string and all arithmetic on amounts happens in Rust.
+
+use rusqlite::{
+    params,
+    Connection,
+    OptionalExtension,
+};
+
+use crate::{
+    events::{
+        AccountId,
+        Balance,
+    },
+    IndexerError,
+};
+
+pub const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS replay_position (
+        id INTEGER PRIMARY KEY CHECK (id = 0),
+        block_number INTEGER NOT NULL,
+        event_index INTEGER NOT NULL
+    );
+
+    CREATE TABLE IF NOT EXISTS contracts (
+        address TEXT PRIMARY KEY,
+        kind TEXT NOT NULL
+    );
+
+    CREATE TABLE IF NOT EXISTS pairs (
+        address TEXT PRIMARY KEY,
+        psp22_token TEXT,
+        deployer TEXT,
+        lp_fee TEXT,
+        created_block INTEGER,
+        a0_reserve TEXT NOT NULL DEFAULT '0',
+        psp22_reserve TEXT NOT NULL DEFAULT '0',
+        lp_total_supply TEXT NOT NULL DEFAULT '0',
+        a0_volume TEXT NOT NULL DEFAULT '0',
+        psp22_volume TEXT NOT NULL DEFAULT '0',
+        a0_lp_fees TEXT NOT NULL DEFAULT '0',
+        psp22_lp_fees TEXT NOT NULL DEFAULT '0',
+        a0_vault_fees TEXT NOT NULL DEFAULT '0',
+        psp22_vault_fees TEXT NOT NULL DEFAULT '0',
+        swap_count INTEGER NOT NULL DEFAULT 0,
+        last_block INTEGER
+    );
+
+    CREATE TABLE IF NOT EXISTS lp_balances (
+        pair TEXT NOT NULL,
+        account TEXT NOT NULL,
+        balance TEXT NOT NULL,
+        PRIMARY KEY (pair, account)
+    );
+
+    CREATE TABLE IF NOT EXISTS token_balances (
+        token TEXT NOT NULL,
+        account TEXT NOT NULL,
+        balance TEXT NOT NULL,
+        PRIMARY KEY (token, account)
+    );
+
+    CREATE TABLE IF NOT EXISTS swaps (
+        pair TEXT NOT NULL,
+        block_number INTEGER NOT NULL,
+        block_timestamp INTEGER NOT NULL,
+        trader TEXT NOT NULL,
+        direction TEXT NOT NULL,
+        amount_in TEXT NOT NULL,
+        amount_out TEXT NOT NULL,
+        lp_fee TEXT NOT NULL,
+        vault_fee_in TEXT NOT NULL,
+        vault_fee_out TEXT NOT NULL,
+        applied_fee TEXT NOT NULL
+    );
+
+    CREATE TABLE IF NOT EXISTS liquidity_changes (
+        pair TEXT NOT NULL,
+        block_number INTEGER NOT NULL,
+        block_timestamp INTEGER NOT NULL,
+        account TEXT NOT NULL,
+        kind TEXT NOT NULL,
+        a0_amount TEXT NOT NULL,
+        psp22_amount TEXT NOT NULL,
+        shares TEXT NOT NULL
+    );
+
+    CREATE TABLE IF NOT EXISTS reward_redemptions (
+        pair TEXT NOT NULL,
+        block_number INTEGER NOT NULL,
+        block_timestamp INTEGER NOT NULL,
+        owner TEXT NOT NULL,
+        recipient TEXT NOT NULL,
+        psp22_amount TEXT NOT NULL
+    );
+
+    CREATE TABLE IF NOT EXISTS incentive_rates (
+        pair TEXT NOT NULL,
+        block_number INTEGER NOT NULL,
+        block_timestamp INTEGER NOT NULL,
+        account TEXT NOT NULL,
+        psp22_daily_amount TEXT NOT NULL
+    );
+";
+
+/// Block number and index of the last event replayed into the database.
+pub fn replay_position(conn: &Connection) -> Result<Option<(u32, u32)>, IndexerError> {
+    let position = conn
+        .query_row(
+            "SELECT block_number, event_index FROM replay_position WHERE id = 0",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .optional()?;
+
+    Ok(position)
+}
+
+/// Records the block number and index of the last event replayed into the database.
+pub fn set_replay_position(conn: &Connection, position: (u32, u32)) -> Result<(), IndexerError> {
+    conn.execute(
+        "INSERT INTO replay_position (id, block_number, event_index) VALUES (0, ?1, ?2)
+         ON CONFLICT (id) DO UPDATE
+         SET block_number = excluded.block_number, event_index = excluded.event_index",
+        params![position.0, position.1],
+    )?;
+
+    Ok(())
+}
+
+/// Hex representation of an account id, as stored in the database.
+pub fn account_key(account: &AccountId) -> String {
+    format!("0x{}", hex::encode(account))
+}
+
+/// Parses an amount stored as a decimal string.
+pub fn parse_amount(value: &str) -> Result<Balance, IndexerError> {
+    value
+        .parse()
+        .map_err(|_| IndexerError::InvalidAmount(value.to_string()))
+}
+
+/// Adds `amount` to a pair amount column.
+pub fn add_to_pair(
+    conn: &Connection,
+    pair: &str,
+    column: &str,
+    amount: Balance,
+) -> Result<(), IndexerError> {
+    let current: String = conn.query_row(
+        &format!("SELECT {} FROM pairs WHERE address = ?1", column),
+        params![pair],
+        |row| row.get(0),
+    )?;
+
+    let new_amount = match parse_amount(&current)?.checked_add(amount) {
+        Some(result) => result,
+        None => return Err(IndexerError::Overflow),
+    };
+
+    conn.execute(
+        &format!("UPDATE pairs SET {} = ?1 WHERE address = ?2", column),
+        params![new_amount.to_string(), pair],
+    )?;
+
+    Ok(())
+}
+
+/// Applies a signed change to a balance of `table` keyed by (`owner_column`, `account`).
+/// A non `strict` balance is clamped at zero instead of failing when it would go below zero.
+#[allow(clippy::too_many_arguments)]
+pub fn change_balance(
+    conn: &Connection,
+    table: &str,
+    owner_column: &str,
+    owner: &str,
+    account: &AccountId,
+    increase: Balance,
+    decrease: Balance,
+    strict: bool,
+) -> Result<(), IndexerError> {
+    let account = account_key(account);
+
+    let current: Option<String> = conn
+        .query_row(
+            &format!(
+                "SELECT balance FROM {} WHERE {} = ?1 AND account = ?2",
+                table, owner_column
+            ),
+            params![owner, account],
+            |row| row.get(0),
+        )
+        .optional()?;
+
+    let current = match current {
+        Some(value) => parse_amount(&value)?,
+        None => 0,
+    };
+
+    let increased = match current.checked_add(increase) {
+        Some(result) => result,
+        None => return Err(IndexerError::Overflow),
+    };
+
+    let new_balance = match increased.checked_sub(decrease) {
+        Some(result) => result,
+        None if !strict => 0,
+        None => return Err(IndexerError::Overflow),
+    };
+
+    conn.execute(
+        &format!(
+            "INSERT INTO {} ({}, account, balance) VALUES (?1, ?2, ?3)
+             ON CONFLICT ({}, account) DO UPDATE SET balance = excluded.balance",
+            table, owner_column, owner_column
+        ),
+        params![owner, account, new_balance.to_string()],
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,164 @@
+//! Loading of stored block/event dumps.
+//!
+//! A dump is an ordered list of contract events, either as JSON:
+//!
+//! ```json
+//! [
+//!   {
+//!     "block_number": 42,
+//!     "event_index": 3,
+//!     "block_timestamp": 1690000000000,
+//!     "contract": "0x<32 bytes hex>",
+//!     "data": "0x<SCALE-encoded event>"
+//!   }
+//! ]
+//! ```
+//!
+//! or as the SCALE encoding of `Vec<RawEvent>` (any file not ending in `.json`).
+//!
+//! Events are identified by their block number and their index among the events
+//! of the block, so replays can tell which events a database already indexed.
+
+use std::path::Path;
+
+use scale::{
+    Decode,
+    Encode,
+};
+use serde::Deserialize;
+
+use crate::{
+    events::AccountId,
+    IndexerError,
+};
+
+/// A single contract event as stored in a dump.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct RawEvent {
+    pub block_number: u32,
+    // Index of the event among the events of its block
+    pub event_index: u32,
+    // Block timestamp in milliseconds
+    pub block_timestamp: u64,
+    // Contract that emitted the event
+    pub contract: AccountId,
+    // SCALE-encoded event (event index followed by the event fields)
+    pub data: Vec<u8>,
+}
+
+#[derive(Deserialize)]
+struct JsonEvent {
+    block_number: u32,
+    event_index: u32,
+    block_timestamp: u64,
+    contract: String,
+    data: String,
+}
+
+/// Loads a dump, picking the JSON or binary format from the file extension.
+pub fn load(path: &Path) -> Result<Vec<RawEvent>, IndexerError> {
+    let bytes = std::fs::read(path)?;
+
+    if path
+        .extension()
+        .is_some_and(|extension| extension == "json")
+    {
+        from_json(&bytes)
+    } else {
+        from_scale(&bytes)
+    }
+}
+
+/// Parses a JSON dump.
+pub fn from_json(bytes: &[u8]) -> Result<Vec<RawEvent>, IndexerError> {
+    let events: Vec<JsonEvent> = serde_json::from_slice(bytes)?;
+
+    events
+        .into_iter()
+        .map(|event| {
+            Ok(RawEvent {
+                block_number: event.block_number,
+                event_index: event.event_index,
+                block_timestamp: event.block_timestamp,
+                contract: parse_account_id(&event.contract)?,
+                data: parse_hex(&event.data)?,
+            })
+        })
+        .collect()
+}
+
+/// Parses a binary (SCALE-encoded) dump.
+pub fn from_scale(mut bytes: &[u8]) -> Result<Vec<RawEvent>, IndexerError> {
+    let events = Vec::<RawEvent>::decode(&mut bytes)?;
+
+    if !bytes.is_empty() {
+        return Err(IndexerError::TrailingBytes);
+    }
+
+    Ok(events)
+}
+
+/// Parses a `0x` prefixed (or bare) hex string.
+pub fn parse_hex(value: &str) -> Result<Vec<u8>, IndexerError> {
+    let value = value.strip_prefix("0x").unwrap_or(value);
+
+    hex::decode(value).map_err(|_| IndexerError::InvalidHex(value.to_string()))
+}
+
+/// Parses a hex encoded 32 bytes account id.
+pub fn parse_account_id(value: &str) -> Result<AccountId, IndexerError> {
+    parse_hex(value)?
+        .try_into()
+        .map_err(|_| IndexerError::InvalidHex(value.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event() -> RawEvent {
+        RawEvent {
+            block_number: 7,
+            event_index: 2,
+            block_timestamp: 1_690_000_000_000,
+            contract: [0xab; 32],
+            data: vec![2, 0, 1],
+        }
+    }
+
+    #[test]
+    fn json_dump_is_parsed() {
+        let json = format!(
+            r#"[{{"block_number": 7, "event_index": 2, "block_timestamp": 1690000000000, "contract": "0x{}", "data": "0x020001"}}]"#,
+            "ab".repeat(32)
+        );
+
+        assert_eq!(from_json(json.as_bytes()).unwrap(), vec![event()]);
+    }
+
+    #[test]
+    fn binary_dump_is_parsed() {
+        let bytes = vec![event()].encode();
+
+        assert_eq!(from_scale(&bytes).unwrap(), vec![event()]);
+    }
+
+    #[test]
+    fn binary_dump_with_trailing_bytes_is_rejected() {
+        let mut bytes = vec![event()].encode();
+        bytes.push(0);
+
+        assert!(matches!(
+            from_scale(&bytes),
+            Err(IndexerError::TrailingBytes)
+        ));
+    }
+
+    #[test]
+    fn invalid_account_id_is_rejected() {
+        assert!(matches!(
+            parse_account_id("0xabcd"),
+            Err(IndexerError::InvalidHex(_))
+        ));
+    }
+}
@@ -0,0 +1,146 @@
+//! SCALE layouts of the contract events consumed by the indexer.
+//!
+//! ink! 4 encodes an emitted event as the index of the event in its contract
+//! (declaration order) followed by all of the event fields, topics included.
+//! Every enum below mirrors the events of one contract in declaration order, so
+//! adding, removing or reordering an event in a contract must be reflected here.
+
+use scale::{
+    Decode,
+    Encode,
+};
+
+pub type AccountId = [u8; 32];
+pub type Balance = u128;
+
+/// Events of the `trading_pair_azero` contract.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub enum TradingPairEvent {
+    Transfer {
+        from: Option<AccountId>,
+        to: Option<AccountId>,
+        value: Balance,
+    },
+    Approval {
+        owner: AccountId,
+        spender: AccountId,
+        value: Balance,
+    },
+    LiquidityPoolProvision {
+        provider: AccountId,
+        a0_deposited_amount: Balance,
+        psp22_deposited_amount: Balance,
+        shares_given: Balance,
+        new_a0_reserve: Balance,
+        new_psp22_reserve: Balance,
+        new_total_supply: Balance,
+    },
+    LiquidityPoolWithdrawal {
+        caller: AccountId,
+        shares_given: Balance,
+        a0_given_amount: Balance,
+        psp22_given_amount: Balance,
+        a0_fee_given_amount: Balance,
+        psp22_fee_given_amount: Balance,
+        new_shares_balance: Balance,
+        new_a0_reserve: Balance,
+        new_psp22_reserve: Balance,
+        new_total_supply: Balance,
+    },
+    A0Swap {
+        caller: AccountId,
+        a0_deposited_amount: Balance,
+        psp22_given_amount: Balance,
+        psp22_given_to_vault: Balance,
+        a0_given_to_vault: Balance,
+        psp22_lp_fee: Balance,
+        applied_fee: Balance,
+        new_a0_reserve: Balance,
+        new_psp22_reserve: Balance,
+    },
+    PSP22Swap {
+        caller: AccountId,
+        psp22_deposited_amount: Balance,
+        a0_given_amount: Balance,
+        a0_given_to_vault: Balance,
+        psp22_given_to_vault: Balance,
+        a0_lp_fee: Balance,
+        applied_fee: Balance,
+        new_a0_reserve: Balance,
+        new_psp22_reserve: Balance,
+    },
+    StakingRewardsCompounded {
+        caller: AccountId,
+        psp22_compounded_amount: Balance,
+        psp22_swapped_amount: Balance,
+        shares_given: Balance,
+        new_a0_reserve: Balance,
+        new_psp22_reserve: Balance,
+        new_total_supply: Balance,
+    },
+    IncentiveProgramUpdated {
+        account: AccountId,
+        psp22_daily_amount: Balance,
+    },
+    RewardsRedeemed {
+        owner: AccountId,
+        recipient: AccountId,
+        psp22_redeemed_amount: Balance,
+    },
+}
+
+/// Events of the `contract_creator` (`PairCreator`) contract.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub enum PairCreatorEvent {
+    NewTPA {
+        caller: AccountId,
+        psp22_address: AccountId,
+        lp_fee: Balance,
+        pair_address: AccountId,
+    },
+    NewTPP {
+        caller: AccountId,
+        psp22_1_address: AccountId,
+        psp22_2_address: AccountId,
+        lp_fee: Balance,
+    },
+    NewMultiSig {
+        caller: AccountId,
+        owners: Vec<AccountId>,
+        threshold: u32,
+        multi_sig_address: AccountId,
+    },
+}
+
+/// Events of the `PSP22_token` contract.
+///
+/// Only the events shared by every token variant are listed here: the mintable token
+/// additionally emits `Snapshot`, `DelegateChanged` and `DelegateVotesChanged`, the
+/// pausable token `Paused` and `Unpaused`, all of them after `Transfer`. Their indices
+/// overlap between the variants, so they are skipped by [`Psp22Event::decode_known`].
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub enum Psp22Event {
+    Approval {
+        owner: AccountId,
+        spender: AccountId,
+        amount: Balance,
+    },
+    Transfer {
+        from: Option<AccountId>,
+        to: Option<AccountId>,
+        value: Balance,
+    },
+}
+
+impl Psp22Event {
+    /// Number of events shared by every token variant.
+    const KNOWN_EVENTS: u8 = 2;
+
+    /// Decodes a token event, returning `None` for the events of a token extension.
+    pub fn decode_known(data: &[u8]) -> Result<Option<Self>, scale::Error> {
+        match data.first() {
+            Some(index) if *index >= Self::KNOWN_EVENTS => Ok(None),
+            _ => Self::decode(&mut &data[..]).map(Some),
+        }
+    }
+}
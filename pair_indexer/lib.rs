@@ -0,0 +1,1010 @@
+//! Off-chain indexer rebuilding the Panorama Swap pools state from contract events.
+//!
+//! The indexer replays a stored dump of `TradingPairAzero`, `PairCreator` and
+//! PSP22 token events into an SQLite database holding the reserves, LP and token
+//! balances, volumes, fees and per-account reward history of every pair.
+//! Pairs and their PSP22 tokens are discovered from the `NewTPA` events of the
+//! registered pair creators, and can also be registered directly.
+
+pub mod db;
+pub mod dump;
+pub mod events;
+
+use std::{
+    fmt,
+    path::Path,
+};
+
+use rusqlite::{
+    params,
+    Connection,
+    OptionalExtension,
+};
+use scale::Decode;
+
+use crate::{
+    db::account_key,
+    dump::RawEvent,
+    events::{
+        AccountId,
+        PairCreatorEvent,
+        Psp22Event,
+        TradingPairEvent,
+    },
+};
+
+#[derive(Debug)]
+pub enum IndexerError {
+    Io(std::io::Error),          // Error reading the dump
+    Json(serde_json::Error),     // Error parsing a JSON dump
+    Scale(scale::Error),         // Error decoding a binary dump or an event
+    Sqlite(rusqlite::Error),     // Error reading or writing the database
+    InvalidHex(String),          // Error parsing a hex value of a JSON dump
+    InvalidAmount(String),       // Error parsing an amount stored in the database
+    TrailingBytes,               // Error for a binary dump with bytes after the events
+    Overflow,                    // Error for an amount overflowing or going below zero
+    UnknownContractKind(String), // Error for an unknown contract kind stored in the database
+}
+
+impl fmt::Display for IndexerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IndexerError::Io(error) => write!(f, "failed to read the dump: {}", error),
+            IndexerError::Json(error) => write!(f, "invalid JSON dump: {}", error),
+            IndexerError::Scale(error) => write!(f, "invalid SCALE data: {}", error),
+            IndexerError::Sqlite(error) => write!(f, "database error: {}", error),
+            IndexerError::InvalidHex(value) => write!(f, "invalid hex value: {}", value),
+            IndexerError::InvalidAmount(value) => write!(f, "invalid stored amount: {}", value),
+            IndexerError::TrailingBytes => write!(f, "trailing bytes after the dump events"),
+            IndexerError::Overflow => write!(f, "amount overflow"),
+            IndexerError::UnknownContractKind(kind) => {
+                write!(f, "unknown contract kind: {}", kind)
+            }
+        }
+    }
+}
+
+impl std::error::Error for IndexerError {}
+
+impl From<std::io::Error> for IndexerError {
+    fn from(error: std::io::Error) -> Self {
+        IndexerError::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for IndexerError {
+    fn from(error: serde_json::Error) -> Self {
+        IndexerError::Json(error)
+    }
+}
+
+impl From<scale::Error> for IndexerError {
+    fn from(error: scale::Error) -> Self {
+        IndexerError::Scale(error)
+    }
+}
+
+impl From<rusqlite::Error> for IndexerError {
+    fn from(error: rusqlite::Error) -> Self {
+        IndexerError::Sqlite(error)
+    }
+}
+
+/// Kind of a contract whose events are indexed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContractKind {
+    PairCreator, // `contract_creator` deploying the pairs
+    TradingPair, // `trading_pair_azero` pair (and LP token)
+    Psp22,       // PSP22 token traded in a pair
+}
+
+impl ContractKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ContractKind::PairCreator => "pair_creator",
+            ContractKind::TradingPair => "trading_pair",
+            ContractKind::Psp22 => "psp22",
+        }
+    }
+
+    fn from_str(kind: &str) -> Result<Self, IndexerError> {
+        match kind {
+            "pair_creator" => Ok(ContractKind::PairCreator),
+            "trading_pair" => Ok(ContractKind::TradingPair),
+            "psp22" => Ok(ContractKind::Psp22),
+            _ => Err(IndexerError::UnknownContractKind(kind.to_string())),
+        }
+    }
+}
+
+/// Number of dump events indexed and skipped by a replay
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ReplaySummary {
+    pub indexed: usize,         // Events applied to the database
+    pub skipped: usize,         // Events of unregistered contracts and token extension events
+    pub already_indexed: usize, // Events at or before the last event replayed into the database
+}
+
+pub struct Indexer {
+    conn: Connection,
+}
+
+impl Indexer {
+    /// Opens (or creates) the indexer database at `path`.
+    pub fn open(path: &Path) -> Result<Self, IndexerError> {
+        Self::with_connection(Connection::open(path)?)
+    }
+
+    /// Creates an indexer backed by an in-memory database.
+    pub fn in_memory() -> Result<Self, IndexerError> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    fn with_connection(conn: Connection) -> Result<Self, IndexerError> {
+        conn.execute_batch(db::SCHEMA)?;
+
+        Ok(Self { conn })
+    }
+
+    /// The underlying database, for analytics queries.
+    pub fn connection(&self) -> &Connection {
+        &self.conn
+    }
+
+    /// Registers a contract whose events should be indexed.
+    pub fn register(&self, address: &AccountId, kind: ContractKind) -> Result<(), IndexerError> {
+        Indexer::applier(&self.conn).register(address, kind)
+    }
+
+    /// Kind of a registered contract.
+    pub fn contract_kind(&self, address: &AccountId) -> Result<Option<ContractKind>, IndexerError> {
+        Indexer::applier(&self.conn).contract_kind(address)
+    }
+
+    /// Replays `events` in order within a single database transaction.
+    ///
+    /// Events at or before the last event replayed into the database are not applied
+    /// again, so the same or an overlapping dump can be replayed safely. Contracts have
+    /// to be registered before the events they emitted are replayed.
+    pub fn replay(&mut self, events: &[RawEvent]) -> Result<ReplaySummary, IndexerError> {
+        let tx = self.conn.transaction()?;
+        let applier = Indexer::applier(&tx);
+
+        let mut last_replayed = db::replay_position(&tx)?;
+        let mut summary = ReplaySummary::default();
+
+        for event in events {
+            let position = (event.block_number, event.event_index);
+            if last_replayed.is_some_and(|last| position <= last) {
+                summary.already_indexed += 1;
+                continue;
+            }
+
+            if applier.apply(event)? {
+                summary.indexed += 1;
+            } else {
+                summary.skipped += 1;
+            }
+            last_replayed = Some(position);
+        }
+
+        if let Some(position) = last_replayed {
+            db::set_replay_position(&tx, position)?;
+        }
+
+        tx.commit()?;
+
+        Ok(summary)
+    }
+
+    /// Block number and index of the last event replayed into the database.
+    pub fn last_replayed(&self) -> Result<Option<(u32, u32)>, IndexerError> {
+        db::replay_position(&self.conn)
+    }
+
+    /// Applies a single event, returning `false` when its contract is not registered
+    /// or it is a token extension event. Unlike `replay`, the event is applied even if
+    /// it was already replayed.
+    pub fn apply(&self, event: &RawEvent) -> Result<bool, IndexerError> {
+        Indexer::applier(&self.conn).apply(event)
+    }
+
+    fn applier(conn: &Connection) -> EventApplier<'_> {
+        EventApplier { conn }
+    }
+}
+
+struct EventApplier<'a> {
+    conn: &'a Connection,
+}
+
+impl EventApplier<'_> {
+    fn apply(&self, event: &RawEvent) -> Result<bool, IndexerError> {
+        let kind = match self.contract_kind(&event.contract)? {
+            Some(kind) => kind,
+            None => return Ok(false),
+        };
+
+        match kind {
+            ContractKind::PairCreator => {
+                self.apply_pair_creator(event, PairCreatorEvent::decode(&mut &event.data[..])?)?
+            }
+            ContractKind::TradingPair => {
+                self.apply_trading_pair(event, TradingPairEvent::decode(&mut &event.data[..])?)?
+            }
+            ContractKind::Psp22 => match Psp22Event::decode_known(&event.data)? {
+                Some(psp22_event) => self.apply_psp22(event, psp22_event)?,
+                None => return Ok(false),
+            },
+        }
+
+        Ok(true)
+    }
+
+    fn contract_kind(&self, address: &AccountId) -> Result<Option<ContractKind>, IndexerError> {
+        let kind: Option<String> = self
+            .conn
+            .query_row(
+                "SELECT kind FROM contracts WHERE address = ?1",
+                params![account_key(address)],
+                |row| row.get(0),
+            )
+            .optional()?;
+
+        kind.map(|kind| ContractKind::from_str(&kind)).transpose()
+    }
+
+    fn register(&self, address: &AccountId, kind: ContractKind) -> Result<(), IndexerError> {
+        let address = account_key(address);
+
+        self.conn.execute(
+            "INSERT OR REPLACE INTO contracts (address, kind) VALUES (?1, ?2)",
+            params![address, kind.as_str()],
+        )?;
+
+        if kind == ContractKind::TradingPair {
+            self.conn.execute(
+                "INSERT OR IGNORE INTO pairs (address) VALUES (?1)",
+                params![address],
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn apply_pair_creator(
+        &self,
+        raw: &RawEvent,
+        event: PairCreatorEvent,
+    ) -> Result<(), IndexerError> {
+        match event {
+            PairCreatorEvent::NewTPA {
+                caller,
+                psp22_address,
+                lp_fee,
+                pair_address,
+            } => {
+                self.register(&pair_address, ContractKind::TradingPair)?;
+                self.register(&psp22_address, ContractKind::Psp22)?;
+
+                self.conn.execute(
+                    "UPDATE pairs SET psp22_token = ?1, deployer = ?2, lp_fee = ?3, created_block = ?4
+                     WHERE address = ?5",
+                    params![
+                        account_key(&psp22_address),
+                        account_key(&caller),
+                        lp_fee.to_string(),
+                        raw.block_number,
+                        account_key(&pair_address)
+                    ],
+                )?;
+            }
+            // PSP22/PSP22 pairs are not indexed yet
+            PairCreatorEvent::NewTPP { .. } => {}
+            // multi sig wallets hold no pair state
+            PairCreatorEvent::NewMultiSig { .. } => {}
+        }
+
+        Ok(())
+    }
+
+    fn apply_trading_pair(
+        &self,
+        raw: &RawEvent,
+        event: TradingPairEvent,
+    ) -> Result<(), IndexerError> {
+        let pair = account_key(&raw.contract);
+
+        match event {
+            TradingPairEvent::Transfer { from, to, value } => {
+                if let Some(from) = from {
+                    db::change_balance(
+                        self.conn,
+                        "lp_balances",
+                        "pair",
+                        &pair,
+                        &from,
+                        0,
+                        value,
+                        true,
+                    )?;
+                }
+                if let Some(to) = to {
+                    db::change_balance(
+                        self.conn,
+                        "lp_balances",
+                        "pair",
+                        &pair,
+                        &to,
+                        value,
+                        0,
+                        true,
+                    )?;
+                }
+            }
+            TradingPairEvent::Approval { .. } => {}
+            TradingPairEvent::LiquidityPoolProvision {
+                provider,
+                a0_deposited_amount,
+                psp22_deposited_amount,
+                shares_given,
+                new_a0_reserve,
+                new_psp22_reserve,
+                new_total_supply,
+            } => {
+                self.insert_liquidity_change(
+                    raw,
+                    &provider,
+                    "provision",
+                    a0_deposited_amount,
+                    psp22_deposited_amount,
+                    shares_given,
+                )?;
+                self.set_pair_state(
+                    raw,
+                    new_a0_reserve,
+                    new_psp22_reserve,
+                    Some(new_total_supply),
+                )?;
+            }
+            TradingPairEvent::LiquidityPoolWithdrawal {
+                caller,
+                shares_given,
+                a0_given_amount,
+                psp22_given_amount,
+                new_a0_reserve,
+                new_psp22_reserve,
+                new_total_supply,
+                ..
+            } => {
+                self.insert_liquidity_change(
+                    raw,
+                    &caller,
+                    "withdrawal",
+                    a0_given_amount,
+                    psp22_given_amount,
+                    shares_given,
+                )?;
+                self.set_pair_state(
+                    raw,
+                    new_a0_reserve,
+                    new_psp22_reserve,
+                    Some(new_total_supply),
+                )?;
+            }
+            TradingPairEvent::A0Swap {
+                caller,
+                a0_deposited_amount,
+                psp22_given_amount,
+                psp22_given_to_vault,
+                a0_given_to_vault,
+                psp22_lp_fee,
+                applied_fee,
+                new_a0_reserve,
+                new_psp22_reserve,
+            } => {
+                self.insert_swap(
+                    raw,
+                    &caller,
+                    "a0_to_psp22",
+                    [
+                        a0_deposited_amount,
+                        psp22_given_amount,
+                        psp22_lp_fee,
+                        a0_given_to_vault,
+                        psp22_given_to_vault,
+                        applied_fee,
+                    ],
+                )?;
+                db::add_to_pair(self.conn, &pair, "a0_volume", a0_deposited_amount)?;
+                db::add_to_pair(self.conn, &pair, "psp22_lp_fees", psp22_lp_fee)?;
+                db::add_to_pair(self.conn, &pair, "a0_vault_fees", a0_given_to_vault)?;
+                db::add_to_pair(self.conn, &pair, "psp22_vault_fees", psp22_given_to_vault)?;
+                self.set_pair_state(raw, new_a0_reserve, new_psp22_reserve, None)?;
+            }
+            TradingPairEvent::PSP22Swap {
+                caller,
+                psp22_deposited_amount,
+                a0_given_amount,
+                a0_given_to_vault,
+                psp22_given_to_vault,
+                a0_lp_fee,
+                applied_fee,
+                new_a0_reserve,
+                new_psp22_reserve,
+            } => {
+                self.insert_swap(
+                    raw,
+                    &caller,
+                    "psp22_to_a0",
+                    [
+                        psp22_deposited_amount,
+                        a0_given_amount,
+                        a0_lp_fee,
+                        psp22_given_to_vault,
+                        a0_given_to_vault,
+                        applied_fee,
+                    ],
+                )?;
+                db::add_to_pair(self.conn, &pair, "psp22_volume", psp22_deposited_amount)?;
+                db::add_to_pair(self.conn, &pair, "a0_lp_fees", a0_lp_fee)?;
+                db::add_to_pair(self.conn, &pair, "a0_vault_fees", a0_given_to_vault)?;
+                db::add_to_pair(self.conn, &pair, "psp22_vault_fees", psp22_given_to_vault)?;
+                self.set_pair_state(raw, new_a0_reserve, new_psp22_reserve, None)?;
+            }
+            TradingPairEvent::StakingRewardsCompounded {
+                caller,
+                psp22_compounded_amount,
+                shares_given,
+                new_a0_reserve,
+                new_psp22_reserve,
+                new_total_supply,
+                ..
+            } => {
+                self.insert_liquidity_change(
+                    raw,
+                    &caller,
+                    "compound",
+                    0,
+                    psp22_compounded_amount,
+                    shares_given,
+                )?;
+                self.set_pair_state(
+                    raw,
+                    new_a0_reserve,
+                    new_psp22_reserve,
+                    Some(new_total_supply),
+                )?;
+            }
+            TradingPairEvent::IncentiveProgramUpdated {
+                account,
+                psp22_daily_amount,
+            } => {
+                self.conn.execute(
+                    "INSERT INTO incentive_rates
+                     (pair, block_number, block_timestamp, account, psp22_daily_amount)
+                     VALUES (?1, ?2, ?3, ?4, ?5)",
+                    params![
+                        pair,
+                        raw.block_number,
+                        raw.block_timestamp,
+                        account_key(&account),
+                        psp22_daily_amount.to_string()
+                    ],
+                )?;
+            }
+            TradingPairEvent::RewardsRedeemed {
+                owner,
+                recipient,
+                psp22_redeemed_amount,
+            } => {
+                self.conn.execute(
+                    "INSERT INTO reward_redemptions
+                     (pair, block_number, block_timestamp, owner, recipient, psp22_amount)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                    params![
+                        pair,
+                        raw.block_number,
+                        raw.block_timestamp,
+                        account_key(&owner),
+                        account_key(&recipient),
+                        psp22_redeemed_amount.to_string()
+                    ],
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn apply_psp22(&self, raw: &RawEvent, event: Psp22Event) -> Result<(), IndexerError> {
+        let token = account_key(&raw.contract);
+
+        // tokens are usually registered long after their creation, so their balances are
+        // tracked from the registration and clamped at zero
+        match event {
+            Psp22Event::Transfer { from, to, value } => {
+                if let Some(from) = from {
+                    db::change_balance(
+                        self.conn,
+                        "token_balances",
+                        "token",
+                        &token,
+                        &from,
+                        0,
+                        value,
+                        false,
+                    )?;
+                }
+                if let Some(to) = to {
+                    db::change_balance(
+                        self.conn,
+                        "token_balances",
+                        "token",
+                        &token,
+                        &to,
+                        value,
+                        0,
+                        false,
+                    )?;
+                }
+            }
+            Psp22Event::Approval { .. } => {}
+        }
+
+        Ok(())
+    }
+
+    fn set_pair_state(
+        &self,
+        raw: &RawEvent,
+        a0_reserve: u128,
+        psp22_reserve: u128,
+        lp_total_supply: Option<u128>,
+    ) -> Result<(), IndexerError> {
+        let pair = account_key(&raw.contract);
+
+        self.conn.execute(
+            "UPDATE pairs SET a0_reserve = ?1, psp22_reserve = ?2, last_block = ?3 WHERE address = ?4",
+            params![
+                a0_reserve.to_string(),
+                psp22_reserve.to_string(),
+                raw.block_number,
+                pair
+            ],
+        )?;
+
+        if let Some(lp_total_supply) = lp_total_supply {
+            self.conn.execute(
+                "UPDATE pairs SET lp_total_supply = ?1 WHERE address = ?2",
+                params![lp_total_supply.to_string(), pair],
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn insert_liquidity_change(
+        &self,
+        raw: &RawEvent,
+        account: &AccountId,
+        kind: &str,
+        a0_amount: u128,
+        psp22_amount: u128,
+        shares: u128,
+    ) -> Result<(), IndexerError> {
+        self.conn.execute(
+            "INSERT INTO liquidity_changes
+             (pair, block_number, block_timestamp, account, kind, a0_amount, psp22_amount, shares)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                account_key(&raw.contract),
+                raw.block_number,
+                raw.block_timestamp,
+                account_key(account),
+                kind,
+                a0_amount.to_string(),
+                psp22_amount.to_string(),
+                shares.to_string()
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    /// Records a swap, `amounts` being (amount in, amount out, LP fee, vault fee in,
+    /// vault fee out, applied fee).
+    fn insert_swap(
+        &self,
+        raw: &RawEvent,
+        trader: &AccountId,
+        direction: &str,
+        amounts: [u128; 6],
+    ) -> Result<(), IndexerError> {
+        let pair = account_key(&raw.contract);
+        let [amount_in, amount_out, lp_fee, vault_fee_in, vault_fee_out, applied_fee] = amounts;
+
+        self.conn.execute(
+            "INSERT INTO swaps
+             (pair, block_number, block_timestamp, trader, direction, amount_in, amount_out,
+              lp_fee, vault_fee_in, vault_fee_out, applied_fee)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+            params![
+                pair,
+                raw.block_number,
+                raw.block_timestamp,
+                account_key(trader),
+                direction,
+                amount_in.to_string(),
+                amount_out.to_string(),
+                lp_fee.to_string(),
+                vault_fee_in.to_string(),
+                vault_fee_out.to_string(),
+                applied_fee.to_string()
+            ],
+        )?;
+
+        self.conn.execute(
+            "UPDATE pairs SET swap_count = swap_count + 1 WHERE address = ?1",
+            params![pair],
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use scale::Encode;
+
+    use super::*;
+
+    const CREATOR: AccountId = [1u8; 32];
+    const PAIR: AccountId = [2u8; 32];
+    const TOKEN: AccountId = [3u8; 32];
+    const ALICE: AccountId = [4u8; 32];
+    const BOB: AccountId = [5u8; 32];
+
+    fn raw(block_number: u32, contract: AccountId, data: Vec<u8>) -> RawEvent {
+        RawEvent {
+            block_number,
+            event_index: 0,
+            block_timestamp: 1_690_000_000_000 + block_number as u64 * 1000,
+            contract,
+            data,
+        }
+    }
+
+    fn pair_amount(indexer: &Indexer, column: &str) -> u128 {
+        let value: String = indexer
+            .connection()
+            .query_row(
+                &format!("SELECT {} FROM pairs WHERE address = ?1", column),
+                params![account_key(&PAIR)],
+                |row| row.get(0),
+            )
+            .unwrap();
+
+        db::parse_amount(&value).unwrap()
+    }
+
+    fn balance(
+        indexer: &Indexer,
+        table: &str,
+        owner_column: &str,
+        owner: &AccountId,
+        account: &AccountId,
+    ) -> u128 {
+        let value: String = indexer
+            .connection()
+            .query_row(
+                &format!(
+                    "SELECT balance FROM {} WHERE {} = ?1 AND account = ?2",
+                    table, owner_column
+                ),
+                params![account_key(owner), account_key(account)],
+                |row| row.get(0),
+            )
+            .unwrap();
+
+        db::parse_amount(&value).unwrap()
+    }
+
+    fn pool_history() -> Vec<RawEvent> {
+        let mut events = vec![
+            raw(
+                1,
+                CREATOR,
+                PairCreatorEvent::NewTPA {
+                    caller: ALICE,
+                    psp22_address: TOKEN,
+                    lp_fee: 1_000_000_000_000,
+                    pair_address: PAIR,
+                }
+                .encode(),
+            ),
+            raw(
+                2,
+                TOKEN,
+                Psp22Event::Transfer {
+                    from: Some(ALICE),
+                    to: Some(PAIR),
+                    value: 1_000,
+                }
+                .encode(),
+            ),
+            raw(
+                2,
+                PAIR,
+                TradingPairEvent::Transfer {
+                    from: None,
+                    to: Some(ALICE),
+                    value: 9_000,
+                }
+                .encode(),
+            ),
+            raw(
+                2,
+                PAIR,
+                TradingPairEvent::LiquidityPoolProvision {
+                    provider: ALICE,
+                    a0_deposited_amount: 100_000,
+                    psp22_deposited_amount: 1_000,
+                    shares_given: 9_000,
+                    new_a0_reserve: 100_000,
+                    new_psp22_reserve: 1_000,
+                    new_total_supply: 10_000,
+                }
+                .encode(),
+            ),
+            raw(
+                3,
+                PAIR,
+                TradingPairEvent::A0Swap {
+                    caller: BOB,
+                    a0_deposited_amount: 10_000,
+                    psp22_given_amount: 89,
+                    psp22_given_to_vault: 1,
+                    a0_given_to_vault: 20,
+                    psp22_lp_fee: 1,
+                    applied_fee: 1_000_000_000_000,
+                    new_a0_reserve: 109_980,
+                    new_psp22_reserve: 910,
+                }
+                .encode(),
+            ),
+            raw(
+                4,
+                PAIR,
+                TradingPairEvent::Transfer {
+                    from: Some(ALICE),
+                    to: Some(BOB),
+                    value: 4_000,
+                }
+                .encode(),
+            ),
+            raw(
+                5,
+                PAIR,
+                TradingPairEvent::RewardsRedeemed {
+                    owner: ALICE,
+                    recipient: BOB,
+                    psp22_redeemed_amount: 7,
+                }
+                .encode(),
+            ),
+            // events of contracts that are not registered are skipped
+            raw(6, [9u8; 32], vec![0]),
+        ];
+
+        // number the events of each block
+        for i in 1..events.len() {
+            if events[i].block_number == events[i - 1].block_number {
+                events[i].event_index = events[i - 1].event_index + 1;
+            }
+        }
+
+        events
+    }
+
+    #[test]
+    fn replay_rebuilds_pool_state() {
+        let mut indexer = Indexer::in_memory().unwrap();
+        indexer
+            .register(&CREATOR, ContractKind::PairCreator)
+            .unwrap();
+
+        let summary = indexer.replay(&pool_history()).unwrap();
+
+        assert_eq!(
+            summary,
+            ReplaySummary {
+                indexed: 7,
+                skipped: 1,
+                already_indexed: 0
+            }
+        );
+        assert_eq!(
+            indexer.contract_kind(&PAIR).unwrap(),
+            Some(ContractKind::TradingPair)
+        );
+        assert_eq!(
+            indexer.contract_kind(&TOKEN).unwrap(),
+            Some(ContractKind::Psp22)
+        );
+
+        assert_eq!(pair_amount(&indexer, "a0_reserve"), 109_980);
+        assert_eq!(pair_amount(&indexer, "psp22_reserve"), 910);
+        assert_eq!(pair_amount(&indexer, "lp_total_supply"), 10_000);
+        assert_eq!(pair_amount(&indexer, "a0_volume"), 10_000);
+        assert_eq!(pair_amount(&indexer, "psp22_lp_fees"), 1);
+        assert_eq!(pair_amount(&indexer, "a0_vault_fees"), 20);
+
+        assert_eq!(
+            balance(&indexer, "lp_balances", "pair", &PAIR, &ALICE),
+            5_000
+        );
+        assert_eq!(balance(&indexer, "lp_balances", "pair", &PAIR, &BOB), 4_000);
+        assert_eq!(
+            balance(&indexer, "token_balances", "token", &TOKEN, &PAIR),
+            1_000
+        );
+
+        let redeemed: String = indexer
+            .connection()
+            .query_row(
+                "SELECT psp22_amount FROM reward_redemptions WHERE owner = ?1",
+                params![account_key(&ALICE)],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(redeemed, "7");
+    }
+
+    #[test]
+    fn replayed_events_are_not_applied_again() {
+        let mut indexer = Indexer::in_memory().unwrap();
+        indexer
+            .register(&CREATOR, ContractKind::PairCreator)
+            .unwrap();
+        let events = pool_history();
+
+        // a first dump ending in the middle of block 2, then an overlapping one
+        indexer.replay(&events[..3]).unwrap();
+        assert_eq!(indexer.last_replayed().unwrap(), Some((2, 1)));
+
+        let summary = indexer.replay(&events).unwrap();
+        assert_eq!(
+            summary,
+            ReplaySummary {
+                indexed: 4,
+                skipped: 1,
+                already_indexed: 3
+            }
+        );
+        assert_eq!(indexer.last_replayed().unwrap(), Some((6, 0)));
+
+        // the same dump again
+        let summary = indexer.replay(&events).unwrap();
+        assert_eq!(
+            summary,
+            ReplaySummary {
+                indexed: 0,
+                skipped: 0,
+                already_indexed: 8
+            }
+        );
+
+        assert_eq!(pair_amount(&indexer, "a0_volume"), 10_000);
+        assert_eq!(pair_amount(&indexer, "lp_total_supply"), 10_000);
+        assert_eq!(
+            balance(&indexer, "lp_balances", "pair", &PAIR, &ALICE),
+            5_000
+        );
+        let swaps: i64 = indexer
+            .connection()
+            .query_row("SELECT COUNT(*) FROM swaps", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(swaps, 1);
+        let liquidity_changes: i64 = indexer
+            .connection()
+            .query_row("SELECT COUNT(*) FROM liquidity_changes", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(liquidity_changes, 1);
+    }
+
+    #[test]
+    fn replay_rolls_back_on_invalid_event() {
+        let mut indexer = Indexer::in_memory().unwrap();
+        indexer.register(&PAIR, ContractKind::TradingPair).unwrap();
+
+        let events = vec![
+            raw(
+                1,
+                PAIR,
+                TradingPairEvent::Transfer {
+                    from: None,
+                    to: Some(ALICE),
+                    value: 1_000,
+                }
+                .encode(),
+            ),
+            raw(2, PAIR, vec![42]),
+        ];
+
+        assert!(matches!(
+            indexer.replay(&events),
+            Err(IndexerError::Scale(_))
+        ));
+
+        let count: i64 = indexer
+            .connection()
+            .query_row("SELECT COUNT(*) FROM lp_balances", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn token_extension_events_are_skipped() {
+        let mut indexer = Indexer::in_memory().unwrap();
+        indexer.register(&TOKEN, ContractKind::Psp22).unwrap();
+
+        // `Snapshot { id: 1 }` of the mintable token and `Paused { account }` of the
+        // pausable token
+        let mut snapshot = vec![2u8];
+        snapshot.extend(1u32.encode());
+        let mut paused = vec![2u8];
+        paused.extend(ALICE.encode());
+
+        let events = vec![
+            raw(1, TOKEN, snapshot),
+            raw(
+                2,
+                TOKEN,
+                Psp22Event::Transfer {
+                    from: None,
+                    to: Some(ALICE),
+                    value: 1_000,
+                }
+                .encode(),
+            ),
+            raw(3, TOKEN, paused),
+        ];
+
+        let summary = indexer.replay(&events).unwrap();
+
+        assert_eq!(
+            summary,
+            ReplaySummary {
+                indexed: 1,
+                skipped: 2,
+                already_indexed: 0
+            }
+        );
+        assert_eq!(
+            balance(&indexer, "token_balances", "token", &TOKEN, &ALICE),
+            1_000
+        );
+    }
+
+    #[test]
+    fn transfer_above_balance_is_rejected() {
+        let indexer = Indexer::in_memory().unwrap();
+        indexer.register(&PAIR, ContractKind::TradingPair).unwrap();
+
+        let event = raw(
+            1,
+            PAIR,
+            TradingPairEvent::Transfer {
+                from: Some(ALICE),
+                to: Some(BOB),
+                value: 1,
+            }
+            .encode(),
+        );
+
+        assert!(matches!(indexer.apply(&event), Err(IndexerError::Overflow)));
+    }
+}
@@ -0,0 +1,68 @@
+//! Command line entry point of the indexer.
+//!
+//! pair_indexer <dump> <database> [--creator <address>]... [--pair <address>]... [--token <address>]...
+
+use std::{
+    path::Path,
+    process,
+};
+
+use pair_indexer::{
+    dump,
+    ContractKind,
+    Indexer,
+    IndexerError,
+};
+
+const USAGE: &str = "usage: pair_indexer <dump> <database> [--creator <address>]... \
+                     [--pair <address>]... [--token <address>]...";
+
+fn run(args: &[String]) -> Result<(), IndexerError> {
+    let mut indexer = Indexer::open(Path::new(&args[1]))?;
+
+    // contracts to index on top of the pairs discovered from the pair creators events
+    for option in args[2..].chunks(2) {
+        let kind = match option[0].as_str() {
+            "--creator" => ContractKind::PairCreator,
+            "--pair" => ContractKind::TradingPair,
+            "--token" => ContractKind::Psp22,
+            _ => {
+                eprintln!("{}", USAGE);
+                process::exit(2);
+            }
+        };
+
+        match option.get(1) {
+            Some(address) => indexer.register(&dump::parse_account_id(address)?, kind)?,
+            None => {
+                eprintln!("{}", USAGE);
+                process::exit(2);
+            }
+        }
+    }
+
+    let events = dump::load(Path::new(&args[0]))?;
+    let summary = indexer.replay(&events)?;
+
+    println!(
+        "indexed {} events ({} events of unregistered contracts or token extensions skipped, \
+         {} events already indexed)",
+        summary.indexed, summary.skipped, summary.already_indexed
+    );
+
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    if args.len() < 2 {
+        eprintln!("{}", USAGE);
+        process::exit(2);
+    }
+
+    if let Err(error) = run(&args) {
+        eprintln!("pair_indexer: {}", error);
+        process::exit(1);
+    }
+}
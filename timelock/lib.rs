@@ -0,0 +1,549 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[ink::contract]
+pub mod timelock {
+
+    use call_executor::execute_call;
+    use ink::env::hash::Blake2x256;
+    use ink::prelude::vec::Vec;
+    use ink::storage::{
+        Lazy,
+        Mapping,
+    };
+
+    // Maximal number of calls a single operation can execute
+    const MAX_OPERATION_CALLS: usize = 10;
+
+    /// A call executed by an operation as (callee, message selector, SCALE encoded arguments, transferred AZERO,
+    /// whether the message returns a `Result`, whose `Err` then fails the call).
+    pub type TimelockCall = (AccountId, [u8; 4], Vec<u8>, Balance, bool);
+
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Role {
+        Admin,     // Grants and revokes roles
+        Proposer,  // Schedules operations
+        Executor,  // Executes ready operations
+        Canceller, // Cancels pending operations
+    }
+
+    #[ink(storage)]
+    pub struct Timelock {
+        // Minimal delay between scheduling and executing an operation, in milliseconds.
+        // Lazy so an update by an executed operation is not overwritten once execute stores the contract
+        min_delay: Lazy<u64>,
+        // Accounts holding each role
+        roles: Mapping<(Role, AccountId), ()>,
+        // Timestamp from which each pending operation can be executed, in milliseconds
+        ready_timestamps: Mapping<Hash, u64>,
+        // Calls of each pending operation
+        operation_calls: Mapping<Hash, Vec<TimelockCall>>,
+        // Operations that were already executed
+        executed: Mapping<Hash, ()>,
+    }
+
+    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum TimelockErrors {
+        MissingRole,               // Error code for a caller without the role required by the message
+        CallerNotTimelock,         // Error code for changing the delay other than through an operation
+        EmptyOperation,            // Error code for an operation without calls
+        TooManyCalls,              // Error code for an operation with more than MAX_OPERATION_CALLS calls
+        InsufficientDelay,         // Error code for scheduling with a delay below the minimal delay
+        OperationAlreadyScheduled, // Error code for scheduling a pending or executed operation again
+        OperationNotPending,       // Error code for an operation that is not scheduled or already executed
+        OperationNotReady,         // Error code for executing an operation before its ready timestamp
+        CallFailed,                // Error code for a failed operation call
+        Overflow,                  // Error code for overflow
+    }
+
+    #[ink(event)]
+    pub struct CallScheduled {
+        #[ink(topic)]
+        id: Hash, // Id of the scheduled operation
+        #[ink(topic)]
+        proposer: AccountId, // Account that scheduled the operation
+        calls: Vec<TimelockCall>, // Calls executed by the operation
+        salt: [u8; 32],           // Salt distinguishing operations with the same calls
+        ready_timestamp: u64, // Timestamp from which the operation can be executed, in milliseconds
+    }
+
+    #[ink(event)]
+    pub struct CallExecuted {
+        #[ink(topic)]
+        id: Hash, // Id of the executed operation
+        #[ink(topic)]
+        executor: AccountId, // Account that executed the operation
+    }
+
+    #[ink(event)]
+    pub struct Cancelled {
+        #[ink(topic)]
+        id: Hash, // Id of the cancelled operation
+        #[ink(topic)]
+        canceller: AccountId, // Account that cancelled the operation
+    }
+
+    #[ink(event)]
+    pub struct MinDelayChanged {
+        old_delay: u64, // Previous minimal delay in milliseconds
+        new_delay: u64, // New minimal delay in milliseconds
+    }
+
+    #[ink(event)]
+    pub struct RoleGranted {
+        role: Role, // Granted role
+        #[ink(topic)]
+        account: AccountId, // Account that received the role
+        #[ink(topic)]
+        sender: AccountId, // Account that granted the role
+    }
+
+    #[ink(event)]
+    pub struct RoleRevoked {
+        role: Role, // Revoked role
+        #[ink(topic)]
+        account: AccountId, // Account that lost the role
+        #[ink(topic)]
+        sender: AccountId, // Account that revoked or renounced the role
+    }
+
+    impl Timelock {
+        #[ink(constructor)]
+        pub fn new(
+            min_delay: u64,            // Minimal delay in milliseconds
+            proposers: Vec<AccountId>, // Accounts allowed to schedule and cancel operations
+            executors: Vec<AccountId>, // Accounts allowed to execute operations
+            admin: Option<AccountId>, // Optional account allowed to manage roles next to the timelock itself
+        ) -> Self {
+            let mut instance = Self {
+                min_delay: Lazy::default(),
+                roles: Mapping::default(),
+                ready_timestamps: Mapping::default(),
+                operation_calls: Mapping::default(),
+                executed: Mapping::default(),
+            };
+            instance.min_delay.set(&min_delay);
+
+            // The timelock administers itself, so role changes can be scheduled as operations
+            let timelock = instance.env().account_id();
+            instance.grant(Role::Admin, timelock, timelock);
+            if let Some(admin) = admin {
+                instance.grant(Role::Admin, admin, timelock);
+            }
+            for proposer in proposers {
+                instance.grant(Role::Proposer, proposer, timelock);
+                instance.grant(Role::Canceller, proposer, timelock);
+            }
+            for executor in executors {
+                instance.grant(Role::Executor, executor, timelock);
+            }
+
+            instance
+        }
+
+        /// Schedules `calls` to be executed after `delay` milliseconds. `salt` allows
+        /// scheduling the same calls more than once.
+        #[ink(message)]
+        pub fn schedule(
+            &mut self,
+            calls: Vec<TimelockCall>,
+            salt: [u8; 32],
+            delay: u64,
+        ) -> Result<Hash, TimelockErrors> {
+            let proposer = self.env().caller();
+            self.ensure_role(Role::Proposer, proposer)?;
+
+            if calls.is_empty() {
+                return Err(TimelockErrors::EmptyOperation);
+            }
+            if calls.len() > MAX_OPERATION_CALLS {
+                return Err(TimelockErrors::TooManyCalls);
+            }
+            if delay < self.get_min_delay() {
+                return Err(TimelockErrors::InsufficientDelay);
+            }
+
+            let id = self.hash_operation(calls.clone(), salt);
+            if self.ready_timestamps.contains(id) || self.executed.contains(id) {
+                return Err(TimelockErrors::OperationAlreadyScheduled);
+            }
+
+            let ready_timestamp = match self.get_current_timestamp().checked_add(delay) {
+                Some(result) => result,
+                None => return Err(TimelockErrors::Overflow),
+            };
+
+            self.ready_timestamps.insert(id, &ready_timestamp);
+            self.operation_calls.insert(id, &calls);
+
+            self.env().emit_event(CallScheduled {
+                id,
+                proposer,
+                calls,
+                salt,
+                ready_timestamp,
+            });
+
+            Ok(id)
+        }
+
+        /// Cancels a pending operation.
+        #[ink(message)]
+        pub fn cancel(&mut self, id: Hash) -> Result<(), TimelockErrors> {
+            let canceller = self.env().caller();
+            self.ensure_role(Role::Canceller, canceller)?;
+
+            if !self.ready_timestamps.contains(id) {
+                return Err(TimelockErrors::OperationNotPending);
+            }
+
+            self.ready_timestamps.remove(id);
+            self.operation_calls.remove(id);
+
+            self.env().emit_event(Cancelled { id, canceller });
+
+            Ok(())
+        }
+
+        /// Executes the calls of a pending operation once its ready timestamp passed.
+        /// Fails, reverting all of the calls, if any of them fails.
+        #[ink(message, payable)]
+        pub fn execute(&mut self, id: Hash) -> Result<(), TimelockErrors> {
+            let executor = self.env().caller();
+            self.ensure_role(Role::Executor, executor)?;
+
+            let ready_timestamp = match self.ready_timestamps.get(id) {
+                Some(result) => result,
+                None => return Err(TimelockErrors::OperationNotPending),
+            };
+            if self.get_current_timestamp() < ready_timestamp {
+                return Err(TimelockErrors::OperationNotReady);
+            }
+
+            let calls = self.operation_calls.get(id).unwrap_or_default();
+
+            // Mark the operation as executed before the calls so it can not be executed again by reentrancy
+            self.ready_timestamps.remove(id);
+            self.operation_calls.remove(id);
+            self.executed.insert(id, &());
+
+            for (callee, selector, input, transferred_value, returns_result) in calls {
+                if !execute_call(callee, selector, &input, transferred_value, returns_result) {
+                    return Err(TimelockErrors::CallFailed);
+                }
+            }
+
+            self.env().emit_event(CallExecuted { id, executor });
+
+            Ok(())
+        }
+
+        /// Updates the minimal delay. Only callable by the timelock itself,
+        /// i.e. by executing a scheduled operation.
+        #[ink(message)]
+        pub fn update_delay(&mut self, new_delay: u64) -> Result<(), TimelockErrors> {
+            if self.env().caller() != self.env().account_id() {
+                return Err(TimelockErrors::CallerNotTimelock);
+            }
+
+            let old_delay = self.get_min_delay();
+            self.min_delay.set(&new_delay);
+
+            self.env().emit_event(MinDelayChanged {
+                old_delay,
+                new_delay,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn grant_role(&mut self, role: Role, account: AccountId) -> Result<(), TimelockErrors> {
+            let sender = self.env().caller();
+            self.ensure_role(Role::Admin, sender)?;
+            self.grant(role, account, sender);
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn revoke_role(
+            &mut self,
+            role: Role,
+            account: AccountId,
+        ) -> Result<(), TimelockErrors> {
+            let sender = self.env().caller();
+            self.ensure_role(Role::Admin, sender)?;
+            self.revoke(role, account, sender);
+            Ok(())
+        }
+
+        /// Gives up a role of the caller.
+        #[ink(message)]
+        pub fn renounce_role(&mut self, role: Role) -> Result<(), TimelockErrors> {
+            let sender = self.env().caller();
+            self.ensure_role(role, sender)?;
+            self.revoke(role, sender, sender);
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn has_role(&self, role: Role, account: AccountId) -> bool {
+            self.roles.contains((role, account))
+        }
+
+        /// Returns the id of an operation executing `calls` with `salt`.
+        #[ink(message)]
+        pub fn hash_operation(&self, calls: Vec<TimelockCall>, salt: [u8; 32]) -> Hash {
+            Hash::from(self.env().hash_encoded::<Blake2x256, _>(&(calls, salt)))
+        }
+
+        /// Returns the ready timestamp of a pending operation.
+        #[ink(message)]
+        pub fn get_ready_timestamp(&self, id: Hash) -> Option<u64> {
+            self.ready_timestamps.get(id)
+        }
+
+        /// Returns the calls of a pending operation.
+        #[ink(message)]
+        pub fn get_operation_calls(&self, id: Hash) -> Vec<TimelockCall> {
+            self.operation_calls.get(id).unwrap_or_default()
+        }
+
+        #[ink(message)]
+        pub fn is_operation_pending(&self, id: Hash) -> bool {
+            self.ready_timestamps.contains(id)
+        }
+
+        #[ink(message)]
+        pub fn is_operation_ready(&self, id: Hash) -> bool {
+            match self.ready_timestamps.get(id) {
+                Some(ready_timestamp) => self.get_current_timestamp() >= ready_timestamp,
+                None => false,
+            }
+        }
+
+        #[ink(message)]
+        pub fn is_operation_executed(&self, id: Hash) -> bool {
+            self.executed.contains(id)
+        }
+
+        #[ink(message)]
+        pub fn get_min_delay(&self) -> u64 {
+            self.min_delay.get().unwrap_or_default()
+        }
+
+        #[ink(message)]
+        pub fn get_current_timestamp(&self) -> u64 {
+            self.env().block_timestamp()
+        }
+
+        fn ensure_role(&self, role: Role, account: AccountId) -> Result<(), TimelockErrors> {
+            if !self.has_role(role, account) {
+                return Err(TimelockErrors::MissingRole);
+            }
+            Ok(())
+        }
+
+        fn grant(&mut self, role: Role, account: AccountId, sender: AccountId) {
+            if self.has_role(role, account) {
+                return;
+            }
+            self.roles.insert((role, account), &());
+            self.env().emit_event(RoleGranted {
+                role,
+                account,
+                sender,
+            });
+        }
+
+        fn revoke(&mut self, role: Role, account: AccountId, sender: AccountId) {
+            if !self.has_role(role, account) {
+                return;
+            }
+            self.roles.remove((role, account));
+            self.env().emit_event(RoleRevoked {
+                role,
+                account,
+                sender,
+            });
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink::env::DefaultEnvironment;
+        use ink::env::test::{
+            default_accounts,
+            set_block_timestamp,
+            set_callee,
+            set_caller,
+        };
+
+        // Timelock at charlie's address with alice as proposer, bob as executor and eve as admin
+        fn timelock() -> Timelock {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_callee::<DefaultEnvironment>(accounts.charlie);
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            Timelock::new(
+                1000,
+                vec![accounts.alice],
+                vec![accounts.bob],
+                Some(accounts.eve),
+            )
+        }
+
+        fn calls() -> Vec<TimelockCall> {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            vec![(accounts.django, [1, 2, 3, 4], vec![5, 6], 0, true)]
+        }
+
+        #[ink::test]
+        fn constructor_grants_roles() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let timelock = timelock();
+
+            assert!(timelock.has_role(Role::Admin, accounts.charlie));
+            assert!(timelock.has_role(Role::Admin, accounts.eve));
+            assert!(timelock.has_role(Role::Proposer, accounts.alice));
+            assert!(timelock.has_role(Role::Canceller, accounts.alice));
+            assert!(timelock.has_role(Role::Executor, accounts.bob));
+            assert!(!timelock.has_role(Role::Proposer, accounts.bob));
+            assert!(!timelock.has_role(Role::Admin, accounts.alice));
+            assert_eq!(timelock.get_min_delay(), 1000);
+        }
+
+        #[ink::test]
+        fn schedule_works() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut timelock = timelock();
+            set_block_timestamp::<DefaultEnvironment>(5000);
+
+            let id = timelock.schedule(calls(), [0; 32], 2000).unwrap();
+
+            assert_eq!(id, timelock.hash_operation(calls(), [0; 32]));
+            assert_ne!(id, timelock.hash_operation(calls(), [1; 32]));
+            assert_eq!(timelock.get_ready_timestamp(id), Some(7000));
+            assert_eq!(timelock.get_operation_calls(id), calls());
+            assert!(timelock.is_operation_pending(id));
+            assert!(!timelock.is_operation_ready(id));
+
+            set_block_timestamp::<DefaultEnvironment>(7000);
+            assert!(timelock.is_operation_ready(id));
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                timelock.schedule(calls(), [1; 32], 2000),
+                Err(TimelockErrors::MissingRole)
+            );
+        }
+
+        #[ink::test]
+        fn invalid_schedules_are_rejected() {
+            let mut timelock = timelock();
+
+            assert_eq!(
+                timelock.schedule(calls(), [0; 32], 999),
+                Err(TimelockErrors::InsufficientDelay)
+            );
+            assert_eq!(
+                timelock.schedule(Vec::new(), [0; 32], 1000),
+                Err(TimelockErrors::EmptyOperation)
+            );
+            assert_eq!(
+                timelock.schedule(
+                    vec![calls()[0].clone(); MAX_OPERATION_CALLS + 1],
+                    [0; 32],
+                    1000
+                ),
+                Err(TimelockErrors::TooManyCalls)
+            );
+            assert!(timelock.schedule(calls(), [0; 32], 1000).is_ok());
+            assert_eq!(
+                timelock.schedule(calls(), [0; 32], 1000),
+                Err(TimelockErrors::OperationAlreadyScheduled)
+            );
+        }
+
+        #[ink::test]
+        fn cancel_works() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut timelock = timelock();
+            let id = timelock.schedule(calls(), [0; 32], 1000).unwrap();
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(timelock.cancel(id), Err(TimelockErrors::MissingRole));
+
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(timelock.cancel(id), Ok(()));
+            assert!(!timelock.is_operation_pending(id));
+            assert_eq!(timelock.get_operation_calls(id), Vec::new());
+            assert_eq!(
+                timelock.cancel(id),
+                Err(TimelockErrors::OperationNotPending)
+            );
+
+            // A cancelled operation can be scheduled again
+            assert!(timelock.schedule(calls(), [0; 32], 1000).is_ok());
+        }
+
+        #[ink::test]
+        fn execute_checks_role_and_readiness() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut timelock = timelock();
+            set_block_timestamp::<DefaultEnvironment>(5000);
+            let id = timelock.schedule(calls(), [0; 32], 1000).unwrap();
+
+            assert_eq!(timelock.execute(id), Err(TimelockErrors::MissingRole));
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(timelock.execute(id), Err(TimelockErrors::OperationNotReady));
+            assert_eq!(
+                timelock.execute(timelock.hash_operation(calls(), [1; 32])),
+                Err(TimelockErrors::OperationNotPending)
+            );
+        }
+
+        #[ink::test]
+        fn roles_are_managed_by_admins() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut timelock = timelock();
+
+            assert_eq!(
+                timelock.grant_role(Role::Executor, accounts.frank),
+                Err(TimelockErrors::MissingRole)
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            assert_eq!(timelock.grant_role(Role::Executor, accounts.frank), Ok(()));
+            assert!(timelock.has_role(Role::Executor, accounts.frank));
+            assert_eq!(timelock.revoke_role(Role::Proposer, accounts.alice), Ok(()));
+            assert!(!timelock.has_role(Role::Proposer, accounts.alice));
+
+            set_caller::<DefaultEnvironment>(accounts.frank);
+            assert_eq!(timelock.renounce_role(Role::Executor), Ok(()));
+            assert!(!timelock.has_role(Role::Executor, accounts.frank));
+            assert_eq!(
+                timelock.renounce_role(Role::Executor),
+                Err(TimelockErrors::MissingRole)
+            );
+        }
+
+        #[ink::test]
+        fn delay_can_only_be_updated_by_the_timelock() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            let mut timelock = timelock();
+
+            set_caller::<DefaultEnvironment>(accounts.eve);
+            assert_eq!(
+                timelock.update_delay(0),
+                Err(TimelockErrors::CallerNotTimelock)
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.charlie);
+            assert_eq!(timelock.update_delay(5000), Ok(()));
+            assert_eq!(timelock.get_min_delay(), 5000);
+        }
+    }
+}
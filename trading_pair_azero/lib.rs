@@ -1,37 +1,65 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-#[openbrush::implementation(PSP22)]
+pub mod pricing;
+
+#[openbrush::implementation(PSP22, PSP22Metadata)]
 #[openbrush::contract]
 pub mod trading_pair_azero {
 
+    use ink::env::call::{
+        build_call,
+        ExecutionInput,
+        Selector,
+    }; // Importing cross contract call builders from ink env
+    use ink::env::hash::Blake2x256; // Importing Blake2x256 from ink env
     use ink::env::CallFlags; // Importing CallFlags from ink env
+    use ink::env::DefaultEnvironment; // Importing DefaultEnvironment from ink env
+    use ink::prelude::format; // Importing format from ink prelude
     use ink::prelude::vec; // Importing vec from ink prelude
     use ink::storage::Mapping; // Importing Mapping from ink storage
     use openbrush::{
-        contracts::traits::psp22::PSP22Ref,
-        traits::Storage,
+        contracts::traits::psp22::{
+            extensions::metadata::PSP22MetadataRef,
+            PSP22Ref,
+        },
+        traits::{
+            Storage,
+            String,
+        },
     };
-    use primitive_types::U256;
+    use crate::pricing;
+
+    // Account holding the permanently locked minimum liquidity of the pool (nobody owns its key)
+    const DEAD_ACCOUNT: [u8; 32] = [0u8; 32];
+
+    // Domain separator prepended to every signed permit message (same as `PSP22Data::permit`)
+    const PERMIT_DOMAIN: &[u8] = b"PSP22Permit";
+
+    // Number of daily volume and fee buckets kept by the pair (older days are overwritten)
+    const VOLUME_BUCKETS: u64 = 30;
+
+    // Maximum number of LP locks an account can hold at once (expired locks are pruned on `lock_lp`)
+    const MAX_LP_LOCKS: usize = 20;
+
+    // Minimal duration of a PANX lock in seconds (7 days), so the same PANX can not grant the
+    // LP fee discount to a different wallet every block
+    const MIN_PANX_LOCK_DURATION: u64 = 7 * 86400;
 
     #[ink(storage)]
     #[derive(Storage)]
     pub struct TradingPairAzero {
         #[storage_field]
         psp22: psp22::Data,
+        #[storage_field]
+        metadata: metadata::Data,
         // Number of transactions
         transasction_number: i64,
         // Account ID for the PSP22 token
         psp22_token: AccountId,
         // Fee amount
         fee: Balance,
-        // Total supply of the token
-        total_supply: Balance,
-        // Balances of individual accounts
-        balances: Mapping<AccountId, Balance>,
         // Account ID for the Panx contract
         panx_contract: AccountId,
-        // LP token allowances between accounts
-        lp_tokens_allowances: Mapping<(AccountId, AccountId), Balance>,
         // Account ID for the vault
         vault: AccountId,
         // PSP22 LP fee vault balance
@@ -50,10 +78,29 @@ pub mod trading_pair_azero {
         account_overall_lp_fee_rewards: Mapping<AccountId, (Balance, Balance)>,
         // Last redeemed timestamp for each account
         last_redeemed: Mapping<AccountId, u64>,
+        // Operators approved by an account to redeem its LP incentive tokens
+        reward_operators: Mapping<(AccountId, AccountId), bool>,
         // LP lock timestamp
         lp_lock_timestamp: u64,
         // Deployer account address
         deployer: AccountId,
+        // Admin account address (allowed to edit the pair parameters)
+        admin: AccountId,
+        // PANX holders LP fee discount tiers (locked PANX threshold, LP fee discount in bps)
+        panx_discount_tiers: Vec<(Balance, u16)>,
+        // Locked PANX amount and unlock timestamp for each account. PANX is locked per pair,
+        // a lock only discounts the LP fee of the pair it was locked in.
+        locked_panx: Mapping<AccountId, (Balance, u64)>,
+        // Total PANX locked in the pair
+        total_locked_panx: Balance,
+        // Number of LP token permits used by each owner
+        lp_permit_nonces: Mapping<AccountId, u64>,
+        // Locked LP shares of each account as (amount, unlock timestamp in seconds)
+        lp_locks: Mapping<AccountId, Vec<(Balance, u64)>>,
+        // Cumulative swapped PSP22 tokens (swap_psp22) and AZERO coins (swap_a0)
+        cumulative_volume: (Balance, Balance),
+        // Daily ring buffer of (day, PSP22 volume, AZERO volume, PSP22 LP fees, AZERO LP fees)
+        daily_volume: Mapping<u64, (u64, Balance, Balance, Balance, Balance)>,
     }
 
     #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
@@ -79,134 +126,283 @@ pub mod trading_pair_azero {
         RemoveLpIncentiveProgramError, // Error code for remove LP incentive program error
         LpStillLocked,             // Error code for remove LP before the lock date
         ZeroRedeemableAmount,
+        CallerNotRewardOperator, // Error code for redeeming on behalf of an account without its approval
+        CallerNotAdmin,          // Error code for admin only functions called by non admin
+        InvalidDiscountTiers,    // Error code for unordered tiers or discount above 100%
+        InvalidUnlockTimestamp,  // Error code for PANX lock shortening or ending in the past
+        PanxStillLocked,         // Error code for unlocking PANX before the unlock timestamp
+        ZeroLockedPanx,          // Error code for zero locked PANX
+        PSP22PermitFailed,       // Error code for a rejected PSP22 permit without enough allowance
+        ZeroLockedLp,            // Error code for locking zero LP shares
+        InvalidLpLockIndex,      // Error code for extending an LP lock that does not exist
+        TooManyLpLocks,          // Error code for exceeding the maximum number of active LP locks
+    }
+
+    /// Direction of a swap through the pair
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+    pub enum SwapDirection {
+        Psp22ToA0, // PSP22 tokens in, AZERO coins out (`swap_psp22`)
+        A0ToPsp22, // AZERO coins in, PSP22 tokens out (`swap_a0`)
+    }
+
+    /// Full breakdown of a swap, exactly as `swap_psp22` / `swap_a0` would execute it
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+    pub struct SwapQuote {
+        pub amount_in: Balance, // Amount of the input token given by the trader
+        pub amount_out_before_fees: Balance, /* Amount out the slippage is validated against */
+        pub amount_out: Balance, // Amount of the output token given to the trader
+        pub lp_fee: Balance, // Amount of the output token added to the LP fee vault
+        pub vault_fee_in: Balance, // Amount of the input token sent to the vault (traders fee)
+        pub vault_fee_out: Balance, // Amount of the output token sent to the vault (traders fee)
+        pub applied_fee: Balance, // LP fee percentage applied after the PANX discount
+        pub panx_discount_bps: u16, // PANX discount of the trader in basis points
+        pub price_impact: Balance, // Price impact percentage of the swap
+        pub new_a0_reserve: Balance, // AZERO reserve after the swap
+        pub new_psp22_reserve: Balance, // PSP22 reserve after the swap
+    }
+
+    #[ink(event)]
+    pub struct Transfer {
+        #[ink(topic)]
+        from: Option<AccountId>, // LP tokens sender (`None` when minted)
+        #[ink(topic)]
+        to: Option<AccountId>, // LP tokens recipient (`None` when burnt)
+        value: Balance, // Amount of LP tokens transferred
+    }
+
+    #[ink(event)]
+    pub struct Approval {
+        #[ink(topic)]
+        owner: AccountId, // Owner of the LP tokens
+        #[ink(topic)]
+        spender: AccountId, // Account allowed to spend the LP tokens
+        value: Balance, // New allowance of `spender` over the owner LP tokens
     }
 
     #[ink(event)]
     pub struct LiquidityPoolProvision {
+        #[ink(topic)]
         provider: AccountId, // Address of the provider who deposited the liquidity
         a0_deposited_amount: Balance, // Amount of AZERO tokens deposited by the provider
         psp22_deposited_amount: Balance, // Amount of PSP22 tokens deposited by the provider
         shares_given: Balance, // Amount of LP tokens (shares) given to the provider in return
+        new_a0_reserve: Balance, // AZERO balance of the pair after the provision
+        new_psp22_reserve: Balance, // PSP22 balance of the pair after the provision
+        new_total_supply: Balance, // LP tokens total supply after the provision
     }
 
     #[ink(event)]
     pub struct LiquidityPoolWithdrawal {
+        #[ink(topic)]
         caller: AccountId, // Address of the caller who initiated the liquidity withdrawal
         shares_given: Balance, // Amount of LP tokens (shares) being withdrawn
         a0_given_amount: Balance, /* Amount of AZERO tokens given to the caller as part of the withdrawal */
         psp22_given_amount: Balance, /* Amount of PSP22 tokens given to the caller as part of the withdrawal */
+        a0_fee_given_amount: Balance, // Part of the given AZERO tokens paid from the AZERO LP fee vault
+        psp22_fee_given_amount: Balance, // Part of the given PSP22 tokens paid from the PSP22 LP fee vault
         new_shares_balance: Balance, // Updated balance of LP tokens (shares) after the withdrawal
+        new_a0_reserve: Balance, // AZERO balance of the pair after the withdrawal
+        new_psp22_reserve: Balance, // PSP22 balance of the pair after the withdrawal
+        new_total_supply: Balance, // LP tokens total supply after the withdrawal
     }
 
     #[ink(event)]
     pub struct A0Swap {
+        #[ink(topic)]
         caller: AccountId, // Address of the caller who initiated the A0 token swap
         a0_deposited_amount: Balance, // Amount of AZERO tokens deposited by the caller for the swap
         psp22_given_amount: Balance, /* Amount of PSP22 tokens given to the caller as part of the swap */
         psp22_given_to_vault: Balance, /* Amount of PSP22 tokens sent to the vault as part of the swap */
+        a0_given_to_vault: Balance, // Amount of AZERO tokens sent to the vault as part of the swap
+        psp22_lp_fee: Balance, // Amount of PSP22 tokens added to the PSP22 LP fee vault
+        applied_fee: Balance, // LP fee percentage applied after the PANX discount
+        new_a0_reserve: Balance, // AZERO balance of the pair after the swap
+        new_psp22_reserve: Balance, // PSP22 balance of the pair after the swap
     }
 
     #[ink(event)]
     pub struct PSP22Swap {
+        #[ink(topic)]
         caller: AccountId, // Address of the caller who initiated the PSP22 token swap
         psp22_deposited_amount: Balance, /* Amount of PSP22 tokens deposited by the caller for the swap */
         a0_given_amount: Balance, // Amount of AZERO tokens given to the caller as part of the swap
         a0_given_to_vault: Balance, // Amount of AZERO tokens sent to the vault as part of the swap
+        psp22_given_to_vault: Balance, // Amount of PSP22 tokens sent to the vault as part of the swap
+        a0_lp_fee: Balance, // Amount of AZERO tokens added to the AZERO LP fee vault
+        applied_fee: Balance, // LP fee percentage applied after the PANX discount
+        new_a0_reserve: Balance, // AZERO balance of the pair after the swap
+        new_psp22_reserve: Balance, // PSP22 balance of the pair after the swap
     }
 
-    #[overrider(PSP22)]
-    fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance {
-        self.lp_tokens_allowances
-            .get(&(owner, spender))
-            .unwrap_or(0)
+    #[ink(event)]
+    pub struct StakingRewardsCompounded {
+        #[ink(topic)]
+        caller: AccountId, // Address of the caller who compounded the staking rewards
+        psp22_compounded_amount: Balance, // Amount of PSP22 staking rewards compounded into the pool
+        psp22_swapped_amount: Balance, // Amount of the compounded PSP22 tokens swapped into AZERO
+        shares_given: Balance, // Amount of LP tokens (shares) given to the caller in return
+        new_a0_reserve: Balance, // AZERO balance of the pair after the compound
+        new_psp22_reserve: Balance, // PSP22 balance of the pair after the compound
+        new_total_supply: Balance, // LP tokens total supply after the compound
+    }
+
+    #[ink(event)]
+    pub struct IncentiveProgramUpdated {
+        #[ink(topic)]
+        account: AccountId, // Address of the LP provider whose incentive rate changed
+        psp22_daily_amount: Balance, // New amount of PSP22 tokens the account earns each day
+    }
+
+    #[ink(event)]
+    pub struct RewardsRedeemed {
+        #[ink(topic)]
+        owner: AccountId, // Address of the LP provider whose incentive tokens were redeemed
+        #[ink(topic)]
+        recipient: AccountId, // Address that received the redeemed tokens
+        psp22_redeemed_amount: Balance, // Amount of PSP22 incentive tokens redeemed
+    }
+
+    #[overrider(psp22::Internal)]
+    fn _emit_transfer_event(
+        &self,
+        from: Option<AccountId>,
+        to: Option<AccountId>,
+        amount: Balance,
+    ) {
+        Self::env().emit_event(Transfer {
+            from,
+            to,
+            value: amount,
+        });
+    }
+
+    #[overrider(psp22::Internal)]
+    fn _emit_approval_event(&self, owner: AccountId, spender: AccountId, amount: Balance) {
+        Self::env().emit_event(Approval {
+            owner,
+            spender,
+            value: amount,
+        });
     }
 
     #[overrider(PSP22)]
     fn approve(&mut self, spender: AccountId, value: Balance) -> Result<(), PSP22Error> {
         let caller = self.get_caller_id();
 
-        self.lp_tokens_allowances
-            .insert((caller, spender), &(value));
+        // approving yourself is a no-op (no event is emitted)
+        if caller == spender {
+            return Ok(())
+        }
 
-        Ok(())
+        psp22::Internal::_approve_from_to(self, caller, spender, value)
     }
 
     #[overrider(PSP22)]
-    fn transfer(
+    fn increase_allowance(
         &mut self,
-        to: AccountId,
-        value: Balance,
-        _data: Vec<u8>,
+        spender: AccountId,
+        delta_value: Balance,
     ) -> Result<(), PSP22Error> {
         let caller = self.get_caller_id();
 
-        let caller_shares: Balance = self.balances.get(&caller).unwrap_or(0);
+        // increasing your own allowance or increasing by nothing is a no-op (no event is emitted)
+        if caller == spender || delta_value == 0 {
+            return Ok(())
+        }
 
-        let recipient_shares: Balance = self.balances.get(&to).unwrap_or(0);
+        let allowance = psp22::PSP22::allowance(self, caller, spender);
 
-        if caller_shares < value {
-            return Err(PSP22Error::InsufficientBalance)
-        }
+        psp22::Internal::_approve_from_to(
+            self,
+            caller,
+            spender,
+            allowance.saturating_add(delta_value),
+        )
+    }
 
-        let new_caller_lp_balance: Balance = caller_shares - value;
+    #[overrider(PSP22)]
+    fn decrease_allowance(
+        &mut self,
+        spender: AccountId,
+        delta_value: Balance,
+    ) -> Result<(), PSP22Error> {
+        let caller = self.get_caller_id();
 
-        let new_recipient_lp_balance: Balance = recipient_shares + value;
+        // decreasing your own allowance or decreasing by nothing is a no-op (no event is emitted)
+        if caller == spender || delta_value == 0 {
+            return Ok(())
+        }
 
-        self.balances.insert(caller, &(new_caller_lp_balance));
+        let allowance = psp22::PSP22::allowance(self, caller, spender);
 
-        self.balances.insert(to, &(new_recipient_lp_balance));
+        if allowance < delta_value {
+            return Err(PSP22Error::InsufficientAllowance)
+        }
 
-        Ok(())
+        psp22::Internal::_approve_from_to(self, caller, spender, allowance - delta_value)
     }
 
     #[overrider(PSP22)]
-    fn transfer_from(
+    fn transfer(
         &mut self,
-        from: AccountId,
         to: AccountId,
         value: Balance,
-        _data: Vec<u8>,
+        data: Vec<u8>,
     ) -> Result<(), PSP22Error> {
         let caller = self.get_caller_id();
 
-        let allowance = psp22::PSP22::allowance(self, from, caller);
+        // transferring to yourself or transferring nothing is a no-op (no event is emitted)
+        if caller == to || value == 0 {
+            return Ok(())
+        }
 
-        if allowance < value {
-            return Err(PSP22Error::InsufficientAllowance)
+        if self.get_unlocked_lp_of(caller) < value {
+            return Err(PSP22Error::Custom(String::from("LP tokens locked")))
         }
 
-        let from_shares: Balance = self.balances.get(&from).unwrap_or(0);
+        psp22::Internal::_transfer_from_to(self, caller, to, value, data)
+    }
 
-        let recipient_shares: Balance = self.balances.get(&to).unwrap_or(0);
+    #[overrider(PSP22)]
+    fn transfer_from(
+        &mut self,
+        from: AccountId,
+        to: AccountId,
+        value: Balance,
+        data: Vec<u8>,
+    ) -> Result<(), PSP22Error> {
+        let caller = self.get_caller_id();
 
-        if from_shares < value {
-            return Err(PSP22Error::InsufficientBalance)
+        // transferring to the same account or transferring nothing is a no-op (no event is emitted)
+        if from == to || value == 0 {
+            return Ok(())
         }
 
-        let new_from_lp_balance: Balance = from_shares - value;
-
-        let new_recipient_lp_balance: Balance = recipient_shares + value;
-
-        self.balances.insert(from, &(new_from_lp_balance));
+        // locked LP shares can not leave the owner wallet
+        if self.get_unlocked_lp_of(from) < value {
+            return Err(PSP22Error::Custom(String::from("LP tokens locked")))
+        }
 
-        self.balances.insert(to, &(new_recipient_lp_balance));
+        // spending your own LP tokens does not need an allowance
+        if caller == from {
+            return psp22::Internal::_transfer_from_to(self, from, to, value, data)
+        }
 
-        let new_allowance = allowance - value;
+        let allowance = psp22::PSP22::allowance(self, from, caller);
 
-        self.lp_tokens_allowances
-            .insert((from, caller), &(new_allowance));
+        if allowance < value {
+            return Err(PSP22Error::InsufficientAllowance)
+        }
 
-        Ok(())
-    }
+        if psp22::PSP22::balance_of(self, from) < value {
+            return Err(PSP22Error::InsufficientBalance)
+        }
 
-    #[overrider(PSP22)]
-    fn balance_of(&self, owner: AccountId) -> Balance {
-        self.balances.get(&owner).unwrap_or(0)
-    }
+        psp22::Internal::_approve_from_to(self, from, caller, allowance - value)?;
 
-    #[overrider(PSP22)]
-    fn total_supply(&self) -> Balance {
-        self.total_supply
+        psp22::Internal::_transfer_from_to(self, from, to, value, data)
     }
 
     impl TradingPairAzero {
@@ -220,11 +416,9 @@ pub mod trading_pair_azero {
             deployer: AccountId,
         ) -> Self {
             let psp22: psp22::Data = Default::default();
+            let mut metadata: metadata::Data = Default::default(); // LP token name, symbol and decimals
             let transasction_number: i64 = 0; // Number of transactions initiated
-            let balances = Mapping::default(); // Mapping to store user balances
-            let lp_tokens_allowances = Mapping::default(); // Mapping to store LP token allowances
             let psp22_token = psp22_contract; // Address of the PSP22 token contract
-            let total_supply: Balance = 0; // Total supply of LP tokens
             let psp22_lp_fee_vault: Balance = 0; // Total PSP22 LP fees sent to the LP vault
             let azero_lp_fee_vault: Balance = 0; // Total AZERO LP fees sent to the LP vault
             let contract_overall_generated_psp22_fee: Balance = 0; // Total PSP22 fees generated by the contract
@@ -233,16 +427,36 @@ pub mod trading_pair_azero {
             let account_overall_staking_rewards = Mapping::default(); // Mapping to store overall staking rewards for accounts
             let account_overall_lp_fee_rewards = Mapping::default(); // Mapping to store overall LP fee rewards for accounts
             let last_redeemed = Mapping::default(); // Mapping to store last redeemed time for accounts
+            let reward_operators = Mapping::default(); // Mapping to store approved reward operators for accounts
+            let admin = deployer; // The deployer is the initial admin of the pair
+            let panx_discount_tiers = vec![(
+                3500u128 * 10u128.pow(12),
+                pricing::default_panx_discount_bps(fee),
+            )]; // Half LP fee (or LP fee - 1% above a 1.4% fee) from 3500 locked PANX
+            let locked_panx = Mapping::default(); // Mapping to store locked PANX for accounts
+            let total_locked_panx: Balance = 0; // Total PANX locked in the pair
+            let lp_permit_nonces = Mapping::default(); // Mapping to store LP token permit nonces for accounts
+            let lp_locks = Mapping::default(); // Mapping to store LP share locks for accounts
+            let cumulative_volume: (Balance, Balance) = (0, 0); // Total swapped PSP22 and AZERO
+            let daily_volume = Mapping::default(); // Mapping to store daily volume and fee buckets
+
+            // LP token metadata derived from the underlying PSP22 token symbol
+            let psp22_symbol = Self::fetch_psp22_symbol(psp22_contract);
+            metadata
+                .name
+                .set(&Some(format!("Panorama LP AZERO-{}", psp22_symbol)));
+            metadata
+                .symbol
+                .set(&Some(format!("AZERO-{}-LP", psp22_symbol)));
+            // LP shares use the same 10^12 scaling as the pair share math
+            metadata.decimals.set(&12);
 
             // Return a new instance of TradingPairAzero with initialized variables
             Self {
                 transasction_number,
                 psp22_token,
                 fee,
-                total_supply,
-                balances,
                 panx_contract,
-                lp_tokens_allowances,
                 vault,
                 psp22_lp_fee_vault,
                 azero_lp_fee_vault,
@@ -252,9 +466,27 @@ pub mod trading_pair_azero {
                 account_overall_staking_rewards,
                 account_overall_lp_fee_rewards,
                 last_redeemed,
+                reward_operators,
                 lp_lock_timestamp,
                 psp22,
+                metadata,
                 deployer,
+                admin,
+                panx_discount_tiers,
+                locked_panx,
+                total_locked_panx,
+                lp_permit_nonces,
+                lp_locks,
+                cumulative_volume,
+                daily_volume,
+            }
+        }
+
+        /// function to fetch the symbol of the underlying PSP22 token, if it exposes one.
+        fn fetch_psp22_symbol(psp22_contract: AccountId) -> String {
+            match PSP22MetadataRef::token_symbol_builder(&psp22_contract).try_invoke() {
+                Ok(Ok(Some(symbol))) => symbol,
+                _ => String::from("PSP22"),
             }
         }
 
@@ -294,31 +526,36 @@ pub mod trading_pair_azero {
                 return Err(TradingPairErrors::NotEnoughAllowance)
             }
 
-            let mut shares: U256 = U256::from(0); // Initialize shares variable to 0
+            let shares: Balance; // LP shares to give to the caller
 
-            if self.total_supply == 0 {
-                shares =
-                    U256::from(self.env().transferred_value()) * U256::from(psp22_deposit_amount);
+            let is_first_deposit = self.get_total_supply() == 0; // Whether this deposit creates the pool
 
-                match shares.checked_div(U256::from(10u128.pow(12))) {
+            if is_first_deposit {
+                match pricing::get_initial_shares(
+                    self.env().transferred_value(),
+                    psp22_deposit_amount,
+                ) {
                     Some(result) => {
                         shares = result;
                     }
                     None => return Err(TradingPairErrors::Overflow),
                 };
-            }
-
-            if self.total_supply > 0 {
+            } else {
                 let reserve_before_transaction =
                     self.get_a0_balance() - a0_deposit_amount;
 
-                let coin_product = (a0_deposit_amount * self.total_supply)
-                    / reserve_before_transaction;
-
-                let psp22_product =
-                    (psp22_deposit_amount * self.total_supply) / self.get_psp22_balance();
-
-                shares = U256::from(self._min(coin_product, psp22_product));
+                match pricing::get_deposit_shares(
+                    a0_deposit_amount,
+                    psp22_deposit_amount,
+                    reserve_before_transaction,
+                    self.get_psp22_balance(),
+                    self.get_total_supply(),
+                ) {
+                    Some(result) => {
+                        shares = result;
+                    }
+                    None => return Err(TradingPairErrors::Overflow),
+                };
 
                 let psp22_amount_needed_to_deposit =
                     self.get_psp22_amount_for_lp(a0_deposit_amount, reserve_before_transaction);
@@ -326,13 +563,11 @@ pub mod trading_pair_azero {
                 let a0_amount_needed_to_deposit =
                     self.get_a0_amount_for_lp(psp22_deposit_amount, reserve_before_transaction);
 
-                let psp22_deposit_percentage_diff = self
-                    .check_difference(psp22_deposit_amount, psp22_amount_needed_to_deposit)
-                    .unwrap();
+                let psp22_deposit_percentage_diff =
+                    self.check_difference(psp22_deposit_amount, psp22_amount_needed_to_deposit)?;
 
-                let a0_deposit_percentage_diff = self
-                    .check_difference(a0_amount_needed_to_deposit, a0_deposit_amount)
-                    .unwrap();
+                let a0_deposit_percentage_diff =
+                    self.check_difference(a0_amount_needed_to_deposit, a0_deposit_amount)?;
 
                 if psp22_deposit_percentage_diff > slippage && a0_deposit_percentage_diff > slippage
                 {
@@ -340,14 +575,13 @@ pub mod trading_pair_azero {
                 }
             }
 
-            if shares <= U256::from(0) {
+            if shares <= 0 {
                 // If shares is less than or equal to 0, return an error difference
                 return Err(TradingPairErrors::ZeroSharesGiven)
             }
 
-            let percentage_diff = self
-                .check_difference(expected_lp_tokens, shares.as_u128())
-                .unwrap(); // Calculate the percentage difference between expected LP tokens and calculated shares
+            // Calculate the percentage difference between expected LP tokens and calculated shares
+            let percentage_diff = self.check_difference(expected_lp_tokens, shares)?;
 
             // Validate slippage tolerance
             if percentage_diff > slippage.try_into().unwrap() {
@@ -355,19 +589,10 @@ pub mod trading_pair_azero {
                 return Err(TradingPairErrors::SlippageTolerance)
             }
 
-            let current_shares: Balance = self.get_lp_token_of(caller); // Get the current LP tokens balance of the caller
-
-            let new_caller_shares: Balance; // Initialize new caller shares variable
-
-            // Calculate the new caller shares by adding current shares and calculated shares
-            match current_shares.checked_add(shares.as_u128()) {
-                Some(result) => {
-                    new_caller_shares = result;
-                }
-                None => {
-                    return Err(TradingPairErrors::Overflow) // If overflow occurs during calculation, return an error
-                }
-            };
+            // Make sure minting `shares` does not overflow the LP tokens total supply
+            if self.get_total_supply().checked_add(shares).is_none() {
+                return Err(TradingPairErrors::Overflow)
+            }
 
             // Perform a cross-contract call to the PSP22 token contract to transfer `psp22_deposit_amount` tokens from `caller` to the current contract's account ID
             if PSP22Ref::transfer_from_builder(
@@ -394,14 +619,49 @@ pub mod trading_pair_azero {
                 return Err(TradingPairErrors::CallerInsufficientPSP22Balance)
             }
 
-            // Increase the LP balance of `caller` (mint) by inserting `new_caller_shares` into `self.balances`
-            self.balances.insert(caller, &(new_caller_shares));
+            // Mint `shares` LP tokens to `caller`
+            if psp22::Internal::_mint_to(self, caller, shares).is_err() {
+                return Err(TradingPairErrors::Overflow)
+            }
+
+            // Shares the deployer provides before the LP lock timestamp are locked until it
+            let current_timestamp = self.get_current_timestamp();
+            if caller == self.deployer && current_timestamp < self.lp_lock_timestamp {
+                let mut locks = self.lp_locks.get(&caller).unwrap_or_default();
+
+                locks.retain(|(_, lock_timestamp)| *lock_timestamp > current_timestamp);
 
-            // self._mint_to(caller, shares.as_u128());
-            psp22::Internal::_mint_to(self, caller, shares.as_u128());
+                // every deposit adds to the same deployer lock instead of pushing a new one
+                match locks
+                    .iter_mut()
+                    .find(|(_, lock_timestamp)| *lock_timestamp == self.lp_lock_timestamp)
+                {
+                    Some((locked_amount, _)) => {
+                        *locked_amount = locked_amount.saturating_add(shares);
+                    }
+                    None => {
+                        if locks.len() >= MAX_LP_LOCKS {
+                            return Err(TradingPairErrors::TooManyLpLocks)
+                        }
+
+                        locks.push((shares, self.lp_lock_timestamp));
+                    }
+                }
+
+                self.lp_locks.insert(caller, &locks);
+            }
 
-            // Add `shares` to the total supply of LP tokens (mint)
-            self.total_supply += shares.as_u128();
+            // Permanently lock the minimum liquidity of a new pool by minting it to a dead address
+            if is_first_deposit
+                && psp22::Internal::_mint_to(
+                    self,
+                    AccountId::from(DEAD_ACCOUNT),
+                    pricing::MINIMUM_LIQUIDITY,
+                )
+                .is_err()
+            {
+                return Err(TradingPairErrors::Overflow)
+            }
 
             // Update the incentive program for `caller`, and if it fails, return an error
             if self.update_incentive_program(caller).is_err() {
@@ -411,9 +671,12 @@ pub mod trading_pair_azero {
             // Emit an event indicating the liquidity pool provision details
             Self::env().emit_event(LiquidityPoolProvision {
                 provider: caller,
-                a0_deposited_amount: self.env().transferred_value(),
+                a0_deposited_amount: a0_deposit_amount,
                 psp22_deposited_amount: psp22_deposit_amount,
-                shares_given: shares.as_u128(),
+                shares_given: shares,
+                new_a0_reserve: self.get_a0_balance(),
+                new_psp22_reserve: self.get_psp22_balance(),
+                new_total_supply: self.get_total_supply(),
             });
 
             // Return a successful result
@@ -430,34 +693,35 @@ pub mod trading_pair_azero {
             // caller address
             let caller = self.env().caller();
 
-            if self.get_current_timestamp() < self.lp_lock_timestamp && caller == self.deployer {
-                return Err(TradingPairErrors::LpStillLocked)
-            }
-
             // throw error is the caller tries to withdraw 0 LP shares
             if shares <= 0 {
                 return Err(TradingPairErrors::ZeroSharesGiven)
             }
 
             // caller total LP shares
-            let caller_shares: Balance = self.balances.get(&caller).unwrap_or(0);
+            let caller_shares: Balance = self.get_lp_token_of(caller);
 
             // validating that the caller has more than the given number of shares.
             if caller_shares < shares {
                 return Err(TradingPairErrors::CallerInsufficientLPBalance)
             }
 
+            // locked LP shares can not be withdrawn before their unlock timestamp
+            if self.get_unlocked_lp_of(caller) < shares {
+                return Err(TradingPairErrors::LpStillLocked)
+            }
+
             // amount of PSP22 tokens to give to the caller
-            let psp22_amount_to_give = self.get_psp22_withdraw_tokens_amount(shares).unwrap();
+            let psp22_amount_to_give = self.get_psp22_withdraw_tokens_amount(shares)?;
 
             // amount of A0 to give to the caller
-            let a0_amount_to_give = self.get_a0_withdraw_tokens_amount(shares).unwrap();
+            let a0_amount_to_give = self.get_a0_withdraw_tokens_amount(shares)?;
 
             // amount of PSP22 tokens the caller earned from the LP fee
-            let psp22_fee_amount_to_give = self.get_psp22_lp_fee_tokens(shares).unwrap();
+            let psp22_fee_amount_to_give = self.get_psp22_lp_fee_tokens(shares)?;
 
             // amount of AZERO tokens the caller earned from the LP fee
-            let a0_fee_amount_to_give = self.get_a0_lp_fee_tokens(shares).unwrap();
+            let a0_fee_amount_to_give = self.get_a0_lp_fee_tokens(shares)?;
 
             // Initialize new_caller_lp_shares variable to 0
             let new_caller_lp_shares: Balance;
@@ -481,26 +745,9 @@ pub mod trading_pair_azero {
                 return Err(TradingPairErrors::A0TransferFailed)
             }
 
-            // reducing caller total LP share tokens balance
-            self.balances.insert(caller, &(new_caller_lp_shares));
-            // self._burn_from(caller, shares);
-            psp22::Internal::_burn_from(self, caller, shares);
-
-            // reducing overall LP token supply
-            self.total_supply -= shares;
-
-            if self.total_supply == 0 {
-                // cross contract call to PSP22 contract to transfer PSP2 tokens to the caller
-                if PSP22Ref::transfer(&self.psp22_token, caller, self.get_psp22_balance(), vec![])
-                    .is_err()
-                {
-                    return Err(TradingPairErrors::PSP22TransferFailed)
-                }
-
-                // function to transfer A0 to the caller
-                if self.env().transfer(caller, self.get_a0_balance()).is_err() {
-                    return Err(TradingPairErrors::A0TransferFailed)
-                }
+            // burning the caller LP share tokens
+            if psp22::Internal::_burn_from(self, caller, shares).is_err() {
+                return Err(TradingPairErrors::CallerInsufficientLPBalance)
             }
 
             // update caller's incentive program claim percentage according to the new LP share tokens
@@ -533,7 +780,12 @@ pub mod trading_pair_azero {
                 shares_given: shares,
                 a0_given_amount: a0_amount_to_give,
                 psp22_given_amount: psp22_amount_to_give,
+                a0_fee_given_amount: a0_fee_amount_to_give,
+                psp22_fee_given_amount: psp22_fee_amount_to_give,
                 new_shares_balance: new_caller_lp_shares,
+                new_a0_reserve: self.get_a0_balance(),
+                new_psp22_reserve: self.get_psp22_balance(),
+                new_total_supply: self.get_total_supply(),
             });
 
             // Return a successful result
@@ -547,47 +799,21 @@ pub mod trading_pair_azero {
             &mut self,
             shares_amount: Balance,
         ) -> Result<(Balance, Balance), TradingPairErrors> {
-            let mut amount_of_a0_to_give: Balance; // Amount of A0 tokens to give to the caller.
-
-            let actual_a0_balance = self.get_a0_balance(); // Get the actual balance of A0 tokens.
-
-            // Calculate the amount of A0 tokens to give to the caller.
-            match (shares_amount * actual_a0_balance).checked_div(self.total_supply) {
-                Some(result) => {
-                    amount_of_a0_to_give = result;
-                }
-                None => {
-                    return Err(TradingPairErrors::Overflow) // Return an error if overflow occurs.
-                }
-            };
-
-            let actual_psp22_balance = self.get_psp22_balance(); // Get the actual balance of PSP22 tokens.
-
-            let mut amount_of_psp22_to_give: U256; // Amount of PSP22 tokens to give to the caller.
-
-            // Calculate the amount of PSP22 tokens to give to the caller.
-            match (U256::from(shares_amount) * U256::from(actual_psp22_balance))
-                .checked_div(U256::from((self.total_supply)))
-            {
-                Some(result) => {
-                    amount_of_psp22_to_give = result;
-                }
-                None => {
-                    return Err(TradingPairErrors::Overflow) // Return an error if overflow occurs.
-                }
-            };
+            // amount of A0 and PSP22 tokens to give to the caller with LP fees.
+            let (amount_of_a0_to_give, amount_of_psp22_to_give) =
+                self.get_withdraw_tokens_amount_with_lp(shares_amount)?;
 
             // amount of PSP22 tokens the caller earned from the LP fee
-            let psp22_fee_amount_to_give = self.get_psp22_lp_fee_tokens(shares_amount).unwrap();
+            let psp22_fee_amount_to_give = self.get_psp22_lp_fee_tokens(shares_amount)?;
 
             // amount of AZERO tokens the caller earned from the LP fee
-            let a0_fee_amount_to_give = self.get_a0_lp_fee_tokens(shares_amount).unwrap();
-
-            amount_of_psp22_to_give -= U256::from(psp22_fee_amount_to_give);
+            let a0_fee_amount_to_give = self.get_a0_lp_fee_tokens(shares_amount)?;
 
-            amount_of_a0_to_give -= a0_fee_amount_to_give;
-
-            Ok((amount_of_a0_to_give, amount_of_psp22_to_give.as_u128())) // Return the calculated amounts of A0 and PSP22 tokens to give to the caller.
+            // Return the calculated amounts of A0 and PSP22 tokens to give to the caller.
+            Ok((
+                amount_of_a0_to_give - a0_fee_amount_to_give,
+                amount_of_psp22_to_give - psp22_fee_amount_to_give,
+            ))
         }
 
         /// function to get the amount of withdrawable PSP22 and A0 by given number of LP shares with LP fees.
@@ -596,31 +822,13 @@ pub mod trading_pair_azero {
             &self,
             shares_amount: Balance,
         ) -> Result<(Balance, Balance), TradingPairErrors> {
-            let amount_of_a0_to_give: U256;
-
-            let actual_a0_balance = self.get_a0_balance();
-
             // calculating the amount of A0 to give to the caller.
-            match (U256::from(shares_amount) * U256::from(actual_a0_balance)).checked_div(U256::from(self.total_supply)) {
-                Some(result) => {
-                    amount_of_a0_to_give = result;
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
-
-            let actual_psp22_balance = self.get_psp22_balance();
-
-            let amount_of_psp22_to_give: U256;
+            let amount_of_a0_to_give = self.get_a0_withdraw_tokens_amount(shares_amount)?;
 
             // calculating the amount of PSP22 to give to the caller.
-            match (U256::from(shares_amount) * U256::from(actual_psp22_balance)).checked_div(U256::from(self.total_supply)) {
-                Some(result) => {
-                    amount_of_psp22_to_give = result;
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
+            let amount_of_psp22_to_give = self.get_psp22_withdraw_tokens_amount(shares_amount)?;
 
-            Ok((amount_of_a0_to_give.as_u128(), amount_of_psp22_to_give.as_u128()))
+            Ok((amount_of_a0_to_give, amount_of_psp22_to_give))
         }
 
         /// function to get the amount of withdrawable pooled PSP22 tokens by given number of LP shares without LP fees.
@@ -629,46 +837,32 @@ pub mod trading_pair_azero {
             &self,
             shares_amount: Balance,
         ) -> Result<Balance, TradingPairErrors> {
-            let amount_of_psp22_to_give: U256;
-
-            let actual_psp22_balance = self.get_psp22_balance();
-
             // calculating the amount of PSP22 to give to the caller.
-            match (U256::from(shares_amount) * U256::from(actual_psp22_balance))
-                .checked_div(U256::from(self.total_supply))
-            {
-                Some(result) => {
-                    amount_of_psp22_to_give = result;
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
-
-            let amount_of_psp22_to_give_u128 = amount_of_psp22_to_give.as_u128();
-
-            Ok(amount_of_psp22_to_give_u128)
+            match pricing::get_share_of(
+                shares_amount,
+                self.get_psp22_balance(),
+                self.get_total_supply(),
+            ) {
+                Some(result) => Ok(result),
+                None => Err(TradingPairErrors::Overflow),
+            }
         }
 
         /// function to get the amount of PSP22 LP fee tokens by number of shares
         #[ink(message)]
         pub fn get_psp22_lp_fee_tokens(
-            &mut self,
+            &self,
             shares_amount: Balance,
         ) -> Result<Balance, TradingPairErrors> {
-            let amount_of_psp22_fees_to_give: U256;
-
-            // calculating the amount of PSP22 to give to the caller.
-            match (U256::from(shares_amount) * U256::from(self.psp22_lp_fee_vault))
-                .checked_div(U256::from(self.total_supply))
-            {
-                Some(result) => {
-                    amount_of_psp22_fees_to_give = result;
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
-
-            let amount_of_psp22_to_give_u128 = amount_of_psp22_fees_to_give.as_u128();
-
-            Ok(amount_of_psp22_to_give_u128)
+            // calculating the amount of LP fee PSP22 to give to the caller.
+            match pricing::get_share_of(
+                shares_amount,
+                self.psp22_lp_fee_vault,
+                self.get_total_supply(),
+            ) {
+                Some(result) => Ok(result),
+                None => Err(TradingPairErrors::Overflow),
+            }
         }
 
         /// function to get the percentage difference between the PSP22 pooled tokens without LP fee and with LP fees
@@ -678,7 +872,7 @@ pub mod trading_pair_azero {
             let caller = self.env().caller();
 
             // caller total LP shares
-            let caller_shares: Balance = self.balances.get(&caller).unwrap_or(0);
+            let caller_shares: Balance = self.get_lp_token_of(caller);
 
             let amount_of_psp22_fees: Balance = self.get_psp22_lp_fee_tokens(caller_shares)?;
 
@@ -699,25 +893,15 @@ pub mod trading_pair_azero {
             &self,
             shares_amount: Balance,
         ) -> Result<Balance, TradingPairErrors> {
-            let amount_of_a0_to_give: U256;
-
-            let actual_a0_balance = self.get_a0_balance();
-
-            
-
             // calculating the amount of A0 to give to the caller.
-            match (U256::from(shares_amount) * U256::from(actual_a0_balance))
-                .checked_div(U256::from(self.total_supply))
-            {
-                Some(result) => {
-                    amount_of_a0_to_give = result;
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
-
-            let amount_of_a0_to_give_u128 = amount_of_a0_to_give.as_u128();
-
-            Ok(amount_of_a0_to_give_u128)
+            match pricing::get_share_of(
+                shares_amount,
+                self.get_a0_balance(),
+                self.get_total_supply(),
+            ) {
+                Some(result) => Ok(result),
+                None => Err(TradingPairErrors::Overflow),
+            }
         }
 
         /// function to get the amount of A0 LP fee tokens by number of shares
@@ -726,17 +910,15 @@ pub mod trading_pair_azero {
             &self,
             shares_amount: Balance,
         ) -> Result<Balance, TradingPairErrors> {
-            let amount_of_a0_fees_to_give: Balance;
-
             // calculating the amount of LP fee A0 to give to the caller.
-            match (shares_amount * self.azero_lp_fee_vault).checked_div(self.total_supply) {
-                Some(result) => {
-                    amount_of_a0_fees_to_give = result;
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
-
-            Ok(amount_of_a0_fees_to_give)
+            match pricing::get_share_of(
+                shares_amount,
+                self.azero_lp_fee_vault,
+                self.get_total_supply(),
+            ) {
+                Some(result) => Ok(result),
+                None => Err(TradingPairErrors::Overflow),
+            }
         }
 
         /// function to get the percentage difference between the AZERO pooled coins without LP fee and with LP fees
@@ -746,18 +928,17 @@ pub mod trading_pair_azero {
             let caller = self.env().caller();
 
             // caller total LP shares
-            let caller_shares: Balance = self.balances.get(&caller).unwrap_or(0);
+            let caller_shares: Balance = self.get_lp_token_of(caller);
 
-            let amount_of_a0_fees: Balance = self.get_a0_lp_fee_tokens(caller_shares).unwrap();
+            let amount_of_a0_fees: Balance = self.get_a0_lp_fee_tokens(caller_shares)?;
 
             // amount of PSP22 to give to the caller
-            let a0_amount_without_fees = self.get_a0_withdraw_tokens_amount(caller_shares).unwrap();
+            let a0_amount_without_fees = self.get_a0_withdraw_tokens_amount(caller_shares)?;
 
             let a0_amount_with_fees = a0_amount_without_fees + amount_of_a0_fees;
 
-            let percentage_diff: Balance = self
-                .check_difference(a0_amount_without_fees, a0_amount_with_fees)
-                .unwrap();
+            let percentage_diff: Balance =
+                self.check_difference(a0_amount_without_fees, a0_amount_with_fees)?;
 
             Ok(percentage_diff)
         }
@@ -768,56 +949,18 @@ pub mod trading_pair_azero {
             &self,
             account_id: AccountId,
         ) -> Result<(Balance, Balance), TradingPairErrors> {
-            // caller address
-            let caller = account_id;
-            // get caller LP tokens
-            let caller_shares: Balance = self.balances.get(&caller).unwrap_or(0);
+            // get account LP tokens
+            let account_shares: Balance = self.get_lp_token_of(account_id);
 
-            let mut amount_of_a0_to_give: Balance = 0;
-
-            let mut amount_of_psp22_to_give: Balance = 0;
-
-            if caller_shares <= 0 {
-                return Ok((amount_of_psp22_to_give, amount_of_a0_to_give))
+            if account_shares <= 0 {
+                return Ok((0, 0))
             }
 
-            let actual_a0_balance = self.get_a0_balance();
-
-            // calculating the amount of A0 to give to the caller.
-            match (caller_shares * actual_a0_balance).checked_div(self.total_supply) {
-                Some(result) => {
-                    amount_of_a0_to_give = result;
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
-
-            let mut actual_psp22_balance = self.get_psp22_balance();
-
-            match actual_psp22_balance.checked_div(10u128.pow(12)) {
-                Some(result) => {
-                    actual_psp22_balance = result;
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
-
-            // calculating the amount of PSP22 to give to the caller.
-            match (caller_shares * actual_psp22_balance).checked_div(self.total_supply) {
-                Some(result) => {
-                    amount_of_psp22_to_give = result;
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
-
-            let actual_amount_of_psp22_to_give: Balance;
-
-            match amount_of_psp22_to_give.checked_mul(10u128.pow(12)) {
-                Some(result) => {
-                    actual_amount_of_psp22_to_give = result;
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
+            // calculating the amount of A0 and PSP22 to give to the account.
+            let (amount_of_a0_to_give, amount_of_psp22_to_give) =
+                self.get_withdraw_tokens_amount_with_lp(account_shares)?;
 
-            Ok((actual_amount_of_psp22_to_give, amount_of_a0_to_give))
+            Ok((amount_of_psp22_to_give, amount_of_a0_to_give))
         }
 
         // function to get the expected amount of LP shares by given A0 amount.
@@ -827,47 +970,32 @@ pub mod trading_pair_azero {
             a0_deposit_amount: Balance,
             psp22_deposit_amount: Balance,
         ) -> Result<Balance, TradingPairErrors> {
+            let shares: Option<Balance>;
 
-            let mut shares: U256 = U256::from(0) ;
-
-            // if its the trading pair first deposit
-            if self.total_supply == 0 {
-
-                shares = U256::from(a0_deposit_amount) * U256::from(psp22_deposit_amount);
-
-                match shares.checked_div(U256::from(10u128.pow(12))) {
-                    Some(result) => {
-                        shares = result;
-                    }
-                    None => return Err(TradingPairErrors::Overflow),
-                };
-
+            if self.get_total_supply() == 0 {
+                // if its the trading pair first deposit
+                shares = pricing::get_initial_shares(a0_deposit_amount, psp22_deposit_amount);
+            } else {
+                // if its not the first LP deposit
+                shares = pricing::get_deposit_shares(
+                    a0_deposit_amount,
+                    psp22_deposit_amount,
+                    self.get_a0_balance(),
+                    self.get_psp22_balance(),
+                    self.get_total_supply(),
+                );
             }
 
-            // if its not the first LP deposit
-            if self.total_supply > 0 {
-
-                let coin_product = (a0_deposit_amount * self.total_supply)
-                    / self.get_a0_balance();
-
-                let psp22_product =
-                    (psp22_deposit_amount * self.total_supply) / self.get_psp22_balance();
-
-                shares = U256::from(self._min(coin_product, psp22_product));
-
+            match shares {
+                Some(result) => Ok(result),
+                None => Err(TradingPairErrors::Overflow),
             }
-
-            Ok(shares.as_u128())
         }
 
         /// function to get the amount of A0 the caller will get for 1 PSP22 token.
         #[ink(message)]
         pub fn get_price_for_one_psp22(&self) -> Result<Balance, TradingPairErrors> {
-            let amount_out = self
-                .get_est_price_psp22_to_a0(1u128 * (10u128.pow(12)))
-                .unwrap();
-
-            Ok(amount_out)
+            self.get_est_price_psp22_to_a0(1u128 * (10u128.pow(12)))
         }
 
         /// function to get the amount of A0 the caller will get for given PSP22 amount.
@@ -878,93 +1006,27 @@ pub mod trading_pair_azero {
         ) -> Result<Balance, TradingPairErrors> {
             let caller = self.env().caller();
 
-            // fetching caller current PSP22 balance
-            let caller_current_balance: Balance = PSP22Ref::balance_of(&self.panx_contract, caller);
+            // the LP fee after the caller PANX discount
+            let lp_fee: Balance = self.get_discounted_fee(caller);
+
+            match pricing::get_amount_out(
+                psp22_amount_in,
+                self.get_psp22_balance(),
+                self.get_a0_balance(),
+                lp_fee,
+            ) {
+                Some(result) => Ok(result),
+                None => Err(TradingPairErrors::Overflow),
+            }
+        }
 
-            let mut psp22_amount_in_with_lp_fees: U256;
-            psp22_amount_in_with_lp_fees = U256::from(0);
-
-            // reducting the LP fee from the PSP22 amount in
-            match U256::from(psp22_amount_in)
-                .checked_mul(U256::from(100u128 * 10u128.pow(12)) - (self.fee))
-            {
-                Some(result) => {
-                    psp22_amount_in_with_lp_fees = result;
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
-
-            const TOKENS_TO_VALIDATE: Balance = 3500u128 * 10u128.pow(12);
-
-            // validating if caller has more than 3500 PANX to verify if the caller is eligible for the incentive program
-            if caller_current_balance >= TOKENS_TO_VALIDATE {
-                if self.fee <= 1400000000000u128 {
-                    // reducting HALF of the LP fee from PSP22 amount in, if the caller has more than 3500 PANX and the LP fee is less than 1.4%
-                    match U256::from(psp22_amount_in)
-                        .checked_mul(U256::from(100u128 * 10u128.pow(12)) - (self.fee / 2u128))
-                    {
-                        Some(result) => {
-                            psp22_amount_in_with_lp_fees = result;
-                        }
-                        None => return Err(TradingPairErrors::Overflow),
-                    };
-                }
-
-                if self.fee > 1400000000000u128 {
-                    // reducting (LP fee - 1) of the LP fee from PSP22 amount in, if the caller has more than 3500 PANX and the LP fee is more than 1.4%
-                    match U256::from(psp22_amount_in).checked_mul(
-                        U256::from(100u128 * 10u128.pow(12))
-                            - (self.fee - (1u128 * 10u128.pow(12))),
-                    ) {
-                        Some(result) => {
-                            psp22_amount_in_with_lp_fees = result;
-                        }
-                        None => return Err(TradingPairErrors::Overflow),
-                    };
-                }
-            }
-
-            psp22_amount_in_with_lp_fees = psp22_amount_in_with_lp_fees / 10u128.pow(12);
-
-            let mut numerator: U256 = U256::from(0);
-            let mut denominator: U256 = U256::from(0);
-            let a0_amount_out: Balance;
-
-            match U256::from(psp22_amount_in_with_lp_fees)
-                .checked_mul(U256::from(self.get_a0_balance()))
-            {
-                Some(result) => {
-                    numerator = result;
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
-
-            match (U256::from(self.get_psp22_balance()) * U256::from(100))
-                .checked_add(U256::from(psp22_amount_in_with_lp_fees))
-            {
-                Some(result) => {
-                    denominator = result;
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
-
-            match numerator.checked_div(denominator) {
-                Some(result) => {
-                    a0_amount_out = result.as_u128();
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
-
-            Ok(a0_amount_out)
-        }
-
-        /// function to get the amount of PSP22 the caller will get for given A0 amount (swap use)
-        #[ink(message)]
-        pub fn get_est_price_a0_to_psp22_for_swap(
-            &self,
-            a0_amout_in: Balance,
-        ) -> Result<Balance, TradingPairErrors> {
-            let caller = self.env().caller();
+        /// function to get the amount of PSP22 the caller will get for given A0 amount (swap use)
+        #[ink(message)]
+        pub fn get_est_price_a0_to_psp22_for_swap(
+            &self,
+            a0_amout_in: Balance,
+        ) -> Result<Balance, TradingPairErrors> {
+            let caller = self.env().caller();
 
             let a0_reserve_before: Balance;
 
@@ -976,82 +1038,18 @@ pub mod trading_pair_azero {
                 None => return Err(TradingPairErrors::Overflow),
             };
 
-            let caller_current_balance: Balance = PSP22Ref::balance_of(&self.panx_contract, caller);
-
-            let mut a0_amount_in_with_lp_fees: U256 = U256::from(0);
-
-            // reducting the LP fee from the A0 amount in
-            match U256::from(a0_amout_in)
-                .checked_mul(U256::from(100u128 * 10u128.pow(12)) - self.fee)
-            {
-                Some(result) => {
-                    a0_amount_in_with_lp_fees = result;
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
-
-            const TOKENS_TO_VALIDATE: Balance = 3500u128 * 10u128.pow(12);
-
-            // validating if the caller has more than 3500 PANX
-            if caller_current_balance >= TOKENS_TO_VALIDATE {
-                if self.fee <= 1400000000000 {
-                    // reducting HALF of the LP fee from the A0 amount in, if the caller has more than 3500 PANX and the LP fee is less than 1.4%
-                    match U256::from(a0_amout_in)
-                        .checked_mul(U256::from(100u128 * 10u128.pow(12)) - (self.fee / 2u128))
-                    {
-                        Some(result) => {
-                            a0_amount_in_with_lp_fees = result;
-                        }
-                        None => return Err(TradingPairErrors::Overflow),
-                    };
-                }
-
-                if self.fee > 1400000000000 {
-                    // reducting (LP fee - 1) of the LP fee from the A0 amount in, if the caller has more than 3500 PANX and the LP fee is more than 1.4%
-                    match U256::from(a0_amout_in).checked_mul(
-                        U256::from(100u128 * 10u128.pow(12))
-                            - (self.fee - (1u128 * 10u128.pow(12))),
-                    ) {
-                        Some(result) => {
-                            a0_amount_in_with_lp_fees = result;
-                        }
-                        None => return Err(TradingPairErrors::Overflow),
-                    };
-                }
+            // the LP fee after the caller PANX discount
+            let lp_fee: Balance = self.get_discounted_fee(caller);
+
+            match pricing::get_amount_out(
+                a0_amout_in,
+                a0_reserve_before,
+                self.get_psp22_balance(),
+                lp_fee,
+            ) {
+                Some(result) => Ok(result),
+                None => Err(TradingPairErrors::Overflow),
             }
-
-            a0_amount_in_with_lp_fees = a0_amount_in_with_lp_fees / 10u128.pow(12);
-
-            let mut numerator: U256 = U256::from(0);
-            let mut denominator: U256 = U256::from(0);
-            let a0_amount_out: Balance;
-
-            match U256::from(a0_amount_in_with_lp_fees)
-                .checked_mul(U256::from(self.get_psp22_balance()))
-            {
-                Some(result) => {
-                    numerator = result;
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
-
-            match (U256::from(a0_reserve_before) * U256::from(100))
-                .checked_add(U256::from(a0_amount_in_with_lp_fees))
-            {
-                Some(result) => {
-                    denominator = result;
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
-
-            match numerator.checked_div(denominator) {
-                Some(result) => {
-                    a0_amount_out = result.as_u128();
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
-
-            Ok(a0_amount_out)
         }
 
         /// function to get the amount of PSP22 the caller will get for given A0 amount (front-end use)
@@ -1062,83 +1060,18 @@ pub mod trading_pair_azero {
         ) -> Result<Balance, TradingPairErrors> {
             let caller = self.env().caller();
 
-            let caller_current_balance: Balance = PSP22Ref::balance_of(&self.panx_contract, caller);
-
-            let mut a0_amount_in_with_lp_fees: U256 = U256::from(0);
-
-            // reducting the LP fee from the A0 amount in
-            match U256::from(a0_amount_in)
-                .checked_mul(U256::from(100u128 * 10u128.pow(12)) - self.fee)
-            {
-                Some(result) => {
-                    a0_amount_in_with_lp_fees = result;
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
-
-            const TOKENS_TO_VALIDATE: Balance = 3500u128 * 10u128.pow(12);
-
-            // validating if the caller has more than 3500 PANX
-            if caller_current_balance >= TOKENS_TO_VALIDATE {
-                if self.fee <= 1400000000000 {
-                    // reducting HALF of the LP fee from the A0 amount in, if the caller has more than 3500 PANX and the LP fee is less than 1.4%
-                    match U256::from(a0_amount_in)
-                        .checked_mul(U256::from(100u128 * 10u128.pow(12)) - (self.fee / 2u128))
-                    {
-                        Some(result) => {
-                            a0_amount_in_with_lp_fees = result;
-                        }
-                        None => return Err(TradingPairErrors::Overflow),
-                    };
-                }
-
-                if self.fee > 1400000000000 {
-                    // reducting (LP fee - 1) of the LP fee from the A0 amount in, if the caller has more than 3500 PANX and the LP fee is more than 1.4%
-                    match U256::from(a0_amount_in).checked_mul(
-                        U256::from(100u128 * 10u128.pow(12))
-                            - (self.fee - (1u128 * 10u128.pow(12))),
-                    ) {
-                        Some(result) => {
-                            a0_amount_in_with_lp_fees = result;
-                        }
-                        None => return Err(TradingPairErrors::Overflow),
-                    };
-                }
+            // the LP fee after the caller PANX discount
+            let lp_fee: Balance = self.get_discounted_fee(caller);
+
+            match pricing::get_amount_out(
+                a0_amount_in,
+                self.get_a0_balance(),
+                self.get_psp22_balance(),
+                lp_fee,
+            ) {
+                Some(result) => Ok(result),
+                None => Err(TradingPairErrors::Overflow),
             }
-
-            a0_amount_in_with_lp_fees = a0_amount_in_with_lp_fees / 10u128.pow(12);
-
-            let mut numerator: U256 = U256::from(0);
-            let mut denominator: U256 = U256::from(0);
-
-            let a0_amount_out: Balance;
-
-            match U256::from(a0_amount_in_with_lp_fees)
-                .checked_mul(U256::from(self.get_psp22_balance()))
-            {
-                Some(result) => {
-                    numerator = result;
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
-
-            match (U256::from(self.get_a0_balance()) * U256::from(100))
-                .checked_add(U256::from(a0_amount_in_with_lp_fees))
-            {
-                Some(result) => {
-                    denominator = result;
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
-
-            match numerator.checked_div(denominator) {
-                Some(result) => {
-                    a0_amount_out = result.as_u128();
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
-
-            Ok(a0_amount_out)
         }
 
         /// function to get the estimated price impact for given psp22 token amount
@@ -1147,54 +1080,21 @@ pub mod trading_pair_azero {
             &self,
             psp22_amount_in: Balance,
         ) -> Result<Balance, TradingPairErrors> {
-            // fetching the amount of A0 the caller WOULD get if he would swap
-            let current_amount_out = self.get_est_price_psp22_to_a0(psp22_amount_in).unwrap();
-
-            let mut psp22_amount_in_with_lp_fees: U256 = U256::from(0);
-
-            // reducting the LP fee from the PSP22 amount in
-            match U256::from(psp22_amount_in)
-                .checked_mul(U256::from(100u128 * 10u128.pow(12)) - (self.fee))
-            {
-                Some(result) => {
-                    psp22_amount_in_with_lp_fees = result;
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
-
-            psp22_amount_in_with_lp_fees = psp22_amount_in_with_lp_fees / 10u128.pow(12);
-
-            let mut numerator: U256 = U256::from(0);
-
-            let mut denominator: U256 = U256::from(0);
-            let future_a0_amount_out: Balance;
-
-            match U256::from(psp22_amount_in_with_lp_fees)
-                .checked_mul(U256::from(self.get_a0_balance() - current_amount_out))
-            {
-                Some(result) => {
-                    numerator = result;
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
-
-            match (U256::from(self.get_psp22_balance() + psp22_amount_in) * U256::from(100))
-                .checked_add(U256::from(psp22_amount_in_with_lp_fees))
-            {
-                Some(result) => {
-                    denominator = result;
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
-
-            match numerator.checked_div(denominator) {
-                Some(result) => {
-                    future_a0_amount_out = result.as_u128();
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
+            let caller = self.env().caller();
 
-            Ok(future_a0_amount_out)
+            // the LP fee after the caller PANX discount
+            let lp_fee: Balance = self.get_discounted_fee(caller);
+
+            // the amount of A0 the caller would get for the same PSP22 amount after this swap
+            match pricing::get_amount_out_after_swap(
+                psp22_amount_in,
+                self.get_psp22_balance(),
+                self.get_a0_balance(),
+                lp_fee,
+            ) {
+                Some(result) => Ok(result),
+                None => Err(TradingPairErrors::Overflow),
+            }
         }
 
         /// function to get the estimated price impact for given A0 amount
@@ -1203,54 +1103,21 @@ pub mod trading_pair_azero {
             &mut self,
             a0_amount_in: Balance,
         ) -> Result<Balance, TradingPairErrors> {
-            let current_amount_out = self.get_est_price_a0_to_psp22(a0_amount_in).unwrap();
-
-            let mut a0_amount_in_with_lp_fees: U256 = U256::from(0);
-
-            // reducting the LP fee from the A0 amount in
-            match U256::from(a0_amount_in)
-                .checked_mul(U256::from(100u128 * 10u128.pow(12)) - self.fee)
-            {
-                Some(result) => {
-                    a0_amount_in_with_lp_fees = result;
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
-
-            a0_amount_in_with_lp_fees = a0_amount_in_with_lp_fees / 10u128.pow(12);
-
-            let mut numerator: U256 = U256::from(0);
-
-            let mut denominator: U256 = U256::from(0);
-
-            let future_psp22_amount_out: Balance;
-
-            match U256::from(a0_amount_in_with_lp_fees)
-                .checked_mul(U256::from(self.get_psp22_balance() - current_amount_out))
-            {
-                Some(result) => {
-                    numerator = result;
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
-
-            match (U256::from(self.get_a0_balance() + a0_amount_in) * U256::from(100))
-                .checked_add(U256::from(a0_amount_in_with_lp_fees))
-            {
-                Some(result) => {
-                    denominator = result;
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
-
-            match numerator.checked_div(denominator) {
-                Some(result) => {
-                    future_psp22_amount_out = result.as_u128();
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
+            let caller = self.env().caller();
 
-            Ok(future_psp22_amount_out)
+            // the LP fee after the caller PANX discount
+            let lp_fee: Balance = self.get_discounted_fee(caller);
+
+            // the amount of PSP22 the caller would get for the same A0 amount after this swap
+            match pricing::get_amount_out_after_swap(
+                a0_amount_in,
+                self.get_a0_balance(),
+                self.get_psp22_balance(),
+                lp_fee,
+            ) {
+                Some(result) => Ok(result),
+                None => Err(TradingPairErrors::Overflow),
+            }
         }
 
         /// function to swap PSP22 to A0
@@ -1265,8 +1132,6 @@ pub mod trading_pair_azero {
 
             let contract_a0_current_balance = self.get_a0_balance();
 
-            const TRADERS_FEE: Balance = 2500000000000 / 10u128.pow(12); // Fee to be charged to traders
-
             // making sure that the contract has more than 0 A0 coins.
             if contract_a0_current_balance <= 0 {
                 return Err(TradingPairErrors::ContractOutOfA0)
@@ -1294,58 +1159,27 @@ pub mod trading_pair_azero {
                 return Err(TradingPairErrors::NotEnoughAllowance)
             }
 
-            // the amount of A0 to give to the caller before traders fee.
-            let a0_amount_out_for_caller_before_traders_fee: Balance = self
-                .get_est_price_psp22_to_a0(psp22_amount_to_transfer)
-                .unwrap();
+            // the full breakdown of the swap (amounts out, fees and new reserves)
+            let quote = self.get_swap_quote(
+                SwapDirection::Psp22ToA0,
+                psp22_amount_to_transfer,
+                caller,
+                contract_a0_current_balance,
+                contract_psp22_current_balance,
+            )?;
 
             // percentage dif between given A0 amount (from front-end) and acutal final AO amount
-            let percentage_diff: Balance = self
-                .check_difference(
-                    a0_amount_to_validate,
-                    a0_amount_out_for_caller_before_traders_fee,
-                )
-                .unwrap();
+            let percentage_diff: Balance =
+                self.check_difference(a0_amount_to_validate, quote.amount_out_before_fees)?;
 
             // validating slippage
             if percentage_diff > slippage.try_into().unwrap() {
                 return Err(TradingPairErrors::SlippageTolerance)
             }
 
-            let actual_a0_amount_out_for_caller: Balance;
-
-            let a0_amount_out_for_vault: Balance;
-
-            // calculating the amount of A0 coins to allocate to the vault account
-            match (a0_amount_out_for_caller_before_traders_fee * TRADERS_FEE).checked_div(1000u128)
-            {
-                Some(result) => {
-                    a0_amount_out_for_vault = result;
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
-
-            let actual_lp_fee: Balance;
-
-            // calculating the actual LP fee
-            match (self.fee / (10u128.pow(12))).checked_mul(10) {
-                Some(result) => {
-                    actual_lp_fee = result;
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
-
-            let a0_amount_out_for_lp_vault: Balance;
-
-            // calculating the amount of A0 coins to allocate to the lp vault
-            match (a0_amount_out_for_caller_before_traders_fee * actual_lp_fee)
-                .checked_div(1000u128)
-            {
-                Some(result) => {
-                    a0_amount_out_for_lp_vault = result;
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
+            let actual_a0_amount_out_for_caller = quote.amount_out;
+            let a0_amount_out_for_vault = quote.vault_fee_out;
+            let a0_amount_out_for_lp_vault = quote.lp_fee;
 
             let new_azero_lp_fee_vault: Balance;
 
@@ -1375,25 +1209,8 @@ pub mod trading_pair_azero {
 
             self.contract_overall_generated_azero_fee = new_contract_overall_generated_azero_fee;
 
-            // calculating the final amount of A0 coins to give to the caller after reducing traders fee
-            match a0_amount_out_for_caller_before_traders_fee
-                .checked_sub(a0_amount_out_for_vault + a0_amount_out_for_lp_vault)
-            {
-                Some(result) => {
-                    actual_a0_amount_out_for_caller = result;
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
-
-            let psp22_amount_out_for_vault: Balance;
-
-            // calculating the amount of PSP22 tokens to allocate to the vault account
-            match (psp22_amount_to_transfer * TRADERS_FEE).checked_div(1000u128) {
-                Some(result) => {
-                    psp22_amount_out_for_vault = result;
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
+            // the amount of PSP22 tokens to allocate to the vault account
+            let psp22_amount_out_for_vault = quote.vault_fee_in;
 
             // cross contract call to psp22 contract to transfer psp22 token to the Pair contract
             if PSP22Ref::transfer_from_builder(
@@ -1449,11 +1266,19 @@ pub mod trading_pair_azero {
 
             // increase num of trans
             self.transasction_number = self.transasction_number + 1;
+
+            self.record_volume(psp22_amount_to_transfer, 0, 0, a0_amount_out_for_lp_vault);
+
             Self::env().emit_event(PSP22Swap {
                 caller,
                 psp22_deposited_amount: psp22_amount_to_transfer,
                 a0_given_amount: actual_a0_amount_out_for_caller,
                 a0_given_to_vault: a0_amount_out_for_vault,
+                psp22_given_to_vault: psp22_amount_out_for_vault,
+                a0_lp_fee: a0_amount_out_for_lp_vault,
+                applied_fee: quote.applied_fee,
+                new_a0_reserve: self.get_a0_balance(),
+                new_psp22_reserve: self.get_psp22_balance(),
             });
 
             Ok(())
@@ -1470,8 +1295,6 @@ pub mod trading_pair_azero {
 
             let contract_a0_current_balance = self.get_a0_balance();
 
-            const TRADERS_FEE: Balance = 2500000000000 / 10u128.pow(12); // Fee to be charged to traders
-
             // making sure that the contract has more than 0 A0 coins.
             if contract_a0_current_balance <= 0 {
                 return Err(TradingPairErrors::ContractOutOfA0)
@@ -1484,59 +1307,27 @@ pub mod trading_pair_azero {
                 return Err(TradingPairErrors::ContractOutOfPSP22)
             }
 
-            // amount of PSP22 tokens to give to caller before traders fee.
-            let psp22_amount_out_for_caller_before_traders_fee: Balance = self
-                .get_est_price_a0_to_psp22_for_swap(self.env().transferred_value())
-                .unwrap();
+            // the full breakdown of the swap against the AZERO reserve before the transferred value
+            let quote = self.get_swap_quote(
+                SwapDirection::A0ToPsp22,
+                self.env().transferred_value(),
+                caller,
+                contract_a0_current_balance - self.env().transferred_value(),
+                contract_psp22_current_balance,
+            )?;
 
             // percentage dif between given PSP22 amount (from front-end) and the acutal final PSP22 amount.
-            let percentage_diff: Balance = self
-                .check_difference(
-                    psp22_amount_to_validate,
-                    psp22_amount_out_for_caller_before_traders_fee,
-                )
-                .unwrap();
+            let percentage_diff: Balance =
+                self.check_difference(psp22_amount_to_validate, quote.amount_out_before_fees)?;
 
             // validating slippage
             if percentage_diff > slippage.try_into().unwrap() {
                 return Err(TradingPairErrors::SlippageTolerance)
             }
 
-            let psp22_amount_out_for_vault: Balance;
-
-            let actual_psp22_amount_out_for_caller: Balance;
-
-            // calculating the amount of PSP22 tokens to allocate to the vault account
-            match (psp22_amount_out_for_caller_before_traders_fee * TRADERS_FEE)
-                .checked_div(1000u128)
-            {
-                Some(result) => {
-                    psp22_amount_out_for_vault = result;
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
-
-            let actual_lp_fee: Balance;
-
-            // calculating the actual LP fee
-            match (self.fee / (10u128.pow(12))).checked_mul(10) {
-                Some(result) => {
-                    actual_lp_fee = result;
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
-
-            let psp22_amount_out_for_lp_vault: Balance;
-
-            // calculating the amount of PSP22 tokens to allocate to the lp vault
-            match (psp22_amount_out_for_caller_before_traders_fee * actual_lp_fee)
-                .checked_div(1000u128)
-            {
-                Some(result) => {
-                    psp22_amount_out_for_lp_vault = result;
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
+            let actual_psp22_amount_out_for_caller = quote.amount_out;
+            let psp22_amount_out_for_vault = quote.vault_fee_out;
+            let psp22_amount_out_for_lp_vault = quote.lp_fee;
 
             let new_psp22_lp_fee_vault: Balance;
 
@@ -1566,25 +1357,8 @@ pub mod trading_pair_azero {
 
             self.contract_overall_generated_psp22_fee = new_contract_overall_generated_psp22_fee;
 
-            // calculating the final amount of PSP22 tokens to give to the caller after reducing traders fee
-            match psp22_amount_out_for_caller_before_traders_fee
-                .checked_sub(psp22_amount_out_for_vault + psp22_amount_out_for_lp_vault)
-            {
-                Some(result) => {
-                    actual_psp22_amount_out_for_caller = result;
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
-
-            let a0_amount_out_for_vault: Balance;
-
-            // calculating the amount of A0 coins to allocate to the vault account
-            match (self.env().transferred_value() * TRADERS_FEE).checked_div(1000u128) {
-                Some(result) => {
-                    a0_amount_out_for_vault = result;
-                }
-                None => return Err(TradingPairErrors::Overflow),
-            };
+            // the amount of A0 coins to allocate to the vault account
+            let a0_amount_out_for_vault = quote.vault_fee_in;
 
             // cross contract call to PSP22 contract to transfer PSP22 to the caller
             if PSP22Ref::transfer(
@@ -1622,21 +1396,190 @@ pub mod trading_pair_azero {
             // increase num of trans
             self.transasction_number = self.transasction_number + 1;
 
+            self.record_volume(
+                0,
+                self.env().transferred_value(),
+                psp22_amount_out_for_lp_vault,
+                0,
+            );
+
             Self::env().emit_event(A0Swap {
                 caller,
                 a0_deposited_amount: self.env().transferred_value(),
                 psp22_given_amount: actual_psp22_amount_out_for_caller,
                 psp22_given_to_vault: psp22_amount_out_for_vault,
+                a0_given_to_vault: a0_amount_out_for_vault,
+                psp22_lp_fee: psp22_amount_out_for_lp_vault,
+                applied_fee: quote.applied_fee,
+                new_a0_reserve: self.get_a0_balance(),
+                new_psp22_reserve: self.get_psp22_balance(),
             });
 
             Ok(())
         }
 
-        /// function to add caller to the LP incentive program
+        /// function to provide liquidity with a permit of the caller for the PSP22 deposit amount
+        /// instead of a prior `approve` transaction.
+        #[ink(message, payable)]
+        pub fn provide_to_pool_with_permit(
+            &mut self,
+            psp22_deposit_amount: Balance, // Amount of PSP22 tokens to be deposited
+            a0_deposit_amount: Balance,    // Amount of AZERO coins to be deposited
+            expected_lp_tokens: Balance,   // Expected amount of LP tokens to be received
+            slippage: Balance,             // Slippage tolerance percentage
+            deadline: u64,                 // Deadline of the permit (block timestamp)
+            signature: [u8; 65],           // Caller signature of the PSP22 permit
+        ) -> Result<(), TradingPairErrors> {
+            self.use_psp22_permit(psp22_deposit_amount, deadline, signature)?;
+
+            self.provide_to_pool(
+                psp22_deposit_amount,
+                a0_deposit_amount,
+                expected_lp_tokens,
+                slippage,
+            )
+        }
+
+        /// function to swap PSP22 to A0 with a permit of the caller for the PSP22 amount
+        /// instead of a prior `approve` transaction.
+        #[ink(message)]
+        pub fn swap_psp22_with_permit(
+            &mut self,
+            psp22_amount_to_transfer: Balance,
+            a0_amount_to_validate: Balance,
+            slippage: Balance,
+            deadline: u64,
+            signature: [u8; 65],
+        ) -> Result<(), TradingPairErrors> {
+            self.use_psp22_permit(psp22_amount_to_transfer, deadline, signature)?;
+
+            self.swap_psp22(psp22_amount_to_transfer, a0_amount_to_validate, slippage)
+        }
+
+        /// function to approve the pair over `value` PSP22 tokens of the caller with the caller permit.
+        /// A failed permit is accepted if the pair is already approved over `value` tokens, e.g. when
+        /// the same permit was front-run and used by another transaction.
+        fn use_psp22_permit(
+            &self,
+            value: Balance,
+            deadline: u64,
+            signature: [u8; 65],
+        ) -> Result<(), TradingPairErrors> {
+            // cross contract call to `PSP22Permit::permit` of the PSP22 contract
+            let result = build_call::<DefaultEnvironment>()
+                .call(self.psp22_token)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(ink::selector_bytes!(
+                        "PSP22Permit::permit"
+                    )))
+                    .push_arg(self.env().caller())
+                    .push_arg(Self::env().account_id())
+                    .push_arg(value)
+                    .push_arg(deadline)
+                    .push_arg(signature),
+                )
+                .returns::<Result<(), PSP22Error>>()
+                .try_invoke();
+
+            if matches!(result, Ok(Ok(Ok(())))) {
+                return Ok(())
+            }
+
+            let allowance: Balance = PSP22Ref::allowance(
+                &self.psp22_token,
+                self.env().caller(),
+                Self::env().account_id(),
+            );
+
+            if allowance < value {
+                return Err(TradingPairErrors::PSP22PermitFailed)
+            }
+
+            Ok(())
+        }
+
+        /// function to get the full breakdown of a swap of `amount_in` by `trader` in the given direction.
+        /// The returned amounts are exactly the ones `swap_psp22` / `swap_a0` would execute.
+        #[ink(message)]
+        pub fn quote_swap(
+            &self,
+            direction: SwapDirection,
+            amount_in: Balance,
+            trader: AccountId,
+        ) -> Result<SwapQuote, TradingPairErrors> {
+            self.get_swap_quote(
+                direction,
+                amount_in,
+                trader,
+                self.get_a0_balance(),
+                self.get_psp22_balance(),
+            )
+        }
+
+        /// function to get the full breakdown of a swap against the given reserves (before the swap).
+        fn get_swap_quote(
+            &self,
+            direction: SwapDirection,
+            amount_in: Balance,
+            trader: AccountId,
+            a0_reserve: Balance,
+            psp22_reserve: Balance,
+        ) -> Result<SwapQuote, TradingPairErrors> {
+            let (reserve_in, reserve_out) = match direction {
+                SwapDirection::Psp22ToA0 => (psp22_reserve, a0_reserve),
+                SwapDirection::A0ToPsp22 => (a0_reserve, psp22_reserve),
+            };
+
+            let panx_discount_bps = self.get_panx_discount_bps(trader);
+
+            // the LP fee after the trader PANX discount
+            let applied_fee = pricing::discounted_fee(self.fee, panx_discount_bps);
+
+            let swap = match pricing::get_swap_amounts(
+                amount_in,
+                reserve_in,
+                reserve_out,
+                self.fee,
+                applied_fee,
+            ) {
+                Some(result) => result,
+                None => return Err(TradingPairErrors::Overflow),
+            };
+
+            let price_impact: Balance;
+
+            match pricing::get_price_impact(amount_in, reserve_in, reserve_out, applied_fee) {
+                Some(result) => {
+                    price_impact = result;
+                }
+                None => return Err(TradingPairErrors::Overflow),
+            };
+
+            let (new_a0_reserve, new_psp22_reserve) = match direction {
+                SwapDirection::Psp22ToA0 => (swap.new_reserve_out, swap.new_reserve_in),
+                SwapDirection::A0ToPsp22 => (swap.new_reserve_in, swap.new_reserve_out),
+            };
+
+            Ok(SwapQuote {
+                amount_in,
+                amount_out_before_fees: swap.amount_out_before_fees,
+                amount_out: swap.amount_out,
+                lp_fee: swap.lp_fee,
+                vault_fee_in: swap.vault_fee_in,
+                vault_fee_out: swap.vault_fee_out,
+                applied_fee,
+                panx_discount_bps,
+                price_impact,
+                new_a0_reserve,
+                new_psp22_reserve,
+            })
+        }
+
+        /// function to add caller to the LP incentive program
         fn update_incentive_program(&mut self, caller: AccountId) -> Result<(), TradingPairErrors> {
             const STAKING_PERCENTAGE: Balance = 2; // Percentage of fees to be distributed as staking rewards
 
-            let account_shares_balance: Balance = self.balances.get(&caller).unwrap_or(0);
+            let account_shares_balance: Balance = self.get_lp_token_of(caller);
 
             // amount of PSP22 to give to the caller without LP fee
             let caller_locked_psp22_balance = self
@@ -1661,6 +1604,11 @@ pub mod trading_pair_azero {
             self.last_redeemed
                 .insert(caller, &self.get_current_timestamp());
 
+            Self::env().emit_event(IncentiveProgramUpdated {
+                account: caller,
+                psp22_daily_amount: psp22_amount_to_give_each_day,
+            });
+
             Ok(())
         }
 
@@ -1669,22 +1617,32 @@ pub mod trading_pair_azero {
         pub fn get_psp22_redeemable_amount(&mut self) -> Result<Balance, TradingPairErrors> {
             // call address
             let caller = self.env().caller();
+
+            self.get_account_psp22_redeemable_amount(caller)
+        }
+
+        /// function to get account redeemable amount of pooled PSP22
+        #[ink(message)]
+        pub fn get_account_psp22_redeemable_amount(
+            &self,
+            account: AccountId,
+        ) -> Result<Balance, TradingPairErrors> {
             // current timestamp
             let current_tsp = self.get_current_timestamp();
 
-            let account_shares_balance: Balance = self.balances.get(&caller).unwrap_or(0);
+            let account_shares_balance: Balance = self.get_lp_token_of(account);
 
-            // amount of PSP22 to give to the caller without LP fee
-            let caller_locked_psp22_balance = self
+            // amount of PSP22 to give to the account without LP fee
+            let account_locked_psp22_balance = self
                 .get_psp22_withdraw_tokens_amount(account_shares_balance)
                 .unwrap_or(0);
 
-            // last time caller redeemed tokens
-            let last_redeemed: u64 = self.last_redeemed.get(caller).unwrap_or(0);
+            // last time account redeemed tokens
+            let last_redeemed: u64 = self.last_redeemed.get(account).unwrap_or(0);
 
-            // the amount of daily PSP22 tokens to give ot the caller
+            // the amount of daily PSP22 tokens to give ot the account
             let psp22_to_give_each_day: Balance =
-                self.psp22_to_give_in_a_day.get(caller).unwrap_or(0);
+                self.psp22_to_give_in_a_day.get(account).unwrap_or(0);
 
             // Declare a variable to hold the difference in days between current timestamp and last redeemed timestamp
             let days_difference: u64;
@@ -1700,17 +1658,17 @@ pub mod trading_pair_azero {
                 }
             };
 
-            // making sure that caller has more then 0 pooled PSP22 tokens
-            if caller_locked_psp22_balance <= 0 {
+            // making sure that account has more then 0 pooled PSP22 tokens
+            if account_locked_psp22_balance <= 0 {
                 return Err(TradingPairErrors::CallerInsufficientPSP22Balance)
             }
 
-            // making sure that caller has more than 0 daily PSP22 tokens to claim
+            // making sure that account has more than 0 daily PSP22 tokens to claim
             if psp22_to_give_each_day <= 0 {
                 return Err(TradingPairErrors::ZeroDailyPSP22)
             }
 
-            // The amount of PSP22 tokens and AZERO to give to the caller
+            // The amount of PSP22 tokens and AZERO to give to the account
             let psp22_redeemable_amount: Balance = psp22_to_give_each_day * days_difference as u128;
 
             Ok(psp22_redeemable_amount)
@@ -1721,22 +1679,205 @@ pub mod trading_pair_azero {
         pub fn redeem_redeemable_amount(&mut self) -> Result<(), TradingPairErrors> {
             // caller address
             let caller = self.env().caller();
-            // caller timestamp
+
+            self.redeem(caller, caller)
+        }
+
+        /// function for caller to redeem LP incentive tokens to a different recipient.
+        #[ink(message)]
+        pub fn redeem_to(&mut self, recipient: AccountId) -> Result<(), TradingPairErrors> {
+            // caller address
+            let caller = self.env().caller();
+
+            self.redeem(caller, recipient)
+        }
+
+        /// function for an approved reward operator to redeem the LP incentive tokens of `owner`.
+        /// The redeemed tokens are sent to the operator (caller).
+        #[ink(message)]
+        pub fn redeem_for(&mut self, owner: AccountId) -> Result<(), TradingPairErrors> {
+            // caller address
+            let caller = self.env().caller();
+
+            // making sure that the owner approved the caller to redeem on his behalf
+            if caller != owner && !self.is_reward_operator(owner, caller) {
+                return Err(TradingPairErrors::CallerNotRewardOperator)
+            }
+
+            self.redeem(owner, caller)
+        }
+
+        /// function for caller to approve (or revoke) an operator to redeem his LP incentive tokens.
+        #[ink(message)]
+        pub fn set_reward_operator(
+            &mut self,
+            operator: AccountId,
+            approved: bool,
+        ) -> Result<(), TradingPairErrors> {
+            // caller address
+            let caller = self.env().caller();
+
+            self.reward_operators.insert((caller, operator), &approved);
+
+            Ok(())
+        }
+
+        /// function to check if `operator` is approved to redeem the LP incentive tokens of `owner`.
+        #[ink(message)]
+        pub fn is_reward_operator(&self, owner: AccountId, operator: AccountId) -> bool {
+            self.reward_operators.get((owner, operator)).unwrap_or(false)
+        }
+
+        /// function to redeem `owner` LP incentive tokens and transfer them to `recipient`.
+        fn redeem(
+            &mut self,
+            owner: AccountId,
+            recipient: AccountId,
+        ) -> Result<(), TradingPairErrors> {
+            let psp22_redeemable_amount = self.get_account_psp22_redeemable_amount(owner)?;
+
+            // nothing to redeem, so `owner` last redeem is not reset
+            if psp22_redeemable_amount == 0 {
+                return Err(TradingPairErrors::ZeroRedeemableAmount)
+            }
+
+            self.transfer_rewards(owner, recipient, psp22_redeemable_amount)
+        }
+
+        /// function to transfer `psp22_redeemable_amount` of `owner` LP incentive tokens to `recipient`
+        /// and reset `owner` last redeem.
+        fn transfer_rewards(
+            &mut self,
+            owner: AccountId,
+            recipient: AccountId,
+            psp22_redeemable_amount: Balance,
+        ) -> Result<(), TradingPairErrors> {
+            // current timestamp
             let current_tsp = self.get_current_timestamp();
 
-            let psp22_redeemable_amount = self.get_psp22_redeemable_amount().unwrap_or(0);
+            // cross contract call to PSP22 contract to transfer PSP22 to recipient
+            if PSP22Ref::transfer(&self.psp22_token, recipient, psp22_redeemable_amount, vec![])
+                .is_err()
+            {
+                return Err(TradingPairErrors::PSP22TransferFailed)
+            }
+
+            let current_account_overall_psp22_staking_rewards = self
+                .account_overall_staking_rewards
+                .get(&owner)
+                .unwrap_or(0);
+
+            self.account_overall_staking_rewards.insert(
+                &owner,
+                &(current_account_overall_psp22_staking_rewards + psp22_redeemable_amount),
+            );
+
+            // Making sure to set owner last redeem to current timestamp
+            self.last_redeemed.insert(owner, &current_tsp);
+
+            Self::env().emit_event(RewardsRedeemed {
+                owner,
+                recipient,
+                psp22_redeemed_amount: psp22_redeemable_amount,
+            });
+
+            Ok(())
+        }
+
+        /// function for caller to compound his LP incentive tokens back into the pool.
+        /// A portion of the redeemable PSP22 tokens is swapped into AZERO and both are
+        /// re-provided as liquidity, minting additional LP shares to the caller.
+        /// The internal swap is charged exactly like `swap_psp22` (LP fee with the caller
+        /// PANX discount, traders fee sent to the vault).
+        #[ink(message)]
+        pub fn compound(&mut self) -> Result<(), TradingPairErrors> {
+            // caller address
+            let caller = self.env().caller();
+
+            // the amount of PSP22 tokens the caller can redeem
+            let psp22_redeemable_amount = self.get_account_psp22_redeemable_amount(caller)?;
+
+            if psp22_redeemable_amount == 0 {
+                return Err(TradingPairErrors::ZeroRedeemableAmount)
+            }
+
+            let psp22_reserve_after_redeem: Balance;
+
+            // the PSP22 reserve as if the redeemable tokens left the pool
+            match self
+                .get_psp22_balance()
+                .checked_sub(psp22_redeemable_amount)
+            {
+                Some(result) => {
+                    psp22_reserve_after_redeem = result;
+                }
+                None => return Err(TradingPairErrors::ContractOutOfPSP22),
+            };
+
+            // the LP fee of the internal swap after the caller PANX discount
+            let applied_fee =
+                pricing::discounted_fee(self.fee, self.get_panx_discount_bps(caller));
+
+            // the internal swap and the LP shares given for providing its output with the rest
+            let compound = match pricing::get_compound_amounts(
+                psp22_redeemable_amount,
+                psp22_reserve_after_redeem,
+                self.get_a0_balance(),
+                self.get_total_supply(),
+                self.fee,
+                applied_fee,
+            ) {
+                Some(result) => result,
+                None => return Err(TradingPairErrors::Overflow),
+            };
+
+            let psp22_amount_to_swap = compound.psp22_amount_to_swap;
+            let swap = compound.swap;
+            let shares = compound.shares;
+
+            if shares <= 0 {
+                return Err(TradingPairErrors::ZeroSharesGiven)
+            }
+
+            match self.azero_lp_fee_vault.checked_add(swap.lp_fee) {
+                Some(result) => {
+                    self.azero_lp_fee_vault = result;
+                }
+                None => return Err(TradingPairErrors::Overflow),
+            };
 
-            //if psp22_redeemable_amount == 0 {
-            //    return Err(TradingPairErrors::ZeroRedeemableAmount)
-            //}
+            match self
+                .contract_overall_generated_azero_fee
+                .checked_add(swap.lp_fee)
+            {
+                Some(result) => {
+                    self.contract_overall_generated_azero_fee = result;
+                }
+                None => return Err(TradingPairErrors::Overflow),
+            };
 
-            // cross contract call to PSP22 contract to transfer PSP22 to caller
-            if PSP22Ref::transfer(&self.psp22_token, caller, psp22_redeemable_amount, vec![])
+            // cross contract call to PSP22 contract to transfer the traders fee to the vault
+            if PSP22Ref::transfer(&self.psp22_token, self.vault, swap.vault_fee_in, vec![])
                 .is_err()
             {
                 return Err(TradingPairErrors::PSP22TransferFailed)
             }
 
+            // function to transfer the AZERO traders fee to the vault
+            if self.env().transfer(self.vault, swap.vault_fee_out).is_err() {
+                return Err(TradingPairErrors::A0TransferFailed)
+            }
+
+            // increase num of trans
+            self.transasction_number = self.transasction_number + 1;
+
+            self.record_volume(psp22_amount_to_swap, 0, 0, swap.lp_fee);
+
+            // Mint `shares` LP tokens to `caller`
+            if psp22::Internal::_mint_to(self, caller, shares).is_err() {
+                return Err(TradingPairErrors::Overflow)
+            }
+
             let current_account_overall_psp22_staking_rewards = self
                 .account_overall_staking_rewards
                 .get(&caller)
@@ -1747,8 +1888,20 @@ pub mod trading_pair_azero {
                 &(current_account_overall_psp22_staking_rewards + psp22_redeemable_amount),
             );
 
-            // Making sure to set his last redeem to current timestamp
-            self.last_redeemed.insert(caller, &current_tsp);
+            // Update the incentive program for `caller` according to the new LP shares (also resets the last redeem)
+            if self.update_incentive_program(caller).is_err() {
+                return Err(TradingPairErrors::UpdateIncentiveProgramError)
+            }
+
+            Self::env().emit_event(StakingRewardsCompounded {
+                caller,
+                psp22_compounded_amount: psp22_redeemable_amount,
+                psp22_swapped_amount: psp22_amount_to_swap,
+                shares_given: shares,
+                new_a0_reserve: self.get_a0_balance(),
+                new_psp22_reserve: self.get_psp22_balance(),
+                new_total_supply: self.get_total_supply(),
+            });
 
             Ok(())
         }
@@ -1761,16 +1914,21 @@ pub mod trading_pair_azero {
             const STAKING_PERCENTAGE: Balance = 2; // Percentage of fees to be distributed as staking rewards
 
             if new_shares == 0 {
-                if self.redeem_redeemable_amount().is_err() {
+                if self.redeem_on_lp_removal(caller).is_err() {
                     return Err(TradingPairErrors::RemoveLpIncentiveProgramError)
                 }
 
                 // insert the daily amount of PSP22 and AZERO tokens to give to the caller
                 self.psp22_to_give_in_a_day.insert(caller, &0);
+
+                Self::env().emit_event(IncentiveProgramUpdated {
+                    account: caller,
+                    psp22_daily_amount: 0,
+                });
             }
 
             if new_shares > 0 {
-                if self.redeem_redeemable_amount().is_err() {
+                if self.redeem_on_lp_removal(caller).is_err() {
                     return Err(TradingPairErrors::RemoveLpIncentiveProgramError)
                 }
 
@@ -1793,11 +1951,26 @@ pub mod trading_pair_azero {
                 // insert the daily amount of PSP22 and AZERO tokens to give to the caller
                 self.psp22_to_give_in_a_day
                     .insert(caller, &new_psp22_amount_to_give_each_day);
+
+                Self::env().emit_event(IncentiveProgramUpdated {
+                    account: caller,
+                    psp22_daily_amount: new_psp22_amount_to_give_each_day,
+                });
             }
 
             Ok(())
         }
 
+        /// function to redeem `caller` pending LP incentive tokens before his daily amount changes.
+        /// Unlike `redeem`, having nothing to redeem (e.g. after withdrawing all of the LP shares) is not an error.
+        fn redeem_on_lp_removal(&mut self, caller: AccountId) -> Result<(), TradingPairErrors> {
+            let psp22_redeemable_amount = self
+                .get_account_psp22_redeemable_amount(caller)
+                .unwrap_or(0);
+
+            self.transfer_rewards(caller, caller, psp22_redeemable_amount)
+        }
+
         /// function to get the amount of tokens to give to caller each day.
         #[ink(message)]
         pub fn get_amount_to_give_each_day_to_caller(&mut self, caller: AccountId) -> Balance {
@@ -1873,7 +2046,7 @@ pub mod trading_pair_azero {
         /// function to get total supply of LP shares
         #[ink(message)]
         pub fn get_total_supply(&self) -> Balance {
-            self.total_supply
+            psp22::PSP22::total_supply(self)
         }
 
         /// function to get trading contract AZERO balance
@@ -1888,88 +2061,534 @@ pub mod trading_pair_azero {
         /// function to get shares of specific account
         #[ink(message)]
         pub fn get_lp_token_of(&self, account: AccountId) -> Balance {
-            self.balances.get(&account).unwrap_or(0)
+            psp22::PSP22::balance_of(self, account)
+        }
+
+        /// function to get the number of LP token permits used by `owner` (`PSP22Permit::nonces`)
+        #[ink(message, selector = 0x4f88fa44)]
+        pub fn nonces(&self, owner: AccountId) -> u64 {
+            self.lp_permit_nonces.get(&owner).unwrap_or(0)
+        }
+
+        /// function to approve `spender` over `value` LP tokens of `owner` with an ECDSA signature of
+        /// `owner` instead of a transaction (`PSP22Permit::permit`, same signed message as `PSP22Data::permit`)
+        #[ink(message, selector = 0x84d6348f)]
+        pub fn permit(
+            &mut self,
+            owner: AccountId,
+            spender: AccountId,
+            value: Balance,
+            deadline: u64,
+            signature: [u8; 65],
+        ) -> Result<(), PSP22Error> {
+            if self.env().block_timestamp() > deadline {
+                return Err(PSP22Error::Custom(String::from("Permit expired")))
+            }
+
+            let nonce = self.nonces(owner);
+
+            // hash of the message signed by `owner`
+            let message = scale::Encode::encode(&(
+                PERMIT_DOMAIN,
+                Self::env().account_id(),
+                owner,
+                spender,
+                value,
+                nonce,
+                deadline,
+            ));
+            let mut message_hash = [0u8; 32];
+            ink::env::hash_bytes::<Blake2x256>(&message, &mut message_hash);
+
+            let mut public_key = [0u8; 33];
+
+            if self
+                .env()
+                .ecdsa_recover(&signature, &message_hash, &mut public_key)
+                .is_err()
+            {
+                return Err(PSP22Error::Custom(String::from("Invalid permit signature")))
+            }
+
+            // accounts of ECDSA keys are the blake2 hash of the compressed public key
+            let mut signer = [0u8; 32];
+            ink::env::hash_bytes::<Blake2x256>(&public_key, &mut signer);
+
+            if AccountId::from(signer) != owner {
+                return Err(PSP22Error::Custom(String::from("Invalid permit signature")))
+            }
+
+            self.lp_permit_nonces.insert(owner, &(nonce + 1));
+
+            // owner approving itself is a no-op
+            if owner == spender {
+                return Ok(())
+            }
+
+            psp22::Internal::_approve_from_to(self, owner, spender, value)
         }
 
         // function to get contract PSP22 reserve (self)
         #[ink(message)]
         pub fn get_psp22_balance(&self) -> Balance {
-            let psp22_balance: Balance =
+            let mut psp22_balance: Balance =
                 PSP22Ref::balance_of(&self.psp22_token, Self::env().account_id());
+
+            // PANX locked for the LP fee discount is not part of the PANX pair reserve
+            if self.psp22_token == self.panx_contract {
+                psp22_balance = psp22_balance
+                    .checked_sub(self.total_locked_panx)
+                    .unwrap_or(0);
+            }
+
             psp22_balance
         }
 
-        /// function to get current fee
+        /// function to get the pair admin
         #[ink(message)]
-        pub fn get_fee(&self) -> Balance {
-            let fee: Balance = self.fee;
-            fee
+        pub fn get_admin(&self) -> AccountId {
+            self.admin
         }
 
-        // function to get the total number of swaps
+        /// function for the admin to hand over the pair admin role
         #[ink(message)]
-        pub fn get_transactions_num(&self) -> i64 {
-            self.transasction_number
+        pub fn set_admin(&mut self, new_admin: AccountId) -> Result<(), TradingPairErrors> {
+            if self.env().caller() != self.admin {
+                return Err(TradingPairErrors::CallerNotAdmin)
+            }
+
+            self.admin = new_admin;
+
+            Ok(())
         }
 
-        /// function to calculate the percentage between values.
+        /// function to get the PANX discount tiers (locked PANX threshold, LP fee discount in bps)
         #[ink(message)]
-        pub fn check_difference(
-            &mut self,
-            value1: Balance,
-            value2: Balance,
-        ) -> Result<Balance, TradingPairErrors> {
-            let mut percentage_difference: Balance = 0;
+        pub fn get_panx_discount_tiers(&self) -> Vec<(Balance, u16)> {
+            self.panx_discount_tiers.clone()
+        }
 
-            if value1 > value2 {
-                percentage_difference = (value1 - value2) * (10u128.pow(12));
-                match (percentage_difference / value2).checked_mul(100u128) {
-                    Some(result) => {
-                        percentage_difference = result;
-                    }
-                    None => return Err(TradingPairErrors::Overflow),
-                };
+        /// function for the admin to replace the PANX discount tiers.
+        /// Thresholds must be strictly increasing and discounts must not exceed 10000 bps.
+        #[ink(message)]
+        pub fn set_panx_discount_tiers(
+            &mut self,
+            tiers: Vec<(Balance, u16)>,
+        ) -> Result<(), TradingPairErrors> {
+            if self.env().caller() != self.admin {
+                return Err(TradingPairErrors::CallerNotAdmin)
             }
 
-            if value2 > value1 {
-                percentage_difference = (value2 - value1) * (10u128.pow(12));
-                match (percentage_difference / value1).checked_mul(100u128) {
-                    Some(result) => {
-                        percentage_difference = result;
-                    }
-                    None => return Err(TradingPairErrors::Overflow),
-                };
+            for (index, (threshold, discount_bps)) in tiers.iter().enumerate() {
+                if *discount_bps as Balance > pricing::DISCOUNT_BPS_DENOMINATOR {
+                    return Err(TradingPairErrors::InvalidDiscountTiers)
+                }
+
+                if index > 0 && tiers[index - 1].0 >= *threshold {
+                    return Err(TradingPairErrors::InvalidDiscountTiers)
+                }
             }
 
-            Ok(percentage_difference)
+            self.panx_discount_tiers = tiers;
+
+            Ok(())
         }
 
+        /// function to get the LP fee discount (in bps) of an account by its PANX locked
+        /// in this pair. PANX stays in the pair it was locked in, so a lock only discounts
+        /// the fees of that pair, and an expired lock grants no discount.
         #[ink(message)]
-        pub fn get_psp22_amount_for_lp(
-            &self,
-            a0_deposit_amount: Balance,
-            a0_contract_balance: Balance,
-        ) -> Balance {
-            let psp22_amount_to_deposit = ((self.get_psp22_balance() * (10u128.pow(12)))
-                / a0_contract_balance
-                * a0_deposit_amount)
-                / (10u128.pow(12));
+        pub fn get_panx_discount_bps(&self, account: AccountId) -> u16 {
+            let (locked_amount, unlock_timestamp) =
+                self.locked_panx.get(&account).unwrap_or((0, 0));
+
+            pricing::panx_discount_bps(
+                &self.panx_discount_tiers,
+                locked_amount,
+                unlock_timestamp,
+                self.get_current_timestamp(),
+            )
+        }
 
-            psp22_amount_to_deposit
+        /// function to get the LP fee of an account after its PANX discount
+        #[ink(message)]
+        pub fn get_discounted_fee(&self, account: AccountId) -> Balance {
+            pricing::discounted_fee(self.fee, self.get_panx_discount_bps(account))
         }
 
+        /// function to lock PANX in the pair until `unlock_timestamp` (in seconds).
+        /// Locked PANX (rather than the wallet balance) grants the LP fee discount of
+        /// this pair only, until the lock expires.
+        /// Locking more PANX or extending the lock is allowed, shortening it is not,
+        /// and the lock must last at least `MIN_PANX_LOCK_DURATION` from now.
         #[ink(message)]
-        pub fn get_a0_amount_for_lp(
+        pub fn lock_panx(
+            &mut self,
+            amount: Balance,
+            unlock_timestamp: u64,
+        ) -> Result<(), TradingPairErrors> {
+            let caller = self.env().caller();
+
+            let (locked_amount, current_unlock_timestamp) =
+                self.locked_panx.get(&caller).unwrap_or((0, 0));
+
+            // the lock must last the minimal duration and can never be shortened
+            if unlock_timestamp < self.get_current_timestamp() + MIN_PANX_LOCK_DURATION
+                || unlock_timestamp < current_unlock_timestamp
+            {
+                return Err(TradingPairErrors::InvalidUnlockTimestamp)
+            }
+
+            let new_locked_amount: Balance;
+
+            match locked_amount.checked_add(amount) {
+                Some(result) => {
+                    new_locked_amount = result;
+                }
+                None => return Err(TradingPairErrors::Overflow),
+            };
+
+            if new_locked_amount == 0 {
+                return Err(TradingPairErrors::ZeroLockedPanx)
+            }
+
+            let new_total_locked_panx: Balance;
+
+            match self.total_locked_panx.checked_add(amount) {
+                Some(result) => {
+                    new_total_locked_panx = result;
+                }
+                None => return Err(TradingPairErrors::Overflow),
+            };
+
+            if amount > 0 {
+                let caller_current_balance: Balance =
+                    PSP22Ref::balance_of(&self.panx_contract, caller);
+
+                let contract_current_balance: Balance =
+                    PSP22Ref::balance_of(&self.panx_contract, Self::env().account_id());
+
+                // cross contract call to PANX contract to transfer PANX to the pair,
+                // a PSP22 error (e.g. no allowance) is a failed transfer as well
+                if !matches!(
+                    PSP22Ref::transfer_from_builder(
+                        &self.panx_contract,
+                        caller,
+                        Self::env().account_id(),
+                        amount,
+                        vec![],
+                    )
+                    .call_flags(CallFlags::default().set_allow_reentry(true))
+                    .try_invoke(),
+                    Ok(Ok(Ok(())))
+                ) {
+                    return Err(TradingPairErrors::PSP22TransferFromFailed)
+                }
+
+                let caller_balance_after_transfer: Balance =
+                    PSP22Ref::balance_of(&self.panx_contract, caller);
+
+                if caller_current_balance == caller_balance_after_transfer {
+                    return Err(TradingPairErrors::CallerInsufficientPSP22Balance)
+                }
+
+                let contract_balance_after_transfer: Balance =
+                    PSP22Ref::balance_of(&self.panx_contract, Self::env().account_id());
+
+                // only PANX that actually reached the pair can be locked
+                if contract_balance_after_transfer != contract_current_balance + amount {
+                    return Err(TradingPairErrors::PSP22TransferFromFailed)
+                }
+            }
+
+            self.locked_panx
+                .insert(caller, &(new_locked_amount, unlock_timestamp));
+
+            self.total_locked_panx = new_total_locked_panx;
+
+            Ok(())
+        }
+
+        /// function to withdraw all of the caller locked PANX after the unlock timestamp
+        #[ink(message)]
+        pub fn unlock_panx(&mut self) -> Result<(), TradingPairErrors> {
+            let caller = self.env().caller();
+
+            let (locked_amount, unlock_timestamp) =
+                self.locked_panx.get(&caller).unwrap_or((0, 0));
+
+            if locked_amount == 0 {
+                return Err(TradingPairErrors::ZeroLockedPanx)
+            }
+
+            if self.get_current_timestamp() < unlock_timestamp {
+                return Err(TradingPairErrors::PanxStillLocked)
+            }
+
+            self.locked_panx.remove(caller);
+
+            self.total_locked_panx -= locked_amount;
+
+            // cross contract call to PANX contract to transfer the locked PANX back to caller
+            if PSP22Ref::transfer(&self.panx_contract, caller, locked_amount, vec![]).is_err() {
+                return Err(TradingPairErrors::PSP22TransferFailed)
+            }
+
+            Ok(())
+        }
+
+        /// function to get the locked PANX amount and unlock timestamp of an account
+        #[ink(message)]
+        pub fn get_locked_panx(&self, account: AccountId) -> (Balance, u64) {
+            self.locked_panx.get(&account).unwrap_or((0, 0))
+        }
+
+        /// function to lock `amount` of the caller LP shares until `unlock_timestamp` (in seconds).
+        /// Locked shares can not be transferred or withdrawn. Expired locks of the caller are pruned.
+        #[ink(message)]
+        pub fn lock_lp(
+            &mut self,
+            amount: Balance,
+            unlock_timestamp: u64,
+        ) -> Result<(), TradingPairErrors> {
+            let caller = self.env().caller();
+
+            if amount == 0 {
+                return Err(TradingPairErrors::ZeroLockedLp)
+            }
+
+            let current_timestamp = self.get_current_timestamp();
+
+            // the lock must end in the future
+            if unlock_timestamp <= current_timestamp {
+                return Err(TradingPairErrors::InvalidUnlockTimestamp)
+            }
+
+            // only shares that are not locked yet can be locked
+            if self.get_unlocked_lp_of(caller) < amount {
+                return Err(TradingPairErrors::CallerInsufficientLPBalance)
+            }
+
+            let mut locks = self.lp_locks.get(&caller).unwrap_or_default();
+
+            locks.retain(|(_, lock_timestamp)| *lock_timestamp > current_timestamp);
+
+            if locks.len() >= MAX_LP_LOCKS {
+                return Err(TradingPairErrors::TooManyLpLocks)
+            }
+
+            locks.push((amount, unlock_timestamp));
+
+            self.lp_locks.insert(caller, &locks);
+
+            Ok(())
+        }
+
+        /// function to extend the caller LP lock at `lock_index` (as listed by `get_locks`)
+        /// until `new_unlock_timestamp`. A lock can never be shortened.
+        #[ink(message)]
+        pub fn extend_lp_lock(
+            &mut self,
+            lock_index: u32,
+            new_unlock_timestamp: u64,
+        ) -> Result<(), TradingPairErrors> {
+            let caller = self.env().caller();
+
+            let mut locks = self.lp_locks.get(&caller).unwrap_or_default();
+
+            let (amount, unlock_timestamp) = match locks.get(lock_index as usize) {
+                Some(lock) => *lock,
+                None => return Err(TradingPairErrors::InvalidLpLockIndex),
+            };
+
+            // the lock must end in the future and can never be shortened
+            if new_unlock_timestamp <= self.get_current_timestamp()
+                || new_unlock_timestamp < unlock_timestamp
+            {
+                return Err(TradingPairErrors::InvalidUnlockTimestamp)
+            }
+
+            // an expired lock can only be renewed while its shares are still unlocked
+            if unlock_timestamp <= self.get_current_timestamp()
+                && self.get_unlocked_lp_of(caller) < amount
+            {
+                return Err(TradingPairErrors::CallerInsufficientLPBalance)
+            }
+
+            locks[lock_index as usize] = (amount, new_unlock_timestamp);
+
+            self.lp_locks.insert(caller, &locks);
+
+            Ok(())
+        }
+
+        /// function to get the LP locks of an account as (amount, unlock timestamp in seconds),
+        /// including expired locks that were not pruned yet
+        #[ink(message)]
+        pub fn get_locks(&self, account: AccountId) -> Vec<(Balance, u64)> {
+            self.lp_locks.get(&account).unwrap_or_default()
+        }
+
+        /// function to get the amount of LP shares an account currently has locked
+        #[ink(message)]
+        pub fn get_locked_lp_of(&self, account: AccountId) -> Balance {
+            let current_timestamp = self.get_current_timestamp();
+
+            self.lp_locks
+                .get(&account)
+                .unwrap_or_default()
+                .iter()
+                .filter(|(_, unlock_timestamp)| *unlock_timestamp > current_timestamp)
+                .fold(0, |locked, (amount, _)| locked.saturating_add(*amount))
+        }
+
+        /// function to get the amount of LP shares of an account that are free to move
+        fn get_unlocked_lp_of(&self, account: AccountId) -> Balance {
+            self.get_lp_token_of(account)
+                .saturating_sub(self.get_locked_lp_of(account))
+        }
+
+        /// function to get current fee
+        #[ink(message)]
+        pub fn get_fee(&self) -> Balance {
+            let fee: Balance = self.fee;
+            fee
+        }
+
+        // function to get the total number of swaps
+        #[ink(message)]
+        pub fn get_transactions_num(&self) -> i64 {
+            self.transasction_number
+        }
+
+        /// function to get the cumulative swapped (PSP22, AZERO) amounts since the pair creation
+        #[ink(message)]
+        pub fn get_cumulative_volume(&self) -> (Balance, Balance) {
+            self.cumulative_volume
+        }
+
+        /// function to get the swapped (PSP22, AZERO) amounts since the day of `since` (timestamp in seconds).
+        /// Only the last `VOLUME_BUCKETS` days are kept, older days are ignored.
+        #[ink(message)]
+        pub fn get_volume(&self, since: u64) -> (Balance, Balance) {
+            let (psp22_volume, a0_volume, _, _) = self.sum_daily_volume(since);
+
+            (psp22_volume, a0_volume)
+        }
+
+        /// function to get the generated (PSP22, AZERO) LP fees since the day of `since` (timestamp in seconds).
+        /// Only the last `VOLUME_BUCKETS` days are kept, older days are ignored.
+        #[ink(message)]
+        pub fn get_fees(&self, since: u64) -> (Balance, Balance) {
+            let (_, _, psp22_lp_fees, a0_lp_fees) = self.sum_daily_volume(since);
+
+            (psp22_lp_fees, a0_lp_fees)
+        }
+
+        /// function to add a swap to the cumulative volume and to the bucket of the current day.
+        /// Analytics counters saturate rather than failing the swap.
+        fn record_volume(
+            &mut self,
+            psp22_amount_in: Balance,
+            a0_amount_in: Balance,
+            psp22_lp_fee: Balance,
+            a0_lp_fee: Balance,
+        ) {
+            let (psp22_volume, a0_volume) = self.cumulative_volume;
+
+            self.cumulative_volume = (
+                psp22_volume.saturating_add(psp22_amount_in),
+                a0_volume.saturating_add(a0_amount_in),
+            );
+
+            let day = self.get_current_timestamp() / 86400;
+
+            // a bucket left by an older day is reset before being reused
+            let (bucket_day, psp22_volume, a0_volume, psp22_lp_fees, a0_lp_fees) = match self
+                .daily_volume
+                .get(day % VOLUME_BUCKETS)
+            {
+                Some(bucket) if bucket.0 == day => bucket,
+                _ => (day, 0, 0, 0, 0),
+            };
+
+            self.daily_volume.insert(
+                day % VOLUME_BUCKETS,
+                &(
+                    bucket_day,
+                    psp22_volume.saturating_add(psp22_amount_in),
+                    a0_volume.saturating_add(a0_amount_in),
+                    psp22_lp_fees.saturating_add(psp22_lp_fee),
+                    a0_lp_fees.saturating_add(a0_lp_fee),
+                ),
+            );
+        }
+
+        /// function to sum the (PSP22 volume, AZERO volume, PSP22 LP fees, AZERO LP fees)
+        /// of the kept days starting from the day of `since`.
+        fn sum_daily_volume(&self, since: u64) -> (Balance, Balance, Balance, Balance) {
+            let current_day = self.get_current_timestamp() / 86400;
+            let first_kept_day = (current_day + 1).saturating_sub(VOLUME_BUCKETS);
+            let since_day = (since / 86400).max(first_kept_day);
+
+            let mut totals: (Balance, Balance, Balance, Balance) = (0, 0, 0, 0);
+
+            for day in since_day..=current_day {
+                if let Some((bucket_day, psp22_volume, a0_volume, psp22_lp_fees, a0_lp_fees)) =
+                    self.daily_volume.get(day % VOLUME_BUCKETS)
+                {
+                    // skip buckets still holding an older day
+                    if bucket_day == day {
+                        totals.0 = totals.0.saturating_add(psp22_volume);
+                        totals.1 = totals.1.saturating_add(a0_volume);
+                        totals.2 = totals.2.saturating_add(psp22_lp_fees);
+                        totals.3 = totals.3.saturating_add(a0_lp_fees);
+                    }
+                }
+            }
+
+            totals
+        }
+
+        /// function to calculate the percentage between values.
+        #[ink(message)]
+        pub fn check_difference(
             &self,
-            psp22_deposit_amount: Balance,
+            value1: Balance,
+            value2: Balance,
+        ) -> Result<Balance, TradingPairErrors> {
+            match pricing::percentage_difference(value1, value2) {
+                Some(result) => Ok(result),
+                None => Err(TradingPairErrors::Overflow),
+            }
+        }
+
+        #[ink(message)]
+        pub fn get_psp22_amount_for_lp(
+            &self,
+            a0_deposit_amount: Balance,
             a0_contract_balance: Balance,
         ) -> Balance {
-            let a0_amount_to_deposit = ((a0_contract_balance * (10u128.pow(12)))
-                / self.get_psp22_balance()
-                * psp22_deposit_amount)
-                / (10u128.pow(12));
+            pricing::get_deposit_amount_for_ratio(
+                a0_deposit_amount,
+                a0_contract_balance,
+                self.get_psp22_balance(),
+            )
+            .unwrap_or(0)
+        }
 
-            a0_amount_to_deposit
+        #[ink(message)]
+        pub fn get_a0_amount_for_lp(
+            &self,
+            psp22_deposit_amount: Balance,
+            a0_contract_balance: Balance,
+        ) -> Balance {
+            pricing::get_deposit_amount_for_ratio(
+                psp22_deposit_amount,
+                self.get_psp22_balance(),
+                a0_contract_balance,
+            )
+            .unwrap_or(0)
         }
 
         /// function to get current timpstamp in seconds
@@ -1984,15 +2603,6 @@ pub mod trading_pair_azero {
         pub fn get_lp_lock_timestamp(&self) -> u64 {
             self.lp_lock_timestamp
         }
-
-        /// function to get LP lock timestamp
-        fn _min(&self, value1: Balance, value2: Balance) -> Balance {
-            if value1 < value2 {
-                return value1
-            } else {
-                return value2
-            }
-        }
     }
 
     /// ink! end-to-end (E2E) tests
@@ -2039,11 +2649,50 @@ pub mod trading_pair_azero {
             charlie_account_id
         }
 
+        /// Helper to decode the events emitted by the pair contract `tpa_acc_id` during a call
+        fn get_pair_events<V: scale::Decode>(
+            call_res: &ink_e2e::CallResult<
+                ink_e2e::PolkadotConfig,
+                ink::env::DefaultEnvironment,
+                V,
+            >,
+            tpa_acc_id: AccountId,
+        ) -> Vec<Event> {
+            call_res
+                .events
+                .iter()
+                .filter_map(|event| {
+                    let event = event.expect("event decoding failed");
+
+                    if event.pallet_name() != "Contracts"
+                        || event.variant_name() != "ContractEmitted"
+                    {
+                        return None
+                    }
+
+                    let (contract, data) =
+                        <(AccountId, Vec<u8>) as scale::Decode>::decode(&mut event.field_bytes())
+                            .expect("ContractEmitted decoding failed");
+
+                    if contract != tpa_acc_id {
+                        return None
+                    }
+
+                    Some(
+                        <Event as scale::Decode>::decode(&mut &data[..])
+                            .expect("pair event decoding failed"),
+                    )
+                })
+                .collect()
+        }
+
         /// Tests included in "provide_to_pool_works":
         /// 1. provide_to_pool
         /// 2. get_a0_balance
         /// 3. get_psp22_balance
-        /// 4. get_amount_to_give_each_day_to_caller
+        /// 4. get_lp_token_of
+        /// 5. get_total_supply
+        /// 6. get_amount_to_give_each_day_to_caller
         #[ink_e2e::test(additional_contracts = "../my_psp22/Cargo.toml")]
         async fn provide_to_pool_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
             // Create a new instance of MyPsp22Ref contract
@@ -2067,6 +2716,8 @@ pub mod trading_pair_azero {
                 1000000000000,
                 psp22_acc_id,
                 get_charlie_account_id(),
+                0,
+                get_alice_account_id(),
             );
 
             // Instantiate TradingPairAzeroRef contract and get the account ID
@@ -2097,8 +2748,9 @@ pub mod trading_pair_azero {
                 |trading_pair_azero| {
                     trading_pair_azero.provide_to_pool(
                         100000000000000,
-                        1000000000000000,
-                        500000000000,
+                        10000000000000,
+                        31622776600683,
+                        0,
                     )
                 },
             );
@@ -2137,6 +2789,54 @@ pub mod trading_pair_azero {
             // Assert the returned balance of psp22
             assert_eq!(get_psp22_res.return_value(), 100000000000000, "get_res");
 
+            // Build a `get_lp_token_of` message for TradingPairAzeroRef contract to get the LP shares of the caller
+            let get_lp_share_balance = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| {
+                    trading_pair_azero.get_lp_token_of(get_alice_account_id())
+                });
+
+            // Call `get_lp_token_of` message
+            let get_lp_share_res = client
+                .call(&ink_e2e::alice(), get_lp_share_balance, 0, None)
+                .await
+                .expect("get_lp_token_of failed");
+
+            // Assert the caller got sqrt(a0 * psp22) shares minus the minimum liquidity
+            assert_eq!(get_lp_share_res.return_value(), 31622776600683);
+
+            // Build a `get_lp_token_of` message for TradingPairAzeroRef contract to get the LP shares of the dead account
+            let get_dead_lp_share_balance = build_message::<TradingPairAzeroRef>(
+                tpa_acc_id.clone(),
+            )
+            .call(|trading_pair_azero| {
+                trading_pair_azero.get_lp_token_of(AccountId::from(DEAD_ACCOUNT))
+            });
+
+            // Call `get_lp_token_of` message
+            let get_dead_lp_share_res = client
+                .call(&ink_e2e::alice(), get_dead_lp_share_balance, 0, None)
+                .await
+                .expect("get_lp_token_of failed");
+
+            // Assert the minimum liquidity is locked in the dead account
+            assert_eq!(
+                get_dead_lp_share_res.return_value(),
+                pricing::MINIMUM_LIQUIDITY
+            );
+
+            // Build a `get_total_supply` message for TradingPairAzeroRef contract to get the LP shares total supply
+            let get_total_supply = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| trading_pair_azero.get_total_supply());
+
+            // Call `get_total_supply` message
+            let get_total_supply_res = client
+                .call(&ink_e2e::alice(), get_total_supply, 0, None)
+                .await
+                .expect("get_total_supply failed");
+
+            // Assert the total supply includes the minimum liquidity
+            assert_eq!(get_total_supply_res.return_value(), 31622776601683);
+
             // Build a `get_amount_to_give_each_day_to_caller` message for TradingPairAzeroRef contract
             // to get the amount to give each day to the caller
             let get_amount_to_give_each_day_to_caller = build_message::<TradingPairAzeroRef>(
@@ -2198,6 +2898,8 @@ pub mod trading_pair_azero {
                 1000000000000,
                 psp22_acc_id,
                 get_charlie_account_id(),
+                0,
+                get_alice_account_id(),
             );
 
             // Instantiate TradingPairAzeroRef contract and get the account ID
@@ -2228,8 +2930,9 @@ pub mod trading_pair_azero {
                 |trading_pair_azero| {
                     trading_pair_azero.provide_to_pool(
                         100000000000000,
-                        1000000000000000,
-                        500000000000,
+                        10000000000000,
+                        31622776600683,
+                        0,
                     )
                 },
             );
@@ -2307,9 +3010,9 @@ pub mod trading_pair_azero {
                 .expect("get_lp_token_of failed");
 
             // Assert the returned LP token balance
-            assert_eq!(get_lp_share_res.return_value(), 1000000000000000);
+            assert_eq!(get_lp_share_res.return_value(), 31622776600683);
 
-            let amount: u128 = 500000000000000;
+            let amount: u128 = 15811388300341;
 
             // Build a `withdraw_specific_amount` message for TradingPairAzeroRef contract to withdraw a specific amount from the pool
             let withdraw_from_pool = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
@@ -2360,7 +3063,7 @@ pub mod trading_pair_azero {
                 .expect("get failed");
 
             // Assert the updated LP token balance
-            assert_eq!(get_lp_share_res.return_value(), 500000000000000);
+            assert_eq!(get_lp_share_res.return_value(), 15811388300342);
 
             // Build a `get_a0_balance` message for TradingPairAzeroRef contract to get the updated balance of a0
             let get_a0_balance = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
@@ -2373,7 +3076,7 @@ pub mod trading_pair_azero {
                 .expect("get failed");
 
             // Assert the updated balance of a0
-            assert_eq!(get_a0_res.return_value(), 5000500000000);
+            assert_eq!(get_a0_res.return_value(), 5000500000159);
 
             // Build a `get_psp22_balance` message for TradingPairAzeroRef contract to get the updated balance of psp22
             let get_psp22_balance = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
@@ -2386,7 +3089,7 @@ pub mod trading_pair_azero {
                 .expect("get failed");
 
             // Assert the updated balance of psp22
-            assert_eq!(get_psp22_res.return_value(), 50000000000000);
+            assert_eq!(get_psp22_res.return_value(), 50000000001583);
 
             // Build a `get_account_overall_staking_rewards` message for TradingPairAzeroRef contract
             // to get the overall staking rewards for the account
@@ -2448,6 +3151,8 @@ pub mod trading_pair_azero {
                 1000000000000,
                 psp22_acc_id,
                 get_charlie_account_id(),
+                0,
+                get_alice_account_id(),
             );
 
             // Instantiate new TradingPairAzero contract using the constructor
@@ -2479,8 +3184,9 @@ pub mod trading_pair_azero {
                 |trading_pair_azero| {
                     trading_pair_azero.provide_to_pool(
                         100000000000000,
-                        1000000000000000,
-                        500000000000,
+                        10000000000000,
+                        31622776600683,
+                        0,
                     )
                 },
             );
@@ -2525,7 +3231,7 @@ pub mod trading_pair_azero {
                 tpa_acc_id.clone(),
             )
             .call(|trading_pair_azero| {
-                trading_pair_azero.get_withdraw_tokens_amount(1000000000000000)
+                trading_pair_azero.get_withdraw_tokens_amount(31622776600683)
             });
 
             // Call and fetch the results
@@ -2537,14 +3243,14 @@ pub mod trading_pair_azero {
             // Fetch the A0 (Native coin) and PSP22 tokens balances from 'get_withdraw_tokens_amount_res'
             let Some((a0_coins, psp22_tokens)) = get_withdraw_tokens_amount_res.return_value().ok() else { panic!("test") };
 
-            // Validate that TPA really holds the PSP22 tokens that we sent
-            assert_eq!(psp22_tokens, 100000000000000);
+            // Validate that the shares give back the PSP22 tokens we sent but the part of the locked minimum liquidity
+            assert_eq!(psp22_tokens, 99999999996837);
 
-            // Validate that TPA really holds the native tokens that we sent
-            assert_eq!(a0_coins, 10001000000000);
+            // Validate that the shares give back the native tokens we sent but the part of the locked minimum liquidity
+            assert_eq!(a0_coins, 10000999999683);
 
-            // LP share amount to withdraw (500 x 10^12)
-            let amount: u128 = 500000000000000;
+            // LP share amount to withdraw (half of the LP provider shares)
+            let amount: u128 = 15811388300341;
 
             // Build the withdraw from pool function with the specified amount
             let withdraw_from_pool = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
@@ -2569,7 +3275,7 @@ pub mod trading_pair_azero {
                 .expect("get failed");
 
             // Validate that the amount of the remaining LP share tokens is correct
-            assert_eq!(get_lp_share_res.return_value(), 500000000000000);
+            assert_eq!(get_lp_share_res.return_value(), 15811388300342);
 
             // Build the get A0 balance message to see the remaining native coin balance after withdrawal
             let get_a0_balance = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
@@ -2582,7 +3288,7 @@ pub mod trading_pair_azero {
                 .expect("get failed");
 
             // Validate that the remaining native coin balance is correct
-            assert_eq!(get_a0_res.return_value(), 5000500000000);
+            assert_eq!(get_a0_res.return_value(), 5000500000159);
 
             // Build the get PSP22 balance message to see the remaining PSP22 balance after withdrawal
             let get_psp22_balance = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
@@ -2595,14 +3301,14 @@ pub mod trading_pair_azero {
                 .expect("get failed");
 
             // Validate that the remaining PSP22 balance is correct
-            assert_eq!(get_psp22_res.return_value(), 50000000000000);
+            assert_eq!(get_psp22_res.return_value(), 50000000001583);
 
             // Get the withdraw tokens amount by given shares after withdrawal
             let get_withdraw_tokens_amount = build_message::<TradingPairAzeroRef>(
                 tpa_acc_id.clone(),
             )
             .call(|trading_pair_azero| {
-                trading_pair_azero.get_withdraw_tokens_amount(500000000000000)
+                trading_pair_azero.get_withdraw_tokens_amount(15811388300342)
             });
 
             // Build get_withdraw_tokens message to fetch the withdrawable tokens by given shares
@@ -2614,9 +3320,9 @@ pub mod trading_pair_azero {
             // Fetch the A0 (Native coin) and PSP22 tokens balances from 'get_withdraw_tokens_amount_res'
             let Some((a0_coins, psp22_tokens)) = get_withdraw_tokens_amount_res.return_value().ok() else { panic!("get withdraw failed") };
 
-            assert_eq!(psp22_tokens, 50000000000000);
+            assert_eq!(psp22_tokens, 49999999998420);
 
-            assert_eq!(a0_coins, 5000500000000);
+            assert_eq!(a0_coins, 5000499999842);
 
             //
 
@@ -2654,6 +3360,8 @@ pub mod trading_pair_azero {
                 1000000000000,
                 psp22_acc_id,
                 get_charlie_account_id(),
+                0,
+                get_alice_account_id(),
             );
 
             // Instantiate new tpa contract using the constructor
@@ -2684,8 +3392,9 @@ pub mod trading_pair_azero {
                 |trading_pair_azero| {
                     trading_pair_azero.provide_to_pool(
                         100000000000000,
-                        1000000000000000,
-                        500000000000,
+                        10000000000000,
+                        31622776600683,
+                        0,
                     )
                 },
             );
@@ -2717,7 +3426,7 @@ pub mod trading_pair_azero {
                 tpa_acc_id.clone(),
             )
             .call(|trading_pair_azero| {
-                trading_pair_azero.get_psp22_withdraw_tokens_amount(1000000000000000)
+                trading_pair_azero.get_psp22_withdraw_tokens_amount(31622776600683)
             });
 
             // Call and fetch the results
@@ -2729,11 +3438,11 @@ pub mod trading_pair_azero {
             // Fetch the get_psp22_withdraw_tokens_amount_res result
             let Some(psp22_tokens) = get_psp22_withdraw_tokens_amount_res.return_value().ok() else { panic!("test") };
 
-            // Validate that tpa really holds the PSP22 tokens that we sent
-            assert_eq!(psp22_tokens, 100000000000000);
+            // Validate that the shares give back the PSP22 tokens we sent but the part of the locked minimum liquidity
+            assert_eq!(psp22_tokens, 99999999996837);
 
-            // LP share amount to withdraw (500 x 10^12)
-            let amount: u128 = 500000000000000;
+            // LP share amount to withdraw (half of the LP provider shares)
+            let amount: u128 = 15811388300341;
 
             // Build the withdraw from pool function with the amount that we stated above
             let withdraw_from_pool = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
@@ -2758,7 +3467,7 @@ pub mod trading_pair_azero {
                 .expect("get_lp_share_res failed");
 
             // Validate that the amount of the remaining LP share tokens is correct
-            assert_eq!(get_lp_share_res.return_value(), 500000000000000);
+            assert_eq!(get_lp_share_res.return_value(), 15811388300342);
 
             // Build the get PSP22 balance message to see remaining PSP22 balance after withdrawal
             let get_psp22_balance = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
@@ -2771,14 +3480,14 @@ pub mod trading_pair_azero {
                 .expect("get_psp22_res failed");
 
             // Validate that the remaining PSP22 balance is correct
-            assert_eq!(get_psp22_res.return_value(), 50000000000000);
+            assert_eq!(get_psp22_res.return_value(), 50000000001583);
 
             // Build get_withdraw_tokens message to fetch the withdrawable tokens by given shares
             let get_psp22_withdraw_tokens_amount = build_message::<TradingPairAzeroRef>(
                 tpa_acc_id.clone(),
             )
             .call(|trading_pair_azero| {
-                trading_pair_azero.get_psp22_withdraw_tokens_amount(1000000000000000)
+                trading_pair_azero.get_psp22_withdraw_tokens_amount(31622776600683)
             });
 
             // Call and fetch the results
@@ -2789,8 +3498,8 @@ pub mod trading_pair_azero {
 
             let Some(psp22_tokens) = get_psp22_withdraw_tokens_amount_res.return_value().ok() else { panic!("test") };
 
-            // Validate that tpa really holds the PSP22 tokens that we sent
-            assert_eq!(psp22_tokens, 100000000000000);
+            // Validate that the withdrawal did not lower the PSP22 tokens given per share (rounding favors the pool)
+            assert_eq!(psp22_tokens, 99999999996838);
 
             Ok(())
         }
@@ -2826,6 +3535,8 @@ pub mod trading_pair_azero {
                 1000000000000,
                 psp22_acc_id,
                 get_charlie_account_id(),
+                0,
+                get_alice_account_id(),
             );
 
             // Instantiate new TPA contract using the constructor
@@ -2857,8 +3568,9 @@ pub mod trading_pair_azero {
                 |trading_pair_azero| {
                     trading_pair_azero.provide_to_pool(
                         100000000000000,
-                        1000000000000000,
-                        500000000000,
+                        10000000000000,
+                        31622776600683,
+                        0,
                     )
                 },
             );
@@ -2890,7 +3602,7 @@ pub mod trading_pair_azero {
                 tpa_acc_id.clone(),
             )
             .call(|trading_pair_azero| {
-                trading_pair_azero.get_a0_withdraw_tokens_amount(1000000000000000)
+                trading_pair_azero.get_a0_withdraw_tokens_amount(31622776600683)
             });
 
             // Call and fetch the result
@@ -2901,11 +3613,11 @@ pub mod trading_pair_azero {
 
             let Some(a0_tokens) = get_a0_withdraw_tokens_amount_res.return_value().ok() else { panic!("test") };
 
-            // Validate that TPA really holds the PSP22 tokens that we sent
-            assert_eq!(a0_tokens, 10001000000000);
+            // Validate that the shares give back the native tokens we sent but the part of the locked minimum liquidity
+            assert_eq!(a0_tokens, 10000999999683);
 
-            // LP share amount to withdraw (500 x 10^12)
-            let amount: u128 = 500000000000000;
+            // LP share amount to withdraw (half of the LP provider shares)
+            let amount: u128 = 15811388300341;
 
             // Build the withdraw from pool function with the specified amount
             let withdraw_from_pool = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
@@ -2930,7 +3642,7 @@ pub mod trading_pair_azero {
                 .expect("get_lp_share_res failed");
 
             // Validate that the amount of the remaining LP share tokens is correct
-            assert_eq!(get_lp_share_res.return_value(), 500000000000000);
+            assert_eq!(get_lp_share_res.return_value(), 15811388300342);
 
             // Build the get PSP22 balance message to see the remaining PSP22 balance after withdrawal
             let get_a0_balance = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
@@ -2943,14 +3655,14 @@ pub mod trading_pair_azero {
                 .expect("get_a0_res failed");
 
             // Validate that the remaining PSP22 balance is correct
-            assert_eq!(get_a0_res.return_value(), 5000500000000);
+            assert_eq!(get_a0_res.return_value(), 5000500000159);
 
             // Build get_withdraw_tokens message to fetch the withdrawable tokens by given shares
             let get_a0_withdraw_tokens_amount = build_message::<TradingPairAzeroRef>(
                 tpa_acc_id.clone(),
             )
             .call(|trading_pair_azero| {
-                trading_pair_azero.get_a0_withdraw_tokens_amount(1000000000000000)
+                trading_pair_azero.get_a0_withdraw_tokens_amount(31622776600683)
             });
 
             // Call and fetch the result
@@ -2961,8 +3673,8 @@ pub mod trading_pair_azero {
 
             let Some(a0_tokens) = get_a0_withdraw_tokens_amount_res.return_value().ok() else { panic!("test") };
 
-            // Validate that TPA really holds the PSP22 tokens that we sent
-            assert_eq!(a0_tokens, 10001000000000);
+            // Validate that the withdrawal did not lower the native tokens given per share (rounding favors the pool)
+            assert_eq!(a0_tokens, 10000999999685);
 
             Ok(())
         }
@@ -2998,6 +3710,8 @@ pub mod trading_pair_azero {
                 1000000000000,
                 psp22_acc_id,
                 get_charlie_account_id(),
+                0,
+                get_alice_account_id(),
             );
 
             // Instantiate new TPA contract using the constructor
@@ -3028,8 +3742,9 @@ pub mod trading_pair_azero {
                 |trading_pair_azero| {
                     trading_pair_azero.provide_to_pool(
                         100000000000000,
-                        1000000000000000,
-                        500000000000,
+                        10000000000000,
+                        31622776600683,
+                        0,
                     )
                 },
             );
@@ -3086,14 +3801,14 @@ pub mod trading_pair_azero {
             // Fetch locked PSP22 tokens and A0 (Native coin) balances
             let Some((psp22_tokens, a0_coins)) = get_account_locked_tokens_res.return_value().ok() else { panic!("test") };
 
-            // Validate that TPA really holds the PSP22 tokens that we sent
-            assert_eq!(a0_coins, 10001000000000);
+            // Validate that the locked native tokens are the ones we sent but the part of the locked minimum liquidity
+            assert_eq!(a0_coins, 10000999999683);
 
-            // Validate that TPA really holds the native tokens that we sent
-            assert_eq!(psp22_tokens, 100000000000000);
+            // Validate that the locked PSP22 tokens are the ones we sent but the part of the locked minimum liquidity
+            assert_eq!(psp22_tokens, 99999999996837);
 
-            // LP share amount to withdraw (500 x 10^12)
-            let amount: u128 = 500000000000000;
+            // LP share amount to withdraw (half of the LP provider shares)
+            let amount: u128 = 15811388300341;
 
             // Build the withdraw from pool function with the specified amount
             let withdraw_from_pool = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
@@ -3116,7 +3831,7 @@ pub mod trading_pair_azero {
                 .expect("get_a0_balance failed");
 
             // Validate that the remaining native coin balance is correct
-            assert_eq!(get_a0_res.return_value(), 5000500000000);
+            assert_eq!(get_a0_res.return_value(), 5000500000159);
 
             // Build the get PSP22 balance message to see the remaining PSP22 balance after withdrawal
             let get_psp22_balance = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
@@ -3129,7 +3844,7 @@ pub mod trading_pair_azero {
                 .expect("get_psp22_balance failed");
 
             // Validate that the remaining PSP22 balance is correct
-            assert_eq!(get_psp22_res.return_value(), 50000000000000);
+            assert_eq!(get_psp22_res.return_value(), 50000000001583);
 
             // Build get_account_locked_tokens message to fetch the locked tokens by given account ID
             let get_account_locked_tokens = build_message::<TradingPairAzeroRef>(
@@ -3148,10 +3863,10 @@ pub mod trading_pair_azero {
             let Some((psp22_tokens, a0_coins)) = get_account_locked_tokens_res.return_value().ok() else { panic!("test") };
 
             // Validate that TPA really holds the PSP22 tokens that remains
-            assert_eq!(psp22_tokens, 50000000000000);
+            assert_eq!(psp22_tokens, 49999999998420);
 
             // Validate that TPA really holds the A0 coins that remains
-            assert_eq!(a0_coins, 5000500000000);
+            assert_eq!(a0_coins, 5000499999842);
 
             Ok(())
         }
@@ -3180,6 +3895,8 @@ pub mod trading_pair_azero {
                 1000000000000,
                 psp22_acc_id,
                 get_charlie_account_id(),
+                0,
+                get_alice_account_id(),
             );
             let tpa_acc_id = client
                 .instantiate(
@@ -3206,8 +3923,9 @@ pub mod trading_pair_azero {
                 |trading_pair_azero| {
                     trading_pair_azero.provide_to_pool(
                         100000000000000,
-                        1000000000000000,
-                        500000000000,
+                        10000000000000,
+                        31622776600683,
+                        0,
                     )
                 },
             );
@@ -3264,6 +3982,8 @@ pub mod trading_pair_azero {
                 1000000000000,
                 psp22_acc_id,
                 get_charlie_account_id(),
+                0,
+                get_alice_account_id(),
             );
             let tpa_acc_id = client
                 .instantiate(
@@ -3290,8 +4010,9 @@ pub mod trading_pair_azero {
                 |trading_pair_azero| {
                     trading_pair_azero.provide_to_pool(
                         100000000000000,
-                        1000000000000000,
-                        500000000000,
+                        10000000000000,
+                        31622776600683,
+                        0,
                     )
                 },
             );
@@ -3350,6 +4071,8 @@ pub mod trading_pair_azero {
                 1000000000000,
                 psp22_acc_id,
                 get_charlie_account_id(),
+                0,
+                get_alice_account_id(),
             );
             let tpa_acc_id = client
                 .instantiate(
@@ -3376,8 +4099,9 @@ pub mod trading_pair_azero {
                 |trading_pair_azero| {
                     trading_pair_azero.provide_to_pool(
                         100000000000000,
-                        1000000000000000,
-                        500000000000,
+                        10000000000000,
+                        31622776600683,
+                        0,
                     )
                 },
             );
@@ -3444,6 +4168,8 @@ pub mod trading_pair_azero {
                 1000000000000,
                 psp22_acc_id,
                 get_charlie_account_id(),
+                0,
+                get_alice_account_id(),
             );
             let tpa_acc_id = client
                 .instantiate(
@@ -3459,11 +4185,13 @@ pub mod trading_pair_azero {
 
             let amount: u128 = 10000000000000;
 
-            // Build a message to get the expected amount of LP tokens for the given amount
+            // Build a message to get the expected amount of LP tokens for the given amounts
             let get_expected_lp_token_amount = build_message::<TradingPairAzeroRef>(
                 tpa_acc_id.clone(),
             )
-            .call(|trading_pair_azero| trading_pair_azero.get_expected_lp_token_amount(amount));
+            .call(|trading_pair_azero| {
+                trading_pair_azero.get_expected_lp_token_amount(amount, 100000000000000)
+            });
 
             // Call and fetch the results
             let get_expected_lp_token_amount_res = client
@@ -3474,8 +4202,8 @@ pub mod trading_pair_azero {
             // Retrieve the expected amount of LP tokens
             let Some(expected_lp_shares) = get_expected_lp_token_amount_res.return_value().ok() else { panic!("test") };
 
-            // Assert the expected amount of LP tokens
-            assert_eq!(expected_lp_shares, 1000000000000000);
+            // Assert the expected amount of LP tokens, sqrt(a0 * psp22) minus the locked minimum liquidity
+            assert_eq!(expected_lp_shares, 31622776600683);
 
             // Approve PSP22 contract to provide liquidity to TradingPairAzero contract
             let approve_psp22_to_provide_lp = build_message::<MyPsp22Ref>(psp22_acc_id.clone())
@@ -3490,8 +4218,9 @@ pub mod trading_pair_azero {
                 |trading_pair_azero| {
                     trading_pair_azero.provide_to_pool(
                         100000000000000,
-                        1000000000000000,
-                        500000000000,
+                        10000000000000,
+                        31622776600683,
+                        0,
                     )
                 },
             );
@@ -3514,7 +4243,7 @@ pub mod trading_pair_azero {
                 .expect("get_lp_share_balance failed");
 
             // Assert the LP token balance of Alice's account
-            assert_eq!(get_lp_share_res.return_value(), 1000000000000000);
+            assert_eq!(get_lp_share_res.return_value(), 31622776600683);
 
             Ok(())
         }
@@ -3545,6 +4274,8 @@ pub mod trading_pair_azero {
                 1000000000000,
                 psp22_acc_id,
                 get_charlie_account_id(),
+                0,
+                get_alice_account_id(),
             );
             let tpa_acc_id = client
                 .instantiate(
@@ -3571,8 +4302,9 @@ pub mod trading_pair_azero {
                 |trading_pair_azero| {
                     trading_pair_azero.provide_to_pool(
                         100000000000000,
-                        1000000000000000,
-                        500000000000,
+                        10000000000000,
+                        31622776600683,
+                        0,
                     )
                 },
             );
@@ -3629,6 +4361,8 @@ pub mod trading_pair_azero {
                 1000000000000,
                 psp22_acc_id,
                 get_charlie_account_id(),
+                0,
+                get_alice_account_id(),
             );
             let tpa_acc_id = client
                 .instantiate(
@@ -3655,8 +4389,9 @@ pub mod trading_pair_azero {
                 |trading_pair_azero| {
                     trading_pair_azero.provide_to_pool(
                         100000000000000,
-                        1000000000000000,
-                        500000000000,
+                        10000000000000,
+                        31622776600683,
+                        0,
                     )
                 },
             );
@@ -3715,6 +4450,8 @@ pub mod trading_pair_azero {
                 1000000000000,
                 psp22_acc_id,
                 get_charlie_account_id(),
+                0,
+                get_alice_account_id(),
             );
             let tpa_acc_id = client
                 .instantiate(
@@ -3741,8 +4478,9 @@ pub mod trading_pair_azero {
                 |trading_pair_azero| {
                     trading_pair_azero.provide_to_pool(
                         100000000000000,
-                        1000000000000000,
-                        500000000000,
+                        10000000000000,
+                        31622776600683,
+                        0,
                     )
                 },
             );
@@ -3775,20 +4513,14 @@ pub mod trading_pair_azero {
             Ok(())
         }
 
-        /// Tests included in 'swap_psp22_works'
-        /// 1. provide_to_pool
-        /// 2. get_est_price_psp22_to_a0
-        /// 3. swap_psp22
-        /// 4. get_a0_balance
-        /// 5. get_generated_lp_fees
-        /// 6. get_a0_difference_by_percentage
-        /// 7. get_account_overall_lp_fee_rewards
-        /// 8. withdraw_specific_amount
-        /// 9. get_psp22_balance
-        /// 10. get_a0_lp_fee_tokens
+        /// Tests included in 'lp_allowance_works'
+        /// 1. approve
+        /// 2. increase_allowance
+        /// 3. decrease_allowance
+        /// 4. allowance
         #[ink_e2e::test(additional_contracts = "../my_psp22/Cargo.toml")]
-        async fn swap_psp22_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
-            // Instantiate MyPsp22Ref contract
+        async fn lp_allowance_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            // Create a PSP22 contract instance
             let psp22_constructor = MyPsp22Ref::new(
                 10000000000000000,
                 Some(String::from("TOKEN").into()),
@@ -3801,12 +4533,14 @@ pub mod trading_pair_azero {
                 .expect("instantiate failed")
                 .account_id;
 
-            // Instantiate TradingPairAzeroRef contract
+            // Create a TradingPairAzero contract instance
             let tpa_constructor = TradingPairAzeroRef::new(
                 psp22_acc_id,
                 1000000000000,
                 psp22_acc_id,
                 get_charlie_account_id(),
+                0,
+                get_alice_account_id(),
             );
             let tpa_acc_id = client
                 .instantiate(
@@ -3820,254 +4554,1224 @@ pub mod trading_pair_azero {
                 .expect("instantiate failed")
                 .account_id;
 
-            // Approve MyPsp22Ref to provide liquidity to TradingPairAzeroRef
-            let approve_psp22_to_provide_lp = build_message::<MyPsp22Ref>(psp22_acc_id.clone())
-                .call(|my_psp22| my_psp22.approve(tpa_acc_id, 100000000000000));
+            // Approve Bob over Alice LP tokens
+            let approve = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| trading_pair_azero.approve(get_bob_account_id(), 1000));
             client
-                .call(&ink_e2e::alice(), approve_psp22_to_provide_lp, 0, None)
+                .call(&ink_e2e::alice(), approve, 0, None)
                 .await
-                .expect("calling `approve_psp22_to_provide_lp` failed");
+                .expect("calling `approve` failed");
+
+            // Increase Bob allowance
+            let increase_allowance = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| {
+                    trading_pair_azero.increase_allowance(get_bob_account_id(), 500)
+                });
+            client
+                .call(&ink_e2e::alice(), increase_allowance, 0, None)
+                .await
+                .expect("calling `increase_allowance` failed");
+
+            // Decrease Bob allowance
+            let decrease_allowance = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| {
+                    trading_pair_azero.decrease_allowance(get_bob_account_id(), 200)
+                });
+            client
+                .call(&ink_e2e::alice(), decrease_allowance, 0, None)
+                .await
+                .expect("calling `decrease_allowance` failed");
+
+            // Decreasing below zero fails
+            let decrease_allowance_too_much = build_message::<TradingPairAzeroRef>(
+                tpa_acc_id.clone(),
+            )
+            .call(|trading_pair_azero| {
+                trading_pair_azero.decrease_allowance(get_bob_account_id(), 2000)
+            });
+            let decrease_allowance_too_much_res = client
+                .call_dry_run(&ink_e2e::alice(), &decrease_allowance_too_much, 0, None)
+                .await;
+            assert_eq!(
+                decrease_allowance_too_much_res.return_value(),
+                Err(PSP22Error::InsufficientAllowance)
+            );
+
+            // Fetch Bob allowance
+            let allowance = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone()).call(
+                |trading_pair_azero| {
+                    trading_pair_azero.allowance(get_alice_account_id(), get_bob_account_id())
+                },
+            );
+            let allowance_res = client
+                .call(&ink_e2e::alice(), allowance, 0, None)
+                .await
+                .expect("allowance failed");
+            assert_eq!(allowance_res.return_value(), 1300);
+
+            Ok(())
+        }
+
+        /// Tests included in 'lp_lock_works'
+        /// 1. provide_to_pool
+        /// 2. lock_lp
+        /// 3. get_locks
+        /// 4. get_locked_lp_of
+        /// 5. transfer (locked shares)
+        #[ink_e2e::test(additional_contracts = "../my_psp22/Cargo.toml")]
+        async fn lp_lock_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            // Create a PSP22 contract instance
+            let psp22_constructor = MyPsp22Ref::new(
+                10000000000000000,
+                Some(String::from("TOKEN").into()),
+                Some(String::from("TKN").into()),
+                12,
+            );
+            let psp22_acc_id = client
+                .instantiate("my_psp22", &ink_e2e::alice(), psp22_constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            // Create a TradingPairAzero contract instance
+            let tpa_constructor = TradingPairAzeroRef::new(
+                psp22_acc_id,
+                1000000000000,
+                psp22_acc_id,
+                get_charlie_account_id(),
+                0,
+                get_alice_account_id(),
+            );
+            let tpa_acc_id = client
+                .instantiate(
+                    "trading_pair_azero",
+                    &ink_e2e::alice(),
+                    tpa_constructor,
+                    0,
+                    None,
+                )
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            // Approve PSP22 contract to provide liquidity to TradingPairAzero contract
+            let approve_psp22_to_provide_lp = build_message::<MyPsp22Ref>(psp22_acc_id.clone())
+                .call(|my_psp22| my_psp22.approve(tpa_acc_id, 100000000000000));
+            client
+                .call(&ink_e2e::alice(), approve_psp22_to_provide_lp, 0, None)
+                .await
+                .expect("calling `approve_psp22_to_provide_lp` failed");
+
+            // Provide liquidity to TradingPairAzero contract
+            let provide_to_tpa = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone()).call(
+                |trading_pair_azero| {
+                    trading_pair_azero.provide_to_pool(
+                        100000000000000,
+                        10000000000000,
+                        31622776600683,
+                        0,
+                    )
+                },
+            );
+            let amount: u128 = 10000000000000;
+            client
+                .call(&ink_e2e::alice(), provide_to_tpa, amount, None)
+                .await
+                .expect("calling `provide_to_tpa` failed");
+
+            // Lock part of Alice LP shares far in the future
+            let unlock_timestamp: u64 = 4102444800;
+            let lock_lp = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone()).call(
+                |trading_pair_azero| trading_pair_azero.lock_lp(20000000000000, unlock_timestamp),
+            );
+            client
+                .call(&ink_e2e::alice(), lock_lp, 0, None)
+                .await
+                .expect("calling `lock_lp` failed");
+
+            // Fetch Alice LP locks
+            let get_locks = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| trading_pair_azero.get_locks(get_alice_account_id()));
+            let get_locks_res = client
+                .call(&ink_e2e::alice(), get_locks, 0, None)
+                .await
+                .expect("get_locks failed");
+            assert_eq!(
+                get_locks_res.return_value(),
+                vec![(20000000000000, unlock_timestamp)]
+            );
+
+            // Fetch Alice locked LP shares
+            let get_locked_lp_of = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| {
+                    trading_pair_azero.get_locked_lp_of(get_alice_account_id())
+                });
+            let get_locked_lp_of_res = client
+                .call(&ink_e2e::alice(), get_locked_lp_of, 0, None)
+                .await
+                .expect("get_locked_lp_of failed");
+            assert_eq!(get_locked_lp_of_res.return_value(), 20000000000000);
+
+            // Transferring locked shares fails
+            let get_lp_token_of = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| {
+                    trading_pair_azero.get_lp_token_of(get_alice_account_id())
+                });
+            let lp_balance = client
+                .call(&ink_e2e::alice(), get_lp_token_of, 0, None)
+                .await
+                .expect("get_lp_token_of failed")
+                .return_value();
+            let transfer_locked = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| {
+                    trading_pair_azero.transfer(get_bob_account_id(), lp_balance, vec![])
+                });
+            let transfer_locked_res = client
+                .call_dry_run(&ink_e2e::alice(), &transfer_locked, 0, None)
+                .await;
+            assert_eq!(
+                transfer_locked_res.return_value(),
+                Err(PSP22Error::Custom(String::from("LP tokens locked").into()))
+            );
+
+            Ok(())
+        }
+
+        /// Tests included in 'lp_token_metadata_works'
+        /// 1. token_name
+        /// 2. token_symbol
+        /// 3. token_decimals
+        #[ink_e2e::test(additional_contracts = "../my_psp22/Cargo.toml")]
+        async fn lp_token_metadata_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            // Create a PSP22 contract instance
+            let psp22_constructor = MyPsp22Ref::new(
+                10000000000000000,
+                Some(String::from("TOKEN").into()),
+                Some(String::from("TKN").into()),
+                12,
+            );
+            let psp22_acc_id = client
+                .instantiate("my_psp22", &ink_e2e::alice(), psp22_constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            // Create a TradingPairAzero contract instance
+            let tpa_constructor = TradingPairAzeroRef::new(
+                psp22_acc_id,
+                1000000000000,
+                psp22_acc_id,
+                get_charlie_account_id(),
+                0,
+                get_alice_account_id(),
+            );
+            let tpa_acc_id = client
+                .instantiate(
+                    "trading_pair_azero",
+                    &ink_e2e::alice(),
+                    tpa_constructor,
+                    0,
+                    None,
+                )
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            // Fetch the LP token name
+            let token_name = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| trading_pair_azero.token_name());
+            let token_name_res = client
+                .call(&ink_e2e::alice(), token_name, 0, None)
+                .await
+                .expect("token_name failed");
+            assert_eq!(
+                token_name_res.return_value(),
+                Some(String::from("Panorama LP AZERO-TKN").into())
+            );
+
+            // Fetch the LP token symbol
+            let token_symbol = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| trading_pair_azero.token_symbol());
+            let token_symbol_res = client
+                .call(&ink_e2e::alice(), token_symbol, 0, None)
+                .await
+                .expect("token_symbol failed");
+            assert_eq!(
+                token_symbol_res.return_value(),
+                Some(String::from("AZERO-TKN-LP").into())
+            );
+
+            // Fetch the LP token decimals
+            let token_decimals = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| trading_pair_azero.token_decimals());
+            let token_decimals_res = client
+                .call(&ink_e2e::alice(), token_decimals, 0, None)
+                .await
+                .expect("token_decimals failed");
+            assert_eq!(token_decimals_res.return_value(), 12);
+
+            Ok(())
+        }
+
+        /// Tests included in 'quote_swap_works'
+        /// 1. provide_to_pool
+        /// 2. quote_swap
+        /// 3. get_est_price_psp22_to_a0
+        #[ink_e2e::test(additional_contracts = "../my_psp22/Cargo.toml")]
+        async fn quote_swap_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            // Create a PSP22 contract instance
+            let psp22_constructor = MyPsp22Ref::new(
+                10000000000000000,
+                Some(String::from("TOKEN").into()),
+                Some(String::from("TKN").into()),
+                12,
+            );
+            let psp22_acc_id = client
+                .instantiate("my_psp22", &ink_e2e::alice(), psp22_constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            // Create a TradingPairAzero contract instance
+            let tpa_constructor = TradingPairAzeroRef::new(
+                psp22_acc_id,
+                1000000000000,
+                psp22_acc_id,
+                get_charlie_account_id(),
+                0,
+                get_alice_account_id(),
+            );
+            let tpa_acc_id = client
+                .instantiate(
+                    "trading_pair_azero",
+                    &ink_e2e::alice(),
+                    tpa_constructor,
+                    0,
+                    None,
+                )
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            // Approve PSP22 contract to provide liquidity to TradingPairAzero contract
+            let approve_psp22_to_provide_lp = build_message::<MyPsp22Ref>(psp22_acc_id.clone())
+                .call(|my_psp22| my_psp22.approve(tpa_acc_id, 100000000000000));
+            client
+                .call(&ink_e2e::alice(), approve_psp22_to_provide_lp, 0, None)
+                .await
+                .expect("calling `approve_psp22_to_provide_lp` failed");
+
+            // Provide liquidity to TradingPairAzero contract
+            let provide_to_tpa = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone()).call(
+                |trading_pair_azero| {
+                    trading_pair_azero.provide_to_pool(
+                        100000000000000,
+                        10000000000000,
+                        31622776600683,
+                        0,
+                    )
+                },
+            );
+            let amount: u128 = 10000000000000;
+            client
+                .call(&ink_e2e::alice(), provide_to_tpa, amount, None)
+                .await
+                .expect("calling `provide_to_tpa` failed");
+
+            // Build a message to quote a swap from PSP22 to A0
+            let quote_swap = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone()).call(
+                |trading_pair_azero| {
+                    trading_pair_azero.quote_swap(
+                        SwapDirection::Psp22ToA0,
+                        1000000000000,
+                        get_alice_account_id(),
+                    )
+                },
+            );
+            let quote_swap_res = client
+                .call(&ink_e2e::alice(), quote_swap, 0, None)
+                .await
+                .expect("quote_swap failed");
+            let Some(quote) = quote_swap_res.return_value().ok() else { panic!("test") };
+
+            // Build a message to get the estimated price from PSP22 to A0
+            let get_est_price_psp22_to_a0 = build_message::<TradingPairAzeroRef>(
+                tpa_acc_id.clone(),
+            )
+            .call(|trading_pair_azero| trading_pair_azero.get_est_price_psp22_to_a0(1000000000000));
+            let get_est_price_psp22_to_a0_res = client
+                .call(&ink_e2e::alice(), get_est_price_psp22_to_a0, 0, None)
+                .await
+                .expect("get_est_price_psp22_to_a0 failed");
+            let Some(price) = get_est_price_psp22_to_a0_res.return_value().ok() else { panic!("test") };
+
+            // The quote agrees with the estimated price and its fees add up
+            assert_eq!(quote.amount_out_before_fees, price);
+            assert_eq!(
+                quote.amount_out + quote.vault_fee_out + quote.lp_fee,
+                quote.amount_out_before_fees
+            );
+            assert_eq!(quote.vault_fee_in, 2000000000);
+            assert_eq!(quote.new_psp22_reserve, 100000000000000 + 1000000000000 - 2000000000);
+            assert_eq!(
+                quote.new_a0_reserve,
+                10001000000000 - quote.amount_out - quote.vault_fee_out
+            );
+
+            Ok(())
+        }
+
+        /// Tests included in 'swap_psp22_works'
+        /// 1. provide_to_pool
+        /// 2. get_est_price_psp22_to_a0
+        /// 3. swap_psp22
+        /// 4. get_a0_balance
+        /// 5. get_generated_lp_fees
+        /// 6. get_a0_difference_by_percentage
+        /// 7. get_account_overall_lp_fee_rewards
+        /// 8. withdraw_specific_amount
+        /// 9. get_psp22_balance
+        /// 10. get_a0_lp_fee_tokens
+        #[ink_e2e::test(additional_contracts = "../my_psp22/Cargo.toml")]
+        async fn swap_psp22_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            // Instantiate MyPsp22Ref contract
+            let psp22_constructor = MyPsp22Ref::new(
+                10000000000000000,
+                Some(String::from("TOKEN").into()),
+                Some(String::from("TKN").into()),
+                12,
+            );
+            let psp22_acc_id = client
+                .instantiate("my_psp22", &ink_e2e::alice(), psp22_constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            // Instantiate TradingPairAzeroRef contract
+            let tpa_constructor = TradingPairAzeroRef::new(
+                psp22_acc_id,
+                1000000000000,
+                psp22_acc_id,
+                get_charlie_account_id(),
+                0,
+                get_alice_account_id(),
+            );
+            let tpa_acc_id = client
+                .instantiate(
+                    "trading_pair_azero",
+                    &ink_e2e::alice(),
+                    tpa_constructor,
+                    0,
+                    None,
+                )
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            // Approve MyPsp22Ref to provide liquidity to TradingPairAzeroRef
+            let approve_psp22_to_provide_lp = build_message::<MyPsp22Ref>(psp22_acc_id.clone())
+                .call(|my_psp22| my_psp22.approve(tpa_acc_id, 100000000000000));
+            client
+                .call(&ink_e2e::alice(), approve_psp22_to_provide_lp, 0, None)
+                .await
+                .expect("calling `approve_psp22_to_provide_lp` failed");
+
+            // Provide liquidity to TradingPairAzeroRef
+            let provide_to_tpa = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone()).call(
+                |trading_pair_azero| {
+                    trading_pair_azero.provide_to_pool(
+                        100000000000000,
+                        10000000000000,
+                        31622776600683,
+                        0,
+                    )
+                },
+            );
+            let amount: u128 = 10000000000000;
+            client
+                .call(&ink_e2e::alice(), provide_to_tpa, amount, None)
+                .await
+                .expect("calling `provide_to_tpa` failed");
+
+            // Get the balance of MyPsp22Ref for Alice account
+            let psp22_balance_of = build_message::<MyPsp22Ref>(psp22_acc_id.clone())
+                .call(|my_psp22| my_psp22.balance_of(get_alice_account_id()));
+
+            // Call and fetch the results
+            let psp22_balance_of_res = client
+                .call(&ink_e2e::alice(), psp22_balance_of, 0, None)
+                .await
+                .expect("psp22_balance_of failed");
+            let psp22_balance = psp22_balance_of_res.return_value();
+
+            // Verify the balance of MyPsp22Ref for Alice account
+            assert_eq!(psp22_balance, 9900000000000000);
+
+            // Approve MyPsp22Ref to provide liquidity to TradingPairAzeroRef again
+            let approve_psp22_to_provide_lp = build_message::<MyPsp22Ref>(psp22_acc_id.clone())
+                .call(|my_psp22| my_psp22.approve(tpa_acc_id, 100000000000000));
+            client
+                .call(&ink_e2e::alice(), approve_psp22_to_provide_lp, 0, None)
+                .await
+                .expect("calling `approve_psp22_to_provide_lp` failed");
+
+            // Get estimated price of MyPsp22Ref to Azero token in TradingPairAzeroRef
+            let get_est_price_psp22_to_a0 = build_message::<TradingPairAzeroRef>(
+                tpa_acc_id.clone(),
+            )
+            .call(|trading_pair_azero| trading_pair_azero.get_est_price_psp22_to_a0(1000000000000));
+
+            // Call and fetch the results
+            let get_est_price_psp22_to_a0_res = client
+                .call(&ink_e2e::alice(), get_est_price_psp22_to_a0, 0, None)
+                .await
+                .expect("get_est_price_psp22_to_a0 failed");
+
+            let Some(price) = get_est_price_psp22_to_a0_res.return_value().ok() else { panic!("failed!") };
+
+            // Verify the estimated price of MyPsp22Ref to Azero token
+            assert_eq!(price, 99019801980);
+
+            // Swap MyPsp22Ref for Azero token
+            let swap_psp22 = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone()).call(
+                |trading_pair_azero| {
+                    trading_pair_azero.swap_psp22(1000000000000, price, 500000000000)
+                },
+            );
+            client
+                .call(&ink_e2e::alice(), swap_psp22, 0, None)
+                .await
+                .expect("calling `swap_psp22` failed");
+
+            // Get the balance of MyPsp22Ref for Alice account after swapping
+            let psp22_balance_of = build_message::<MyPsp22Ref>(psp22_acc_id.clone())
+                .call(|my_psp22| my_psp22.balance_of(get_alice_account_id()));
+
+            // Call and fetch the results
+            let psp22_balance_of_res = client
+                .call(&ink_e2e::alice(), psp22_balance_of, 0, None)
+                .await
+                .expect("psp22_balance_of failed");
+
+            let psp22_balance = psp22_balance_of_res.return_value();
+
+            // Verify the balance of MyPsp22Ref for Alice account after swapping
+            assert_eq!(psp22_balance, 9899000000000000);
+
+            // Get the balance of Azero token in TradingPairAzeroRef
+            let get_a0_balance = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| trading_pair_azero.get_a0_balance());
+            let get_a0_res = client
+                .call(&ink_e2e::alice(), get_a0_balance, 0, None)
+                .await
+                .expect("get_a0_balance failed");
+
+            // Verify the balance of Azero token in TradingPairAzeroRef
+            assert_eq!(get_a0_res.return_value(), 9902970396039);
+
+            // Get the balance of MyPsp22Ref in TradingPairAzeroRef
+            let get_psp22_balance = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| trading_pair_azero.get_psp22_balance());
+            let get_psp22_res = client
+                .call(&ink_e2e::alice(), get_psp22_balance, 0, None)
+                .await
+                .expect("get_psp22_balance failed");
+
+            // Verify the balance of MyPsp22Ref in TradingPairAzeroRef
+            assert_eq!(get_psp22_res.return_value(), 100998000000000);
+
+            // Get the balance of MyPsp22Ref for Charlie account after swapping
+            let psp22_balance_of = build_message::<MyPsp22Ref>(psp22_acc_id.clone())
+                .call(|my_psp22| my_psp22.balance_of(get_charlie_account_id()));
+
+            // Call and fetch the results
+            let psp22_balance_of_res = client
+                .call(&ink_e2e::alice(), psp22_balance_of, 0, None)
+                .await
+                .expect("psp22_balance_of failed");
+            let psp22_balance = psp22_balance_of_res.return_value();
+
+            // Verify the balance of MyPsp22Ref for Charlie account after swapping
+            assert_eq!(psp22_balance, 2000000000);
+
+            // Get the generated LP fees in TradingPairAzeroRef
+            let get_generated_lp_fees = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| trading_pair_azero.get_generated_lp_fees());
+
+            // Call and fetch the results
+            let get_generated_lp_fees_res = client
+                .call(&ink_e2e::alice(), get_generated_lp_fees, 0, None)
+                .await
+                .expect("get_generated_lp_fees failed");
+
+            let (psp22_fees, a0_fees) = get_generated_lp_fees_res.return_value();
+
+            // Verify the generated LP fees in TradingPairAzeroRef
+            assert_eq!(psp22_fees, 0);
+
+            assert_eq!(a0_fees, 990198019);
+
+            // Get the swap volume of the last day in TradingPairAzeroRef
+            let get_volume = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| trading_pair_azero.get_volume(0));
+            let get_volume_res = client
+                .call(&ink_e2e::alice(), get_volume, 0, None)
+                .await
+                .expect("get_volume failed");
+
+            // Verify the swapped PSP22 and AZERO volume
+            assert_eq!(get_volume_res.return_value(), (1000000000000, 0));
+
+            // Get the LP fees of the last day in TradingPairAzeroRef
+            let get_fees = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| trading_pair_azero.get_fees(0));
+            let get_fees_res = client
+                .call(&ink_e2e::alice(), get_fees, 0, None)
+                .await
+                .expect("get_fees failed");
+
+            // Verify the generated LP fees match the lifetime LP fees
+            assert_eq!(get_fees_res.return_value(), (0, 990198019));
+
+            // Get the percentage difference of Azero LP fees
+            let get_a0_difference_by_percentage = build_message::<TradingPairAzeroRef>(
+                tpa_acc_id.clone(),
+            )
+            .call(|trading_pair_azero| trading_pair_azero.get_a0_difference_by_percentage());
+
+            // Call and fetch the results
+            let get_a0_difference_by_percentage_res = client
+                .call(&ink_e2e::alice(), get_a0_difference_by_percentage, 0, None)
+                .await
+                .expect("get_a0_difference_by_percentage failed");
+            let Some(a0_lp_fee_diff) = get_a0_difference_by_percentage_res.return_value().ok() else { panic!("test") };
+
+            // Verify the percentage difference of Azero LP fees
+            assert_eq!(a0_lp_fee_diff, 9998500200);
+
+            let amount: u128 = 15811388300341;
+
+            // Withdraw a specific amount from the pool
+            let withdraw_from_pool = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| trading_pair_azero.withdraw_specific_amount(amount));
+
+            client
+                .call(&ink_e2e::alice(), withdraw_from_pool, 0, None)
+                .await
+                .expect("withdraw_from_pool failed");
+
+            // Get the overall LP fee rewards for Alice account
+            let get_account_overall_lp_fee_rewards = build_message::<TradingPairAzeroRef>(
+                tpa_acc_id.clone(),
+            )
+            .call(|trading_pair_azero| {
+                trading_pair_azero.get_account_overall_lp_fee_rewards(get_alice_account_id())
+            });
+
+            // Call and fetch the results
+            let get_account_overall_lp_fee_rewards_res = client
+                .call(
+                    &ink_e2e::alice(),
+                    get_account_overall_lp_fee_rewards,
+                    0,
+                    None,
+                )
+                .await
+                .expect("get_account_overall_lp_fee_rewards failed");
+
+            let (psp22_fees, a0_fees) = get_account_overall_lp_fee_rewards_res.return_value();
+
+            // Verify the overall LP fee rewards for Alice account
+            assert_eq!(psp22_fees, 0);
+
+            assert_eq!(a0_fees, 495099009);
+
+            // Get A0 LP fee tokens
+            let get_a0_lp_fee_tokens = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| trading_pair_azero.get_a0_lp_fee_tokens(amount));
+
+            let get_a0_lp_fee_tokens_res = client
+                .call(&ink_e2e::alice(), get_a0_lp_fee_tokens, 0, None)
+                .await
+                .expect("get_a0_lp_fee_tokens failed");
+
+            let Some(a0_lp_fee) = get_a0_lp_fee_tokens_res.return_value().ok() else {
+                panic!("failed!")
+            };
+
+            assert_eq!(a0_lp_fee, 495099009);
+
+            Ok(())
+        }
+
+        /// Tests included in 'swap_a0_works'
+        /// 1. provide_to_pool
+        /// 2. swap_a0
+        /// 3. get_a0_balance
+        /// 4. get_psp22_balance
+        /// 5. get_generated_lp_fees
+        /// 6. get_contract_overall_generated_fee
+        /// 7. get_psp22_difference_by_percentage
+        /// 8. withdraw_specific_amount
+        /// 9. get_account_overall_lp_fee_rewards
+        /// 10.get_psp22_lp_fee_tokens
+        #[ink_e2e::test(additional_contracts = "../my_psp22/Cargo.toml")]
+        async fn swap_a0_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            // Create and initialize MyPsp22 contract
+            let psp22_constructor = MyPsp22Ref::new(
+                10000000000000000,
+                Some(String::from("TOKEN").into()),
+                Some(String::from("TKN").into()),
+                12,
+            );
+
+            let psp22_acc_id = client
+                .instantiate("my_psp22", &ink_e2e::alice(), psp22_constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            // Create and initialize TradingPairAzero contract
+            let tpa_constructor = TradingPairAzeroRef::new(
+                psp22_acc_id,
+                1000000000000,
+                psp22_acc_id,
+                get_charlie_account_id(),
+                0,
+                get_alice_account_id(),
+            );
+
+            let tpa_acc_id = client
+                .instantiate(
+                    "trading_pair_azero",
+                    &ink_e2e::alice(),
+                    tpa_constructor,
+                    0,
+                    None,
+                )
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            // Approve MyPsp22 to provide LP to TradingPairAzero
+            let approve_psp22_to_provide_lp = build_message::<MyPsp22Ref>(psp22_acc_id.clone())
+                .call(|my_psp22| my_psp22.approve(tpa_acc_id, 100000000000000));
+
+            client
+                .call(&ink_e2e::alice(), approve_psp22_to_provide_lp, 0, None)
+                .await
+                .expect("calling `approve_psp22_to_provide_lp` failed");
+
+            // Provide LP to TradingPairAzero
+            let provide_to_tpa = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone()).call(
+                |trading_pair_azero| {
+                    trading_pair_azero.provide_to_pool(
+                        100000000000000,
+                        10000000000000,
+                        31622776600683,
+                        0,
+                    )
+                },
+            );
+
+            let amount: u128 = 10000000000000;
 
-            // Provide liquidity to TradingPairAzeroRef
-            let provide_to_tpa = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone()).call(
-                |trading_pair_azero| {
-                    trading_pair_azero.provide_to_pool(
-                        100000000000000,
-                        1000000000000000,
-                        500000000000,
-                    )
-                },
-            );
-            let amount: u128 = 10000000000000;
             client
                 .call(&ink_e2e::alice(), provide_to_tpa, amount, None)
                 .await
-                .expect("calling `provide_to_tpa` failed");
+                .expect("calling `provide_to_tpa` failed");
+
+            // Fetch the estimated price for swap
+            let get_est_price_a0_to_psp22_for_swap = build_message::<TradingPairAzeroRef>(
+                tpa_acc_id.clone(),
+            )
+            .call(|trading_pair_azero| {
+                trading_pair_azero.get_est_price_a0_to_psp22_for_swap(100000000000)
+            });
+
+            let get_est_price_a0_to_psp22_for_swap_res = client
+                .call(
+                    &ink_e2e::alice(),
+                    get_est_price_a0_to_psp22_for_swap,
+                    0,
+                    None,
+                )
+                .await
+                .expect("get_est_price_a0_to_psp22_for_swap failed");
+
+            let Some(price) = get_est_price_a0_to_psp22_for_swap_res.return_value().ok() else { panic!("failed!") };
+
+            assert_eq!(price, 999900009999);
+
+            // Perform the swap
+            let swap_a0 = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| trading_pair_azero.swap_a0(price, 1000000000000));
+
+            let amount: u128 = 100000000000;
+
+            client
+                .call(&ink_e2e::alice(), swap_a0, amount, None)
+                .await
+                .expect("calling `swap_a0` failed");
+
+            // Check MyPsp22 balance
+            let psp22_balance_of = build_message::<MyPsp22Ref>(psp22_acc_id.clone())
+                .call(|my_psp22| my_psp22.balance_of(get_alice_account_id()));
+
+            let psp22_balance_of_res = client
+                .call(&ink_e2e::alice(), psp22_balance_of, 0, None)
+                .await
+                .expect("psp22_balance_of failed");
+
+            let psp22_balance = psp22_balance_of_res.return_value();
+
+            assert_eq!(psp22_balance, 9900978120978120);
+
+            // Get TradingPairAzero a0 balance
+            let get_a0_balance = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| trading_pair_azero.get_a0_balance());
+
+            let get_a0_res = client
+                .call(&ink_e2e::alice(), get_a0_balance, 0, None)
+                .await
+                .expect("get_a0_balance failed");
+
+            assert_eq!(get_a0_res.return_value(), 10100800000000);
+
+            // Get TradingPairAzero MyPsp22 balance
+            let get_psp22_balance = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| trading_pair_azero.get_psp22_balance());
+
+            let get_psp22_res = client
+                .call(&ink_e2e::alice(), get_psp22_balance, 0, None)
+                .await
+                .expect("get_psp22_balance failed");
+
+            assert_eq!(get_psp22_res.return_value(), 99019899019900);
+
+            // Check MyPsp22 balance for Charlie
+            let psp22_balance_of = build_message::<MyPsp22Ref>(psp22_acc_id.clone())
+                .call(|my_psp22| my_psp22.balance_of(get_charlie_account_id()));
+
+            let psp22_balance_of_res = client
+                .call(&ink_e2e::alice(), psp22_balance_of, 0, None)
+                .await
+                .expect("psp22_balance_of failed");
+
+            let psp22_balance = psp22_balance_of_res.return_value();
+
+            assert_eq!(psp22_balance, 1980001980);
+
+            // Get generated LP fees
+            let get_generated_lp_fees = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| trading_pair_azero.get_generated_lp_fees());
+
+            let get_generated_lp_fees_res = client
+                .call(&ink_e2e::alice(), get_generated_lp_fees, 0, None)
+                .await
+                .expect("get_generated_lp_fees failed");
+
+            let (psp22_fees, a0_fees) = get_generated_lp_fees_res.return_value();
+
+            assert_eq!(psp22_fees, 9900009900);
+            assert_eq!(a0_fees, 0);
+
+            // Get overall generated fees by the contract
+            let get_contract_overall_generated_fee = build_message::<TradingPairAzeroRef>(
+                tpa_acc_id.clone(),
+            )
+            .call(|trading_pair_azero| trading_pair_azero.get_contract_overall_generated_fee());
+
+            let get_contract_overall_generated_fee_res = client
+                .call(
+                    &ink_e2e::alice(),
+                    get_contract_overall_generated_fee,
+                    0,
+                    None,
+                )
+                .await
+                .expect("get_contract_overall_generated_fee failed");
+
+            let (psp22_fees, a0_fees) = get_contract_overall_generated_fee_res.return_value();
+
+            assert_eq!(psp22_fees, 9900009900);
+            assert_eq!(a0_fees, 0);
+
+            // Get MyPsp22 difference by percentage
+            let get_psp22_difference_by_percentage = build_message::<TradingPairAzeroRef>(
+                tpa_acc_id.clone(),
+            )
+            .call(|trading_pair_azero| trading_pair_azero.get_psp22_difference_by_percentage());
+
+            let get_psp22_difference_by_percentage_res = client
+                .call(
+                    &ink_e2e::alice(),
+                    get_psp22_difference_by_percentage,
+                    0,
+                    None,
+                )
+                .await
+                .expect("get_psp22_difference_by_percentage failed");
+
+            let Some(psp22_lp_fee_diff) = get_psp22_difference_by_percentage_res.return_value().ok() else {
+                panic!("failed!")
+            };
+
+            assert_eq!(psp22_lp_fee_diff, 9997500600);
+
+            // Withdraw from the pool
+            let withdraw_from_pool = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| trading_pair_azero.withdraw_specific_amount(amount));
+
+            client
+                .call(&ink_e2e::alice(), withdraw_from_pool, 0, None)
+                .await
+                .expect("withdraw_from_pool failed");
+
+            // Get account overall LP fee rewards
+            let get_account_overall_lp_fee_rewards = build_message::<TradingPairAzeroRef>(
+                tpa_acc_id.clone(),
+            )
+            .call(|trading_pair_azero| {
+                trading_pair_azero.get_account_overall_lp_fee_rewards(get_alice_account_id())
+            });
+
+            let get_account_overall_lp_fee_rewards_res = client
+                .call(
+                    &ink_e2e::alice(),
+                    get_account_overall_lp_fee_rewards,
+                    0,
+                    None,
+                )
+                .await
+                .expect("get_account_overall_lp_fee_rewards failed");
+
+            let (psp22_fees, a0_fees) = get_account_overall_lp_fee_rewards_res.return_value();
+
+            assert_eq!(psp22_fees, 31306580);
+            assert_eq!(a0_fees, 0);
+
+            // Get PSP22 LP fee tokens
+            let get_psp22_lp_fee_tokens = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| trading_pair_azero.get_psp22_lp_fee_tokens(amount));
+
+            let get_psp22_lp_fee_tokens_res = client
+                .call(&ink_e2e::alice(), get_psp22_lp_fee_tokens, 0, None)
+                .await
+                .expect("get_psp22_lp_fee_tokens failed");
 
-            // Get the balance of MyPsp22Ref for Alice account
-            let psp22_balance_of = build_message::<MyPsp22Ref>(psp22_acc_id.clone())
-                .call(|my_psp22| my_psp22.balance_of(get_alice_account_id()));
+            let Some(psp22_lp_fee) = get_psp22_lp_fee_tokens_res.return_value().ok() else {
+                panic!("failed!")
+            };
 
-            // Call and fetch the results
-            let psp22_balance_of_res = client
-                .call(&ink_e2e::alice(), psp22_balance_of, 0, None)
+            assert_eq!(psp22_lp_fee, 31306580);
+
+            Ok(())
+        }
+
+        /// Tests included in 'transfer_lp_tokens_works'
+        /// 1. provide_to_tpa
+        /// 2. get_lp_token_of
+        /// 3. transfer
+        /// 4. get_lp_token_of
+        #[ink_e2e::test(additional_contracts = "../my_psp22/Cargo.toml")]
+        async fn transfer_lp_tokens_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            // Instantiate MyPsp22 contract
+            let psp22_constructor = MyPsp22Ref::new(
+                10000000000000000,
+                Some(String::from("TOKEN").into()),
+                Some(String::from("TKN").into()),
+                12,
+            );
+            let psp22_acc_id = client
+                .instantiate("my_psp22", &ink_e2e::alice(), psp22_constructor, 0, None)
                 .await
-                .expect("psp22_balance_of failed");
-            let psp22_balance = psp22_balance_of_res.return_value();
+                .expect("instantiate failed")
+                .account_id;
 
-            // Verify the balance of MyPsp22Ref for Alice account
-            assert_eq!(psp22_balance, 9900000000000000);
+            // Instantiate TradingPairAzero contract
+            let tpa_constructor = TradingPairAzeroRef::new(
+                psp22_acc_id.clone(),
+                1000000000000,
+                psp22_acc_id.clone(),
+                get_charlie_account_id(),
+                0,
+                get_alice_account_id(),
+            );
+            let tpa_acc_id = client
+                .instantiate(
+                    "trading_pair_azero",
+                    &ink_e2e::alice(),
+                    tpa_constructor,
+                    0,
+                    None,
+                )
+                .await
+                .expect("instantiate failed")
+                .account_id;
 
-            // Approve MyPsp22Ref to provide liquidity to TradingPairAzeroRef again
+            // Approve MyPsp22 contract to provide LP tokens to TradingPairAzero
             let approve_psp22_to_provide_lp = build_message::<MyPsp22Ref>(psp22_acc_id.clone())
-                .call(|my_psp22| my_psp22.approve(tpa_acc_id, 100000000000000));
+                .call(|my_psp22| my_psp22.approve(tpa_acc_id.clone(), 100000000000000));
             client
                 .call(&ink_e2e::alice(), approve_psp22_to_provide_lp, 0, None)
                 .await
                 .expect("calling `approve_psp22_to_provide_lp` failed");
 
-            // Get estimated price of MyPsp22Ref to Azero token in TradingPairAzeroRef
-            let get_est_price_psp22_to_a0 = build_message::<TradingPairAzeroRef>(
-                tpa_acc_id.clone(),
-            )
-            .call(|trading_pair_azero| trading_pair_azero.get_est_price_psp22_to_a0(1000000000000));
-
-            // Call and fetch the results
-            let get_est_price_psp22_to_a0_res = client
-                .call(&ink_e2e::alice(), get_est_price_psp22_to_a0, 0, None)
+            // Provide LP tokens to TradingPairAzero
+            let provide_to_tpa = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone()).call(
+                |trading_pair_azero| {
+                    trading_pair_azero.provide_to_pool(
+                        100000000000000,
+                        10000000000000,
+                        31622776600683,
+                        0,
+                    )
+                },
+            );
+            let amount: u128 = 10000000000000;
+            client
+                .call(&ink_e2e::alice(), provide_to_tpa, amount, None)
                 .await
-                .expect("get_est_price_psp22_to_a0 failed");
+                .expect("calling `provide_to_tpa` failed");
 
-            let Some(price) = get_est_price_psp22_to_a0_res.return_value().ok() else { panic!("failed!") };
+            // Check LP token balance for Alice
+            let get_lp_share_balance = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| {
+                    trading_pair_azero.get_lp_token_of(get_alice_account_id())
+                });
+            let get_lp_share_res = client
+                .call(&ink_e2e::alice(), get_lp_share_balance, 0, None)
+                .await
+                .expect("get_lp_share_balance failed");
 
-            // Verify the estimated price of MyPsp22Ref to Azero token
-            assert_eq!(price, 99019801980);
+            assert_eq!(get_lp_share_res.return_value(), 31622776600683);
 
-            // Swap MyPsp22Ref for Azero token
-            let swap_psp22 = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone()).call(
+            // Transfer LP tokens from Alice to Bob
+            let transfer_lp_tokens = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone()).call(
                 |trading_pair_azero| {
-                    trading_pair_azero.swap_psp22(1000000000000, price, 500000000000)
+                    trading_pair_azero.transfer(get_bob_account_id(), 15811388300341, vec![])
                 },
             );
             client
-                .call(&ink_e2e::alice(), swap_psp22, 0, None)
+                .call(&ink_e2e::alice(), transfer_lp_tokens, 0, None)
                 .await
-                .expect("calling `swap_psp22` failed");
-
-            // Get the balance of MyPsp22Ref for Alice account after swapping
-            let psp22_balance_of = build_message::<MyPsp22Ref>(psp22_acc_id.clone())
-                .call(|my_psp22| my_psp22.balance_of(get_alice_account_id()));
+                .expect("calling `transfer` failed");
 
-            // Call and fetch the results
-            let psp22_balance_of_res = client
-                .call(&ink_e2e::alice(), psp22_balance_of, 0, None)
+            // Check LP token balance for Alice after transfer
+            let get_lp_share_balance = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| {
+                    trading_pair_azero.get_lp_token_of(get_alice_account_id())
+                });
+            let get_lp_share_res = client
+                .call(&ink_e2e::alice(), get_lp_share_balance, 0, None)
                 .await
-                .expect("psp22_balance_of failed");
-
-            let psp22_balance = psp22_balance_of_res.return_value();
+                .expect("get_lp_share_balance failed");
 
-            // Verify the balance of MyPsp22Ref for Alice account after swapping
-            assert_eq!(psp22_balance, 9899000000000000);
+            assert_eq!(get_lp_share_res.return_value(), 15811388300342);
 
-            // Get the balance of Azero token in TradingPairAzeroRef
-            let get_a0_balance = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
-                .call(|trading_pair_azero| trading_pair_azero.get_a0_balance());
-            let get_a0_res = client
-                .call(&ink_e2e::alice(), get_a0_balance, 0, None)
+            // Check LP token balance for Bob after transfer
+            let get_lp_share_balance = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| {
+                    trading_pair_azero.get_lp_token_of(get_bob_account_id())
+                });
+            let get_lp_share_res = client
+                .call(&ink_e2e::alice(), get_lp_share_balance, 0, None)
                 .await
-                .expect("get_a0_balance failed");
-
-            // Verify the balance of Azero token in TradingPairAzeroRef
-            assert_eq!(get_a0_res.return_value(), 9902970396039);
+                .expect("get failed");
 
-            // Get the balance of MyPsp22Ref in TradingPairAzeroRef
-            let get_psp22_balance = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
-                .call(|trading_pair_azero| trading_pair_azero.get_psp22_balance());
-            let get_psp22_res = client
-                .call(&ink_e2e::alice(), get_psp22_balance, 0, None)
-                .await
-                .expect("get_psp22_balance failed");
+            assert_eq!(get_lp_share_res.return_value(), 15811388300341);
 
-            // Verify the balance of MyPsp22Ref in TradingPairAzeroRef
-            assert_eq!(get_psp22_res.return_value(), 100998000000000);
+            Ok(())
+        }
 
-            // Get the balance of MyPsp22Ref for Charlie account after swapping
-            let psp22_balance_of = build_message::<MyPsp22Ref>(psp22_acc_id.clone())
-                .call(|my_psp22| my_psp22.balance_of(get_charlie_account_id()));
+        /// Tests included in 'transfer_lp_tokens_from_to_works'
+        /// 1. provide_to_pool
+        /// 2. get_lp_token_of
+        /// 3. approve
+        /// 4. allowance
+        /// 5. transfer_from
+        #[ink_e2e::test(additional_contracts = "../my_psp22/Cargo.toml")]
+        async fn transfer_lp_tokens_from_to_works(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            // Instantiate MyPsp22 contract
+            let psp22_constructor = MyPsp22Ref::new(
+                10000000000000000,
+                Some(String::from("TOKEN").into()),
+                Some(String::from("TKN").into()),
+                12,
+            );
 
-            // Call and fetch the results
-            let psp22_balance_of_res = client
-                .call(&ink_e2e::alice(), psp22_balance_of, 0, None)
+            let psp22_acc_id = client
+                .instantiate("my_psp22", &ink_e2e::alice(), psp22_constructor, 0, None)
                 .await
-                .expect("psp22_balance_of failed");
-            let psp22_balance = psp22_balance_of_res.return_value();
+                .expect("instantiate failed")
+                .account_id;
 
-            // Verify the balance of MyPsp22Ref for Charlie account after swapping
-            assert_eq!(psp22_balance, 2000000000);
+            // Instantiate TradingPairAzero contract
+            let tpa_constructor = TradingPairAzeroRef::new(
+                psp22_acc_id,
+                1000000000000,
+                psp22_acc_id,
+                get_charlie_account_id(),
+                0,
+                get_alice_account_id(),
+            );
 
-            // Get the generated LP fees in TradingPairAzeroRef
-            let get_generated_lp_fees = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
-                .call(|trading_pair_azero| trading_pair_azero.get_generated_lp_fees());
+            let tpa_acc_id = client
+                .instantiate(
+                    "trading_pair_azero",
+                    &ink_e2e::alice(),
+                    tpa_constructor,
+                    0,
+                    None,
+                )
+                .await
+                .expect("instantiate failed")
+                .account_id;
 
-            // Call and fetch the results
-            let get_generated_lp_fees_res = client
-                .call(&ink_e2e::alice(), get_generated_lp_fees, 0, None)
+            // Approve MyPsp22 to provide LP tokens to TradingPairAzero
+            let approve_psp22_to_provide_lp = build_message::<MyPsp22Ref>(psp22_acc_id.clone())
+                .call(|my_psp22| my_psp22.approve(tpa_acc_id, 100000000000000));
+
+            client
+                .call(&ink_e2e::alice(), approve_psp22_to_provide_lp, 0, None)
                 .await
-                .expect("get_generated_lp_fees failed");
+                .expect("calling `approve_psp22_to_provide_lp` failed");
 
-            let (psp22_fees, a0_fees) = get_generated_lp_fees_res.return_value();
+            // Provide LP tokens to TradingPairAzero
+            let provide_to_tpa = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone()).call(
+                |trading_pair_azero| {
+                    trading_pair_azero.provide_to_pool(
+                        100000000000000,
+                        10000000000000,
+                        31622776600683,
+                        0,
+                    )
+                },
+            );
 
-            // Verify the generated LP fees in TradingPairAzeroRef
-            assert_eq!(psp22_fees, 0);
+            let amount: u128 = 10000000000000;
 
-            assert_eq!(a0_fees, 990198019);
+            client
+                .call(&ink_e2e::alice(), provide_to_tpa, amount, None)
+                .await
+                .expect("calling `provide_to_tpa` failed");
 
-            // Get the percentage difference of Azero LP fees
-            let get_a0_difference_by_percentage = build_message::<TradingPairAzeroRef>(
-                tpa_acc_id.clone(),
-            )
-            .call(|trading_pair_azero| trading_pair_azero.get_a0_difference_by_percentage());
+            // Get LP share balance of Alice
+            let get_lp_share_balance = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| {
+                    trading_pair_azero.get_lp_token_of(get_alice_account_id())
+                });
 
-            // Call and fetch the results
-            let get_a0_difference_by_percentage_res = client
-                .call(&ink_e2e::alice(), get_a0_difference_by_percentage, 0, None)
+            let get_lp_share_res = client
+                .call(&ink_e2e::alice(), get_lp_share_balance, 0, None)
                 .await
-                .expect("get_a0_difference_by_percentage failed");
-            let Some(a0_lp_fee_diff) = get_a0_difference_by_percentage_res.return_value().ok() else { panic!("test") };
+                .expect("get_lp_share_balance failed");
 
-            // Verify the percentage difference of Azero LP fees
-            assert_eq!(a0_lp_fee_diff, 9998500200);
+            assert_eq!(get_lp_share_res.return_value(), 31622776600683);
+
+            // Approve LP tokens for Bob
+            let approve_lp_tokens = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| {
+                    trading_pair_azero.approve(get_bob_account_id(), 15811388300341)
+                });
 
-            let amount: u128 = 500000000000000;
+            client
+                .call(&ink_e2e::alice(), approve_lp_tokens, 0, None)
+                .await
+                .expect("calling `approve` failed");
 
-            // Withdraw a specific amount from the pool
-            let withdraw_from_pool = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
-                .call(|trading_pair_azero| trading_pair_azero.withdraw_specific_amount(amount));
+            // Get LP tokens allowance from Alice to Bob
+            let get_lp_tokens_allowance = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| {
+                    trading_pair_azero.allowance(get_alice_account_id(), get_bob_account_id())
+                });
 
-            client
-                .call(&ink_e2e::alice(), withdraw_from_pool, 0, None)
+            let get_lp_tokens_allowance_res = client
+                .call(&ink_e2e::alice(), get_lp_tokens_allowance, 0, None)
                 .await
-                .expect("withdraw_from_pool failed");
+                .expect("allowance failed");
 
-            // Get the overall LP fee rewards for Alice account
-            let get_account_overall_lp_fee_rewards = build_message::<TradingPairAzeroRef>(
+            assert_eq!(get_lp_tokens_allowance_res.return_value(), 15811388300341);
+
+            // Bob transfers the approved LP tokens from Alice to himself
+            let transfer_lp_tokens_from_to = build_message::<TradingPairAzeroRef>(
                 tpa_acc_id.clone(),
             )
             .call(|trading_pair_azero| {
-                trading_pair_azero.get_account_overall_lp_fee_rewards(get_alice_account_id())
+                trading_pair_azero.transfer_from(
+                    get_alice_account_id(),
+                    get_bob_account_id(),
+                    15811388300341,
+                    vec![],
+                )
             });
 
-            // Call and fetch the results
-            let get_account_overall_lp_fee_rewards_res = client
-                .call(
-                    &ink_e2e::alice(),
-                    get_account_overall_lp_fee_rewards,
-                    0,
-                    None,
-                )
+            client
+                .call(&ink_e2e::bob(), transfer_lp_tokens_from_to, 0, None)
                 .await
-                .expect("get_account_overall_lp_fee_rewards failed");
+                .expect("calling `transfer_from` failed");
 
-            let (psp22_fees, a0_fees) = get_account_overall_lp_fee_rewards_res.return_value();
+            // Get LP share balance of Alice after transfer
+            let get_lp_share_balance = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| {
+                    trading_pair_azero.get_lp_token_of(get_alice_account_id())
+                });
 
-            // Verify the overall LP fee rewards for Alice account
-            assert_eq!(psp22_fees, 0);
+            let get_lp_share_res = client
+                .call(&ink_e2e::alice(), get_lp_share_balance, 0, None)
+                .await
+                .expect("get failed");
 
-            assert_eq!(a0_fees, 495099009);
+            assert_eq!(get_lp_share_res.return_value(), 15811388300342);
 
-            // Get A0 LP fee tokens
-            let get_a0_lp_fee_tokens = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
-                .call(|trading_pair_azero| trading_pair_azero.get_a0_lp_fee_tokens(amount));
+            // Get LP share balance of Bob after transfer
+            let get_lp_share_balance = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| {
+                    trading_pair_azero.get_lp_token_of(get_bob_account_id())
+                });
 
-            let get_a0_lp_fee_tokens_res = client
-                .call(&ink_e2e::alice(), get_a0_lp_fee_tokens, 0, None)
+            let get_lp_share_res = client
+                .call(&ink_e2e::alice(), get_lp_share_balance, 0, None)
                 .await
-                .expect("get_a0_lp_fee_tokens failed");
-
-            let Some(a0_lp_fee) = get_a0_lp_fee_tokens_res.return_value().ok() else {
-                panic!("failed!")
-            };
+                .expect("get failed");
 
-            assert_eq!(a0_lp_fee, 495099010);
+            assert_eq!(get_lp_share_res.return_value(), 15811388300341);
 
             Ok(())
         }
 
-        /// Tests included in 'swap_a0_works'
+        /// Tests included in 'reward_operator_works'
         /// 1. provide_to_pool
-        /// 2. swap_a0
-        /// 3. get_a0_balance
-        /// 4. get_psp22_balance
-        /// 5. get_generated_lp_fees
-        /// 6. get_contract_overall_generated_fee
-        /// 7. get_psp22_difference_by_percentage
-        /// 8. withdraw_specific_amount
-        /// 9. get_account_overall_lp_fee_rewards
-        /// 10.get_psp22_lp_fee_tokens
+        /// 2. set_reward_operator
+        /// 3. is_reward_operator
+        /// 4. redeem_for (approved, not approved and revoked operator)
         #[ink_e2e::test(additional_contracts = "../my_psp22/Cargo.toml")]
-        async fn swap_a0_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
-            // Create and initialize MyPsp22 contract
+        async fn reward_operator_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            // Create a PSP22 contract instance
             let psp22_constructor = MyPsp22Ref::new(
                 10000000000000000,
                 Some(String::from("TOKEN").into()),
                 Some(String::from("TKN").into()),
                 12,
             );
-
             let psp22_acc_id = client
                 .instantiate("my_psp22", &ink_e2e::alice(), psp22_constructor, 0, None)
                 .await
                 .expect("instantiate failed")
                 .account_id;
 
-            // Create and initialize TradingPairAzero contract
+            // Create a TradingPairAzero contract instance
             let tpa_constructor = TradingPairAzeroRef::new(
                 psp22_acc_id,
                 1000000000000,
                 psp22_acc_id,
                 get_charlie_account_id(),
+                0,
+                get_alice_account_id(),
             );
-
             let tpa_acc_id = client
                 .instantiate(
                     "trading_pair_azero",
@@ -4080,229 +5784,432 @@ pub mod trading_pair_azero {
                 .expect("instantiate failed")
                 .account_id;
 
-            // Approve MyPsp22 to provide LP to TradingPairAzero
+            // Approve PSP22 contract to provide liquidity to TradingPairAzero contract
             let approve_psp22_to_provide_lp = build_message::<MyPsp22Ref>(psp22_acc_id.clone())
                 .call(|my_psp22| my_psp22.approve(tpa_acc_id, 100000000000000));
-
             client
                 .call(&ink_e2e::alice(), approve_psp22_to_provide_lp, 0, None)
                 .await
                 .expect("calling `approve_psp22_to_provide_lp` failed");
 
-            // Provide LP to TradingPairAzero
+            // Provide liquidity to TradingPairAzero contract
             let provide_to_tpa = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone()).call(
                 |trading_pair_azero| {
                     trading_pair_azero.provide_to_pool(
                         100000000000000,
-                        1000000000000000,
-                        500000000000,
+                        10000000000000,
+                        31622776600683,
+                        0,
                     )
                 },
             );
-
             let amount: u128 = 10000000000000;
-
             client
                 .call(&ink_e2e::alice(), provide_to_tpa, amount, None)
                 .await
                 .expect("calling `provide_to_tpa` failed");
 
-            // Fetch the estimated price for swap
-            let get_est_price_a0_to_psp22_for_swap = build_message::<TradingPairAzeroRef>(
-                tpa_acc_id.clone(),
-            )
-            .call(|trading_pair_azero| {
-                trading_pair_azero.get_est_price_a0_to_psp22_for_swap(100000000000)
-            });
+            // Charlie is not approved to redeem on behalf of Alice
+            let redeem_for = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| trading_pair_azero.redeem_for(get_alice_account_id()));
+            let redeem_for_res = client
+                .call_dry_run(&ink_e2e::charlie(), &redeem_for, 0, None)
+                .await;
+            assert_eq!(
+                redeem_for_res.return_value(),
+                Err(TradingPairErrors::CallerNotRewardOperator)
+            );
 
-            let get_est_price_a0_to_psp22_for_swap_res = client
-                .call(
-                    &ink_e2e::alice(),
-                    get_est_price_a0_to_psp22_for_swap,
-                    0,
-                    None,
-                )
+            // Alice approves Bob as her reward operator
+            let set_reward_operator = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| {
+                    trading_pair_azero.set_reward_operator(get_bob_account_id(), true)
+                });
+            client
+                .call(&ink_e2e::alice(), set_reward_operator, 0, None)
                 .await
-                .expect("get_est_price_a0_to_psp22_for_swap failed");
+                .expect("calling `set_reward_operator` failed");
 
-            let Some(price) = get_est_price_a0_to_psp22_for_swap_res.return_value().ok() else { panic!("failed!") };
+            let is_reward_operator = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| {
+                    trading_pair_azero
+                        .is_reward_operator(get_alice_account_id(), get_bob_account_id())
+                });
+            let is_reward_operator_res = client
+                .call(&ink_e2e::alice(), is_reward_operator, 0, None)
+                .await
+                .expect("is_reward_operator failed");
+            assert!(is_reward_operator_res.return_value());
+
+            // Bob can redeem for Alice, but no day passed so there is nothing to redeem
+            // (and Alice last redeem is not reset)
+            let redeem_for = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| trading_pair_azero.redeem_for(get_alice_account_id()));
+            let redeem_for_res = client
+                .call_dry_run(&ink_e2e::bob(), &redeem_for, 0, None)
+                .await;
+            assert_eq!(
+                redeem_for_res.return_value(),
+                Err(TradingPairErrors::ZeroRedeemableAmount)
+            );
 
-            assert_eq!(price, 999900009999);
+            // Alice revokes Bob
+            let set_reward_operator = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| {
+                    trading_pair_azero.set_reward_operator(get_bob_account_id(), false)
+                });
+            client
+                .call(&ink_e2e::alice(), set_reward_operator, 0, None)
+                .await
+                .expect("calling `set_reward_operator` failed");
 
-            // Perform the swap
-            let swap_a0 = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
-                .call(|trading_pair_azero| trading_pair_azero.swap_a0(price, 1000000000000));
+            let is_reward_operator = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| {
+                    trading_pair_azero
+                        .is_reward_operator(get_alice_account_id(), get_bob_account_id())
+                });
+            let is_reward_operator_res = client
+                .call(&ink_e2e::alice(), is_reward_operator, 0, None)
+                .await
+                .expect("is_reward_operator failed");
+            assert!(!is_reward_operator_res.return_value());
+
+            // A revoked operator can not redeem anymore
+            let redeem_for = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| trading_pair_azero.redeem_for(get_alice_account_id()));
+            let redeem_for_res = client
+                .call_dry_run(&ink_e2e::bob(), &redeem_for, 0, None)
+                .await;
+            assert_eq!(
+                redeem_for_res.return_value(),
+                Err(TradingPairErrors::CallerNotRewardOperator)
+            );
 
-            let amount: u128 = 100000000000;
+            Ok(())
+        }
 
-            client
-                .call(&ink_e2e::alice(), swap_a0, amount, None)
+        /// Tests included in 'compound_works'
+        /// 1. provide_to_pool
+        /// 2. compound (nothing to compound yet)
+        /// 3. get_lp_token_of
+        /// 4. get_a0_balance
+        /// 5. get_psp22_balance
+        /// 6. get_account_overall_staking_rewards
+        ///
+        /// The amounts of a successful compound (swap fees, shares and reserves) are tested
+        /// by `pricing::get_compound_amounts`, as rewards only become redeemable after a day.
+        #[ink_e2e::test(additional_contracts = "../my_psp22/Cargo.toml")]
+        async fn compound_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            // Create a PSP22 contract instance
+            let psp22_constructor = MyPsp22Ref::new(
+                10000000000000000,
+                Some(String::from("TOKEN").into()),
+                Some(String::from("TKN").into()),
+                12,
+            );
+            let psp22_acc_id = client
+                .instantiate("my_psp22", &ink_e2e::alice(), psp22_constructor, 0, None)
                 .await
-                .expect("calling `swap_a0` failed");
+                .expect("instantiate failed")
+                .account_id;
 
-            // Check MyPsp22 balance
-            let psp22_balance_of = build_message::<MyPsp22Ref>(psp22_acc_id.clone())
-                .call(|my_psp22| my_psp22.balance_of(get_alice_account_id()));
+            // Create a TradingPairAzero contract instance
+            let tpa_constructor = TradingPairAzeroRef::new(
+                psp22_acc_id,
+                1000000000000,
+                psp22_acc_id,
+                get_charlie_account_id(),
+                0,
+                get_alice_account_id(),
+            );
+            let tpa_acc_id = client
+                .instantiate(
+                    "trading_pair_azero",
+                    &ink_e2e::alice(),
+                    tpa_constructor,
+                    0,
+                    None,
+                )
+                .await
+                .expect("instantiate failed")
+                .account_id;
 
-            let psp22_balance_of_res = client
-                .call(&ink_e2e::alice(), psp22_balance_of, 0, None)
+            // Approve PSP22 contract to provide liquidity to TradingPairAzero contract
+            let approve_psp22_to_provide_lp = build_message::<MyPsp22Ref>(psp22_acc_id.clone())
+                .call(|my_psp22| my_psp22.approve(tpa_acc_id, 100000000000000));
+            client
+                .call(&ink_e2e::alice(), approve_psp22_to_provide_lp, 0, None)
                 .await
-                .expect("psp22_balance_of failed");
+                .expect("calling `approve_psp22_to_provide_lp` failed");
 
-            let psp22_balance = psp22_balance_of_res.return_value();
+            // Provide liquidity to TradingPairAzero contract
+            let provide_to_tpa = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone()).call(
+                |trading_pair_azero| {
+                    trading_pair_azero.provide_to_pool(
+                        100000000000000,
+                        10000000000000,
+                        31622776600683,
+                        0,
+                    )
+                },
+            );
+            let amount: u128 = 10000000000000;
+            client
+                .call(&ink_e2e::alice(), provide_to_tpa, amount, None)
+                .await
+                .expect("calling `provide_to_tpa` failed");
 
-            assert_eq!(psp22_balance, 9900978120978120);
+            // No day passed since the provision, so there is nothing to compound
+            let compound = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| trading_pair_azero.compound());
+            let compound_res = client
+                .call_dry_run(&ink_e2e::alice(), &compound, 0, None)
+                .await;
+            assert_eq!(
+                compound_res.return_value(),
+                Err(TradingPairErrors::ZeroRedeemableAmount)
+            );
 
-            // Get TradingPairAzero a0 balance
+            // Alice LP shares are unchanged
+            let get_lp_token_of = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| {
+                    trading_pair_azero.get_lp_token_of(get_alice_account_id())
+                });
+            let get_lp_token_of_res = client
+                .call(&ink_e2e::alice(), get_lp_token_of, 0, None)
+                .await
+                .expect("get_lp_token_of failed");
+            assert_eq!(get_lp_token_of_res.return_value(), 31622776600683);
+
+            // The pool reserves are unchanged
             let get_a0_balance = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
                 .call(|trading_pair_azero| trading_pair_azero.get_a0_balance());
-
-            let get_a0_res = client
+            let get_a0_balance_res = client
                 .call(&ink_e2e::alice(), get_a0_balance, 0, None)
                 .await
                 .expect("get_a0_balance failed");
+            assert_eq!(get_a0_balance_res.return_value(), 10001000000000);
 
-            assert_eq!(get_a0_res.return_value(), 10100800000000);
-
-            // Get TradingPairAzero MyPsp22 balance
             let get_psp22_balance = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
                 .call(|trading_pair_azero| trading_pair_azero.get_psp22_balance());
-
-            let get_psp22_res = client
+            let get_psp22_balance_res = client
                 .call(&ink_e2e::alice(), get_psp22_balance, 0, None)
                 .await
                 .expect("get_psp22_balance failed");
+            assert_eq!(get_psp22_balance_res.return_value(), 100000000000000);
+
+            // Nothing was added to Alice overall staking rewards
+            let get_account_overall_staking_rewards =
+                build_message::<TradingPairAzeroRef>(tpa_acc_id.clone()).call(
+                    |trading_pair_azero| {
+                        trading_pair_azero
+                            .get_account_overall_staking_rewards(get_alice_account_id())
+                    },
+                );
+            let get_account_overall_staking_rewards_res = client
+                .call(&ink_e2e::alice(), get_account_overall_staking_rewards, 0, None)
+                .await
+                .expect("get_account_overall_staking_rewards failed");
+            assert_eq!(get_account_overall_staking_rewards_res.return_value(), 0);
 
-            assert_eq!(get_psp22_res.return_value(), 99019899019900);
-
-            // Check MyPsp22 balance for Charlie
-            let psp22_balance_of = build_message::<MyPsp22Ref>(psp22_acc_id.clone())
-                .call(|my_psp22| my_psp22.balance_of(get_charlie_account_id()));
-
-            let psp22_balance_of_res = client
-                .call(&ink_e2e::alice(), psp22_balance_of, 0, None)
-                .await
-                .expect("psp22_balance_of failed");
-
-            let psp22_balance = psp22_balance_of_res.return_value();
-
-            assert_eq!(psp22_balance, 1980001980);
-
-            // Get generated LP fees
-            let get_generated_lp_fees = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
-                .call(|trading_pair_azero| trading_pair_azero.get_generated_lp_fees());
-
-            let get_generated_lp_fees_res = client
-                .call(&ink_e2e::alice(), get_generated_lp_fees, 0, None)
-                .await
-                .expect("get_generated_lp_fees failed");
-
-            let (psp22_fees, a0_fees) = get_generated_lp_fees_res.return_value();
-
-            assert_eq!(psp22_fees, 9900009900);
-            assert_eq!(a0_fees, 0);
-
-            // Get overall generated fees by the contract
-            let get_contract_overall_generated_fee = build_message::<TradingPairAzeroRef>(
-                tpa_acc_id.clone(),
-            )
-            .call(|trading_pair_azero| trading_pair_azero.get_contract_overall_generated_fee());
-
-            let get_contract_overall_generated_fee_res = client
-                .call(
-                    &ink_e2e::alice(),
-                    get_contract_overall_generated_fee,
-                    0,
-                    None,
-                )
-                .await
-                .expect("get_contract_overall_generated_fee failed");
-
-            let (psp22_fees, a0_fees) = get_contract_overall_generated_fee_res.return_value();
-
-            assert_eq!(psp22_fees, 9900009900);
-            assert_eq!(a0_fees, 0);
+            Ok(())
+        }
 
-            // Get MyPsp22 difference by percentage
-            let get_psp22_difference_by_percentage = build_message::<TradingPairAzeroRef>(
-                tpa_acc_id.clone(),
-            )
-            .call(|trading_pair_azero| trading_pair_azero.get_psp22_difference_by_percentage());
+        /// Tests included in 'panx_discount_works'
+        /// 1. get_panx_discount_tiers (default tier)
+        /// 2. set_panx_discount_tiers
+        /// 3. lock_panx (rejected transfer_from, too short lock)
+        /// 4. get_locked_panx
+        /// 5. get_panx_discount_bps
+        /// 6. get_psp22_balance (locked PANX is not part of the reserve)
+        /// 7. unlock_panx
+        #[ink_e2e::test(additional_contracts = "../my_psp22/Cargo.toml")]
+        async fn panx_discount_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            // Create a PSP22 contract instance (used as PANX as well)
+            let psp22_constructor = MyPsp22Ref::new(
+                10000000000000000,
+                Some(String::from("TOKEN").into()),
+                Some(String::from("TKN").into()),
+                12,
+            );
+            let psp22_acc_id = client
+                .instantiate("my_psp22", &ink_e2e::alice(), psp22_constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
 
-            let get_psp22_difference_by_percentage_res = client
-                .call(
+            // Create a TradingPairAzero contract instance with a 1% LP fee
+            let tpa_constructor = TradingPairAzeroRef::new(
+                psp22_acc_id,
+                1000000000000,
+                psp22_acc_id,
+                get_charlie_account_id(),
+                0,
+                get_alice_account_id(),
+            );
+            let tpa_acc_id = client
+                .instantiate(
+                    "trading_pair_azero",
                     &ink_e2e::alice(),
-                    get_psp22_difference_by_percentage,
+                    tpa_constructor,
                     0,
                     None,
                 )
                 .await
-                .expect("get_psp22_difference_by_percentage failed");
+                .expect("instantiate failed")
+                .account_id;
 
-            let Some(psp22_lp_fee_diff) = get_psp22_difference_by_percentage_res.return_value().ok() else {
-                panic!("failed!")
-            };
+            // A 1% LP fee pair halves the LP fee from 3500 locked PANX by default
+            let get_panx_discount_tiers = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| trading_pair_azero.get_panx_discount_tiers());
+            let get_panx_discount_tiers_res = client
+                .call(&ink_e2e::alice(), get_panx_discount_tiers, 0, None)
+                .await
+                .expect("get_panx_discount_tiers failed");
+            assert_eq!(
+                get_panx_discount_tiers_res.return_value(),
+                vec![(3500000000000000, 5000)]
+            );
 
-            assert_eq!(psp22_lp_fee_diff, 9997500600);
+            // Only the admin can set the tiers
+            let set_panx_discount_tiers = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| {
+                    trading_pair_azero.set_panx_discount_tiers(vec![(1000, 2500), (5000, 7500)])
+                });
+            let set_panx_discount_tiers_res = client
+                .call_dry_run(&ink_e2e::bob(), &set_panx_discount_tiers, 0, None)
+                .await;
+            assert_eq!(
+                set_panx_discount_tiers_res.return_value(),
+                Err(TradingPairErrors::CallerNotAdmin)
+            );
 
-            // Withdraw from the pool
-            let withdraw_from_pool = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
-                .call(|trading_pair_azero| trading_pair_azero.withdraw_specific_amount(amount));
+            // Tiers must be ordered by threshold
+            let set_unordered_tiers = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| {
+                    trading_pair_azero.set_panx_discount_tiers(vec![(5000, 2500), (1000, 7500)])
+                });
+            let set_unordered_tiers_res = client
+                .call_dry_run(&ink_e2e::alice(), &set_unordered_tiers, 0, None)
+                .await;
+            assert_eq!(
+                set_unordered_tiers_res.return_value(),
+                Err(TradingPairErrors::InvalidDiscountTiers)
+            );
 
             client
-                .call(&ink_e2e::alice(), withdraw_from_pool, 0, None)
+                .call(&ink_e2e::alice(), set_panx_discount_tiers, 0, None)
                 .await
-                .expect("withdraw_from_pool failed");
+                .expect("calling `set_panx_discount_tiers` failed");
 
-            // Get account overall LP fee rewards
-            let get_account_overall_lp_fee_rewards = build_message::<TradingPairAzeroRef>(
-                tpa_acc_id.clone(),
-            )
-            .call(|trading_pair_azero| {
-                trading_pair_azero.get_account_overall_lp_fee_rewards(get_alice_account_id())
-            });
+            // Locking PANX without an allowance fails and locks nothing
+            let unlock_timestamp: u64 = 4102444800;
+            let lock_panx = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| trading_pair_azero.lock_panx(5000, unlock_timestamp));
+            let lock_panx_res = client
+                .call_dry_run(&ink_e2e::alice(), &lock_panx, 0, None)
+                .await;
+            assert_eq!(
+                lock_panx_res.return_value(),
+                Err(TradingPairErrors::PSP22TransferFromFailed)
+            );
 
-            let get_account_overall_lp_fee_rewards_res = client
-                .call(
-                    &ink_e2e::alice(),
-                    get_account_overall_lp_fee_rewards,
-                    0,
-                    None,
-                )
+            // Approve the pair over Alice PANX
+            let approve_panx = build_message::<MyPsp22Ref>(psp22_acc_id.clone())
+                .call(|my_psp22| my_psp22.approve(tpa_acc_id, 5000));
+            client
+                .call(&ink_e2e::alice(), approve_panx, 0, None)
                 .await
-                .expect("get_account_overall_lp_fee_rewards failed");
+                .expect("calling `approve_panx` failed");
 
-            let (psp22_fees, a0_fees) = get_account_overall_lp_fee_rewards_res.return_value();
+            // A lock shorter than the minimal lock duration is rejected
+            let get_current_timestamp = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| trading_pair_azero.get_current_timestamp());
+            let current_timestamp = client
+                .call(&ink_e2e::alice(), get_current_timestamp, 0, None)
+                .await
+                .expect("get_current_timestamp failed")
+                .return_value();
+            let lock_panx_too_short = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| {
+                    trading_pair_azero.lock_panx(5000, current_timestamp + 60)
+                });
+            let lock_panx_too_short_res = client
+                .call_dry_run(&ink_e2e::alice(), &lock_panx_too_short, 0, None)
+                .await;
+            assert_eq!(
+                lock_panx_too_short_res.return_value(),
+                Err(TradingPairErrors::InvalidUnlockTimestamp)
+            );
 
-            assert_eq!(psp22_fees, 990000);
-            assert_eq!(a0_fees, 0);
+            // Lock 5000 PANX
+            client
+                .call(&ink_e2e::alice(), lock_panx, 0, None)
+                .await
+                .expect("calling `lock_panx` failed");
 
-            // Get PSP22 LP fee tokens
-            let get_psp22_lp_fee_tokens = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
-                .call(|trading_pair_azero| trading_pair_azero.get_psp22_lp_fee_tokens(amount));
+            let get_locked_panx = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| {
+                    trading_pair_azero.get_locked_panx(get_alice_account_id())
+                });
+            let get_locked_panx_res = client
+                .call(&ink_e2e::alice(), get_locked_panx, 0, None)
+                .await
+                .expect("get_locked_panx failed");
+            assert_eq!(get_locked_panx_res.return_value(), (5000, unlock_timestamp));
 
-            let get_psp22_lp_fee_tokens_res = client
-                .call(&ink_e2e::alice(), get_psp22_lp_fee_tokens, 0, None)
+            // Alice reached the highest tier
+            let get_panx_discount_bps = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| {
+                    trading_pair_azero.get_panx_discount_bps(get_alice_account_id())
+                });
+            let get_panx_discount_bps_res = client
+                .call(&ink_e2e::alice(), get_panx_discount_bps, 0, None)
                 .await
-                .expect("get_psp22_lp_fee_tokens failed");
+                .expect("get_panx_discount_bps failed");
+            assert_eq!(get_panx_discount_bps_res.return_value(), 7500);
 
-            let Some(psp22_lp_fee) = get_psp22_lp_fee_tokens_res.return_value().ok() else {
-                panic!("failed!")
-            };
+            // The locked PANX is not part of the PANX reserve of the pair
+            let get_psp22_balance = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| trading_pair_azero.get_psp22_balance());
+            let get_psp22_balance_res = client
+                .call(&ink_e2e::alice(), get_psp22_balance, 0, None)
+                .await
+                .expect("get_psp22_balance failed");
+            assert_eq!(get_psp22_balance_res.return_value(), 0);
+
+            // The PANX can not be unlocked before the unlock timestamp
+            let unlock_panx = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| trading_pair_azero.unlock_panx());
+            let unlock_panx_res = client
+                .call_dry_run(&ink_e2e::alice(), &unlock_panx, 0, None)
+                .await;
+            assert_eq!(
+                unlock_panx_res.return_value(),
+                Err(TradingPairErrors::PanxStillLocked)
+            );
 
-            assert_eq!(psp22_lp_fee, 990000);
+            // Bob has nothing to unlock
+            let unlock_panx_res = client
+                .call_dry_run(&ink_e2e::bob(), &unlock_panx, 0, None)
+                .await;
+            assert_eq!(
+                unlock_panx_res.return_value(),
+                Err(TradingPairErrors::ZeroLockedPanx)
+            );
 
             Ok(())
         }
 
-        /// Tests included in 'transfer_lp_tokens_works'
-        /// 1. provide_to_tpa
-        /// 2. get_lp_token_of
-        /// 3. transfer_lp_tokens
-        /// 4. get_lp_token_of
+        /// Tests included in 'lp_token_events_works'
+        /// 1. provide_to_pool
+        /// 2. transfer (Transfer event, self-transfer and zero value no-ops)
+        /// 3. approve (Approval event, self-approval no-op)
+        /// 4. transfer_from (Approval and Transfer events, same account no-op)
+        /// 5. balance_of
         #[ink_e2e::test(additional_contracts = "../my_psp22/Cargo.toml")]
-        async fn transfer_lp_tokens_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
-            // Instantiate MyPsp22 contract
+        async fn lp_token_events_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            // Create a PSP22 contract instance
             let psp22_constructor = MyPsp22Ref::new(
                 10000000000000000,
                 Some(String::from("TOKEN").into()),
@@ -4315,12 +6222,14 @@ pub mod trading_pair_azero {
                 .expect("instantiate failed")
                 .account_id;
 
-            // Instantiate TradingPairAzero contract
+            // Create a TradingPairAzero contract instance
             let tpa_constructor = TradingPairAzeroRef::new(
-                psp22_acc_id.clone(),
+                psp22_acc_id,
                 1000000000000,
-                psp22_acc_id.clone(),
+                psp22_acc_id,
                 get_charlie_account_id(),
+                0,
+                get_alice_account_id(),
             );
             let tpa_acc_id = client
                 .instantiate(
@@ -4334,113 +6243,199 @@ pub mod trading_pair_azero {
                 .expect("instantiate failed")
                 .account_id;
 
-            // Approve MyPsp22 contract to provide LP tokens to TradingPairAzero
+            // Approve PSP22 contract to provide liquidity to TradingPairAzero contract
             let approve_psp22_to_provide_lp = build_message::<MyPsp22Ref>(psp22_acc_id.clone())
-                .call(|my_psp22| my_psp22.approve(tpa_acc_id.clone(), 100000000000000));
+                .call(|my_psp22| my_psp22.approve(tpa_acc_id, 100000000000000));
             client
                 .call(&ink_e2e::alice(), approve_psp22_to_provide_lp, 0, None)
                 .await
                 .expect("calling `approve_psp22_to_provide_lp` failed");
 
-            // Provide LP tokens to TradingPairAzero
+            // Provide liquidity to TradingPairAzero contract
             let provide_to_tpa = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone()).call(
                 |trading_pair_azero| {
                     trading_pair_azero.provide_to_pool(
                         100000000000000,
-                        1000000000000000,
-                        500000000000,
+                        10000000000000,
+                        31622776600683,
+                        0,
                     )
                 },
             );
             let amount: u128 = 10000000000000;
-            client
+            let provide_to_tpa_res = client
                 .call(&ink_e2e::alice(), provide_to_tpa, amount, None)
                 .await
                 .expect("calling `provide_to_tpa` failed");
 
-            // Check LP token balance for Alice
-            let get_lp_share_balance = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
-                .call(|trading_pair_azero| {
-                    trading_pair_azero.get_lp_token_of(get_alice_account_id())
-                });
-            let get_lp_share_res = client
-                .call(&ink_e2e::alice(), get_lp_share_balance, 0, None)
-                .await
-                .expect("get_lp_share_balance failed");
-
-            assert_eq!(get_lp_share_res.return_value(), 1000000000000000);
+            // Minting the LP shares emits a Transfer event from nobody
+            let events = get_pair_events(&provide_to_tpa_res, tpa_acc_id);
+            assert!(events.iter().any(|event| matches!(
+                event,
+                Event::Transfer(Transfer { from: None, to: Some(to), value: 31622776600683 })
+                    if *to == get_alice_account_id()
+            )));
 
-            // Transfer LP tokens from Alice to Bob
-            let transfer_lp_tokens = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone()).call(
+            // Transferring LP tokens emits a Transfer event
+            let transfer = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone()).call(
                 |trading_pair_azero| {
-                    trading_pair_azero.transfer_lp_tokens(get_bob_account_id(), 500000000000000)
+                    trading_pair_azero.transfer(get_bob_account_id(), 1000, vec![])
                 },
             );
-            client
-                .call(&ink_e2e::alice(), transfer_lp_tokens, 0, None)
-                .await
-                .expect("calling `transfer_lp_tokens` failed");
-
-            // Check LP token balance for Alice after transfer
-            let get_lp_share_balance = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
-                .call(|trading_pair_azero| {
-                    trading_pair_azero.get_lp_token_of(get_alice_account_id())
-                });
-            let get_lp_share_res = client
-                .call(&ink_e2e::alice(), get_lp_share_balance, 0, None)
+            let transfer_res = client
+                .call(&ink_e2e::alice(), transfer, 0, None)
+                .await
+                .expect("calling `transfer` failed");
+            let events = get_pair_events(&transfer_res, tpa_acc_id);
+            assert_eq!(events.len(), 1);
+            assert!(matches!(
+                &events[0],
+                Event::Transfer(Transfer { from: Some(from), to: Some(to), value: 1000 })
+                    if *from == get_alice_account_id() && *to == get_bob_account_id()
+            ));
+
+            // Transferring to yourself is a no-op without events
+            let self_transfer = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone()).call(
+                |trading_pair_azero| {
+                    trading_pair_azero.transfer(get_alice_account_id(), 1000, vec![])
+                },
+            );
+            let self_transfer_res = client
+                .call(&ink_e2e::alice(), self_transfer, 0, None)
                 .await
-                .expect("get_lp_share_balance failed");
+                .expect("calling `self_transfer` failed");
+            assert_eq!(self_transfer_res.return_value(), Ok(()));
+            assert!(get_pair_events(&self_transfer_res, tpa_acc_id).is_empty());
 
-            assert_eq!(get_lp_share_res.return_value(), 500000000000000);
-
-            // Check LP token balance for Bob after transfer
-            let get_lp_share_balance = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+            // Transferring nothing is a no-op without events
+            let zero_transfer = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone()).call(
+                |trading_pair_azero| trading_pair_azero.transfer(get_bob_account_id(), 0, vec![]),
+            );
+            let zero_transfer_res = client
+                .call(&ink_e2e::alice(), zero_transfer, 0, None)
+                .await
+                .expect("calling `zero_transfer` failed");
+            assert_eq!(zero_transfer_res.return_value(), Ok(()));
+            assert!(get_pair_events(&zero_transfer_res, tpa_acc_id).is_empty());
+
+            // Approving yourself is a no-op without events
+            let self_approve = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| trading_pair_azero.approve(get_alice_account_id(), 500));
+            let self_approve_res = client
+                .call(&ink_e2e::alice(), self_approve, 0, None)
+                .await
+                .expect("calling `self_approve` failed");
+            assert!(get_pair_events(&self_approve_res, tpa_acc_id).is_empty());
+
+            // Approving Bob emits an Approval event
+            let approve = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| trading_pair_azero.approve(get_bob_account_id(), 500));
+            let approve_res = client
+                .call(&ink_e2e::alice(), approve, 0, None)
+                .await
+                .expect("calling `approve` failed");
+            let events = get_pair_events(&approve_res, tpa_acc_id);
+            assert_eq!(events.len(), 1);
+            assert!(matches!(
+                &events[0],
+                Event::Approval(Approval { owner, spender, value: 500 })
+                    if *owner == get_alice_account_id() && *spender == get_bob_account_id()
+            ));
+
+            // Bob transferring from Alice to Alice is a no-op without events
+            let transfer_from_same = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
                 .call(|trading_pair_azero| {
-                    trading_pair_azero.get_lp_token_of(get_bob_account_id())
+                    trading_pair_azero.transfer_from(
+                        get_alice_account_id(),
+                        get_alice_account_id(),
+                        200,
+                        vec![],
+                    )
                 });
-            let get_lp_share_res = client
-                .call(&ink_e2e::alice(), get_lp_share_balance, 0, None)
+            let transfer_from_same_res = client
+                .call(&ink_e2e::bob(), transfer_from_same, 0, None)
                 .await
-                .expect("get failed");
+                .expect("calling `transfer_from_same` failed");
+            assert_eq!(transfer_from_same_res.return_value(), Ok(()));
+            assert!(get_pair_events(&transfer_from_same_res, tpa_acc_id).is_empty());
 
-            assert_eq!(get_lp_share_res.return_value(), 500000000000000);
+            // Bob transferring from Alice emits the new allowance and the transfer
+            let transfer_from = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone()).call(
+                |trading_pair_azero| {
+                    trading_pair_azero.transfer_from(
+                        get_alice_account_id(),
+                        get_charlie_account_id(),
+                        200,
+                        vec![],
+                    )
+                },
+            );
+            let transfer_from_res = client
+                .call(&ink_e2e::bob(), transfer_from, 0, None)
+                .await
+                .expect("calling `transfer_from` failed");
+            let events = get_pair_events(&transfer_from_res, tpa_acc_id);
+            assert_eq!(events.len(), 2);
+            assert!(matches!(
+                &events[0],
+                Event::Approval(Approval { owner, spender, value: 300 })
+                    if *owner == get_alice_account_id() && *spender == get_bob_account_id()
+            ));
+            assert!(matches!(
+                &events[1],
+                Event::Transfer(Transfer { from: Some(from), to: Some(to), value: 200 })
+                    if *from == get_alice_account_id() && *to == get_charlie_account_id()
+            ));
+
+            // The no-ops did not move any LP tokens
+            let balance_of = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| trading_pair_azero.balance_of(get_bob_account_id()));
+            let balance_of_res = client
+                .call(&ink_e2e::alice(), balance_of, 0, None)
+                .await
+                .expect("balance_of failed");
+            assert_eq!(balance_of_res.return_value(), 1000);
+
+            let balance_of = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| trading_pair_azero.balance_of(get_alice_account_id()));
+            let balance_of_res = client
+                .call(&ink_e2e::alice(), balance_of, 0, None)
+                .await
+                .expect("balance_of failed");
+            assert_eq!(balance_of_res.return_value(), 31622776600683 - 1000 - 200);
 
             Ok(())
         }
 
-        /// Tests included in 'transfer_lp_tokens_from_to_works'
-        /// 1. provide_to_pool
-        /// 2. get_lp_token_of
-        /// 3. approve_lp_tokens
-        /// 4. get_lp_tokens_allowance
-        /// 5. transfer_lp_tokens_from_to
-        /// 6.
+        /// Tests included in 'deployer_lp_lock_works'
+        /// 1. provide_to_pool (twice by the deployer before the LP lock timestamp)
+        /// 2. get_locks
+        /// 3. get_locked_lp_of
         #[ink_e2e::test(additional_contracts = "../my_psp22/Cargo.toml")]
-        async fn transfer_lp_tokens_from_to_works(
-            mut client: ink_e2e::Client<C, E>,
-        ) -> E2EResult<()> {
-            // Instantiate MyPsp22 contract
+        async fn deployer_lp_lock_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            // Create a PSP22 contract instance
             let psp22_constructor = MyPsp22Ref::new(
                 10000000000000000,
                 Some(String::from("TOKEN").into()),
                 Some(String::from("TKN").into()),
                 12,
             );
-
             let psp22_acc_id = client
                 .instantiate("my_psp22", &ink_e2e::alice(), psp22_constructor, 0, None)
                 .await
                 .expect("instantiate failed")
                 .account_id;
 
-            // Instantiate TradingPairAzero contract
+            // Create a TradingPairAzero contract instance deployed by Alice with an LP lock far in the future
+            let lp_lock_timestamp: u64 = 4102444800;
             let tpa_constructor = TradingPairAzeroRef::new(
                 psp22_acc_id,
                 1000000000000,
                 psp22_acc_id,
                 get_charlie_account_id(),
+                lp_lock_timestamp,
+                get_alice_account_id(),
             );
-
             let tpa_acc_id = client
                 .instantiate(
                     "trading_pair_azero",
@@ -4453,114 +6448,70 @@ pub mod trading_pair_azero {
                 .expect("instantiate failed")
                 .account_id;
 
-            // Approve MyPsp22 to provide LP tokens to TradingPairAzero
+            // Approve PSP22 contract to provide liquidity to TradingPairAzero contract
             let approve_psp22_to_provide_lp = build_message::<MyPsp22Ref>(psp22_acc_id.clone())
-                .call(|my_psp22| my_psp22.approve(tpa_acc_id, 100000000000000));
-
+                .call(|my_psp22| my_psp22.approve(tpa_acc_id, 200000000000000));
             client
                 .call(&ink_e2e::alice(), approve_psp22_to_provide_lp, 0, None)
                 .await
                 .expect("calling `approve_psp22_to_provide_lp` failed");
 
-            // Provide LP tokens to TradingPairAzero
+            // Provide liquidity to TradingPairAzero contract
             let provide_to_tpa = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone()).call(
                 |trading_pair_azero| {
                     trading_pair_azero.provide_to_pool(
                         100000000000000,
-                        1000000000000000,
-                        500000000000,
+                        10000000000000,
+                        31622776600683,
+                        0,
                     )
                 },
             );
-
             let amount: u128 = 10000000000000;
-
             client
                 .call(&ink_e2e::alice(), provide_to_tpa, amount, None)
                 .await
                 .expect("calling `provide_to_tpa` failed");
 
-            // Get LP share balance of Alice
-            let get_lp_share_balance = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
-                .call(|trading_pair_azero| {
-                    trading_pair_azero.get_lp_token_of(get_alice_account_id())
-                });
-
-            let get_lp_share_res = client
-                .call(&ink_e2e::alice(), get_lp_share_balance, 0, None)
-                .await
-                .expect("get_lp_share_balance failed");
-
-            assert_eq!(get_lp_share_res.return_value(), 1000000000000000);
-
-            // Approve LP tokens for Bob
-            let approve_lp_tokens = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone()).call(
-                |trading_pair_azero| {
-                    trading_pair_azero.approve_lp_tokens(get_bob_account_id(), 500000000000000)
-                },
-            );
-
-            client
-                .call(&ink_e2e::alice(), approve_lp_tokens, 0, None)
-                .await
-                .expect("calling `approve_lp_tokens` failed");
-
-            // Get LP tokens allowance from Alice to Bob
-            let get_lp_tokens_allowance = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+            // Provide the same amounts again, at the pool ratio
+            let provide_to_tpa_again = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
                 .call(|trading_pair_azero| {
-                    trading_pair_azero
-                        .get_lp_tokens_allowance(get_alice_account_id(), get_bob_account_id())
+                    trading_pair_azero.provide_to_pool(
+                        100000000000000,
+                        10000000000000,
+                        31622776601683,
+                        0,
+                    )
                 });
-
-            let get_lp_tokens_allowance_res = client
-                .call(&ink_e2e::alice(), get_lp_tokens_allowance, 0, None)
-                .await
-                .expect("get_lp_tokens_allowance failed");
-
-            assert_eq!(get_lp_tokens_allowance_res.return_value(), 500000000000000);
-
-            // Transfer LP tokens from Alice to Bob
-            let transfer_lp_tokens_from_to = build_message::<TradingPairAzeroRef>(
-                tpa_acc_id.clone(),
-            )
-            .call(|trading_pair_azero| {
-                trading_pair_azero.transfer_lp_tokens_from_to(
-                    get_alice_account_id(),
-                    get_bob_account_id(),
-                    500000000000000,
-                )
-            });
-
             client
-                .call(&ink_e2e::alice(), transfer_lp_tokens_from_to, 0, None)
+                .call(&ink_e2e::alice(), provide_to_tpa_again, amount, None)
                 .await
-                .expect("calling `transfer_lp_tokens_from_to` failed");
-
-            // Get LP share balance of Alice after transfer
-            let get_lp_share_balance = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
-                .call(|trading_pair_azero| {
-                    trading_pair_azero.get_lp_token_of(get_alice_account_id())
-                });
+                .expect("calling `provide_to_tpa_again` failed");
 
-            let get_lp_share_res = client
-                .call(&ink_e2e::alice(), get_lp_share_balance, 0, None)
+            // Both deposits are locked in a single deployer lock
+            let get_locks = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+                .call(|trading_pair_azero| trading_pair_azero.get_locks(get_alice_account_id()));
+            let get_locks_res = client
+                .call(&ink_e2e::alice(), get_locks, 0, None)
                 .await
-                .expect("get failed");
-
-            assert_eq!(get_lp_share_res.return_value(), 500000000000000);
+                .expect("get_locks failed");
+            assert_eq!(
+                get_locks_res.return_value(),
+                vec![(31622776600683 + 31622776601683, lp_lock_timestamp)]
+            );
 
-            // Get LP share balance of Bob after transfer
-            let get_lp_share_balance = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
+            let get_locked_lp_of = build_message::<TradingPairAzeroRef>(tpa_acc_id.clone())
                 .call(|trading_pair_azero| {
-                    trading_pair_azero.get_lp_token_of(get_bob_account_id())
+                    trading_pair_azero.get_locked_lp_of(get_alice_account_id())
                 });
-
-            let get_lp_share_res = client
-                .call(&ink_e2e::alice(), get_lp_share_balance, 0, None)
+            let get_locked_lp_of_res = client
+                .call(&ink_e2e::alice(), get_locked_lp_of, 0, None)
                 .await
-                .expect("get failed");
-
-            assert_eq!(get_lp_share_res.return_value(), 500000000000000);
+                .expect("get_locked_lp_of failed");
+            assert_eq!(
+                get_locked_lp_of_res.return_value(),
+                31622776600683 + 31622776601683
+            );
 
             Ok(())
         }
@@ -0,0 +1,844 @@
+//! Pure pricing math of the AZERO/PSP22 trading pair.
+//!
+//! Every amount the pair quotes or executes (swap outputs, fees, LP shares and
+//! withdrawals) is computed by the functions of this module, so that the
+//! `get_est_price_*` quotes and the swap / liquidity messages always agree.
+//!
+//! LP fees are percentages scaled by `PRECISION` (`1 * PRECISION` is 1%), the
+//! same way the pair `fee` field is stored. All functions return `None` on
+//! overflow or division by zero.
+
+use primitive_types::U256;
+
+/// Scaling factor of LP fees and percentages (`1 * PRECISION` is 1%).
+pub const PRECISION: u128 = 1_000_000_000_000;
+
+/// 100% in `PRECISION` units.
+pub const ONE_HUNDRED_PERCENT: u128 = 100 * PRECISION;
+
+/// Denominator of the PANX discount tiers (10000 bps == 100%).
+pub const DISCOUNT_BPS_DENOMINATOR: u128 = 10_000;
+
+/// Traders fee sent to the vault, in thousandths of the swapped amount.
+pub const TRADERS_FEE: u128 = 2;
+
+/// LP shares permanently locked on the first deposit of a pool, so that the share
+/// price cannot be inflated by a tiny first deposit.
+pub const MINIMUM_LIQUIDITY: u128 = 1000;
+
+/// Converts a `U256` back to a `u128`, returning `None` if it does not fit.
+fn to_balance(value: U256) -> Option<u128> {
+    if value > U256::from(u128::MAX) {
+        return None
+    }
+
+    Some(value.as_u128())
+}
+
+/// Returns `fee` reduced by `discount_bps` basis points of itself.
+pub fn discounted_fee(fee: u128, discount_bps: u16) -> u128 {
+    let discount_bps = (discount_bps as u128).min(DISCOUNT_BPS_DENOMINATOR);
+
+    fee - fee * discount_bps / DISCOUNT_BPS_DENOMINATOR
+}
+
+/// Returns the default PANX holders discount (in bps) of a pair with the given
+/// LP fee: half of the fee up to a 1.4% fee, and 1% of the fee above it (rounded
+/// to the nearest bps).
+pub fn default_panx_discount_bps(fee: u128) -> u16 {
+    const HALF_FEE_LIMIT: u128 = 14 * PRECISION / 10;
+
+    if fee <= HALF_FEE_LIMIT {
+        return (DISCOUNT_BPS_DENOMINATOR / 2) as u16
+    }
+
+    ((PRECISION * DISCOUNT_BPS_DENOMINATOR + fee / 2) / fee) as u16
+}
+
+/// Returns the discount (in bps) of the highest of the `tiers` (ordered by locked
+/// PANX threshold) reached by `locked_amount`, or 0 if the lock expired at `now`.
+pub fn panx_discount_bps(
+    tiers: &[(u128, u16)],
+    locked_amount: u128,
+    unlock_timestamp: u64,
+    now: u64,
+) -> u16 {
+    // PANX which can already be unlocked does not count
+    if unlock_timestamp <= now {
+        return 0
+    }
+
+    let mut discount_bps: u16 = 0;
+
+    for (threshold, tier_discount_bps) in tiers.iter() {
+        if locked_amount >= *threshold {
+            discount_bps = *tier_discount_bps;
+        }
+    }
+
+    discount_bps
+}
+
+/// Returns `amount_in * (100 - fee%)`, i.e. the amount in after the LP fee
+/// scaled by 100.
+fn amount_in_with_lp_fee(amount_in: u128, fee: u128) -> Option<U256> {
+    let fee_multiplier = ONE_HUNDRED_PERCENT.checked_sub(fee)?;
+
+    Some(U256::from(amount_in).checked_mul(U256::from(fee_multiplier))? / PRECISION)
+}
+
+/// Returns the amount out of a swap of `amount_in` against the given reserves,
+/// with the LP fee reducted from the amount in.
+pub fn get_amount_out(
+    amount_in: u128,
+    reserve_in: u128,
+    reserve_out: u128,
+    fee: u128,
+) -> Option<u128> {
+    let amount_in_with_lp_fee = amount_in_with_lp_fee(amount_in, fee)?;
+
+    let numerator = amount_in_with_lp_fee.checked_mul(U256::from(reserve_out))?;
+
+    let denominator = U256::from(reserve_in)
+        .checked_mul(U256::from(100))?
+        .checked_add(amount_in_with_lp_fee)?;
+
+    to_balance(numerator.checked_div(denominator)?)
+}
+
+/// Returns the minimal amount in of a swap giving at least `amount_out`
+/// against the given reserves, with the LP fee reducted from the amount in.
+///
+/// Returns `None` if `amount_out` is not lower than `reserve_out`.
+pub fn get_amount_in(
+    amount_out: u128,
+    reserve_in: u128,
+    reserve_out: u128,
+    fee: u128,
+) -> Option<u128> {
+    let remaining_reserve_out = U256::from(reserve_out.checked_sub(amount_out)?);
+
+    if remaining_reserve_out.is_zero() {
+        return None
+    }
+
+    // amount in after the LP fee (scaled by 100) needed for `amount_out`, rounded up
+    let numerator = U256::from(reserve_in)
+        .checked_mul(U256::from(100))?
+        .checked_mul(U256::from(amount_out))?;
+    let amount_in_with_lp_fee = ceil_div(numerator, remaining_reserve_out)?;
+
+    // amount in before the LP fee, rounded up
+    let fee_multiplier = U256::from(ONE_HUNDRED_PERCENT.checked_sub(fee)?);
+    let amount_in = ceil_div(
+        amount_in_with_lp_fee.checked_mul(U256::from(PRECISION))?,
+        fee_multiplier,
+    )?;
+
+    to_balance(amount_in)
+}
+
+/// Returns `numerator / denominator` rounded up.
+fn ceil_div(numerator: U256, denominator: U256) -> Option<U256> {
+    let quotient = numerator.checked_div(denominator)?;
+
+    if (quotient * denominator) == numerator {
+        Some(quotient)
+    } else {
+        quotient.checked_add(U256::from(1))
+    }
+}
+
+/// Returns the amount out the same `amount_in` would give right after a swap
+/// of `amount_in` against the given reserves.
+pub fn get_amount_out_after_swap(
+    amount_in: u128,
+    reserve_in: u128,
+    reserve_out: u128,
+    fee: u128,
+) -> Option<u128> {
+    let amount_out = get_amount_out(amount_in, reserve_in, reserve_out, fee)?;
+
+    get_amount_out(
+        amount_in,
+        reserve_in.checked_add(amount_in)?,
+        reserve_out.checked_sub(amount_out)?,
+        fee,
+    )
+}
+
+/// Returns the price impact of a swap of `amount_in` against the given
+/// reserves: the percentage difference (scaled by `PRECISION`) between the
+/// amount out at the current spot price and the actual amount out.
+pub fn get_price_impact(
+    amount_in: u128,
+    reserve_in: u128,
+    reserve_out: u128,
+    fee: u128,
+) -> Option<u128> {
+    let amount_in_with_lp_fee = amount_in_with_lp_fee(amount_in, fee)?;
+
+    let spot_amount_out = to_balance(
+        amount_in_with_lp_fee
+            .checked_mul(U256::from(reserve_out))?
+            .checked_div(U256::from(reserve_in).checked_mul(U256::from(100))?)?,
+    )?;
+
+    let amount_out = get_amount_out(amount_in, reserve_in, reserve_out, fee)?;
+
+    if amount_out == 0 {
+        return Some(0)
+    }
+
+    percentage_difference(spot_amount_out, amount_out)
+}
+
+/// Returns the traders fee taken from `amount` and sent to the vault.
+pub fn get_traders_fee(amount: u128) -> Option<u128> {
+    to_balance(U256::from(amount).checked_mul(U256::from(TRADERS_FEE))? / 1000)
+}
+
+/// Returns the LP fee taken from `amount` and added to the LP fee vault.
+///
+/// Only the whole percents of `fee` are charged here.
+pub fn get_lp_fee(amount: u128, fee: u128) -> Option<u128> {
+    let actual_lp_fee = (fee / PRECISION).checked_mul(10)?;
+
+    to_balance(U256::from(amount).checked_mul(U256::from(actual_lp_fee))? / 1000)
+}
+
+/// Splits the amount out of a swap into the amount given to the trader, the
+/// traders fee sent to the vault and the LP fee added to the LP fee vault.
+pub fn split_amount_out(amount_out: u128, fee: u128) -> Option<(u128, u128, u128)> {
+    let traders_fee = get_traders_fee(amount_out)?;
+
+    let lp_fee = get_lp_fee(amount_out, fee)?;
+
+    let amount_for_trader = amount_out.checked_sub(traders_fee.checked_add(lp_fee)?)?;
+
+    Some((amount_for_trader, traders_fee, lp_fee))
+}
+
+/// Returns the LP shares given to the first depositor of a pool: the geometric mean
+/// of the deposited amounts, minus the `MINIMUM_LIQUIDITY` locked in the pool.
+///
+/// Returns `Some(0)` if the deposit does not exceed the minimum liquidity.
+pub fn get_initial_shares(a0_amount: u128, psp22_amount: u128) -> Option<u128> {
+    let shares =
+        to_balance(U256::from(a0_amount).checked_mul(U256::from(psp22_amount))?.integer_sqrt())?;
+
+    Some(shares.saturating_sub(MINIMUM_LIQUIDITY))
+}
+
+/// Returns the LP shares minted for a deposit into a pool with the given
+/// reserves and LP total supply.
+pub fn get_deposit_shares(
+    a0_amount: u128,
+    psp22_amount: u128,
+    a0_reserve: u128,
+    psp22_reserve: u128,
+    total_supply: u128,
+) -> Option<u128> {
+    let a0_shares = get_share_of(a0_amount, total_supply, a0_reserve)?;
+
+    let psp22_shares = get_share_of(psp22_amount, total_supply, psp22_reserve)?;
+
+    Some(a0_shares.min(psp22_shares))
+}
+
+/// Returns `amount * total / part`, i.e. the part of `total` that `amount`
+/// represents out of `part`.
+///
+/// Used for both LP shares to mint and reserves to give back for burnt shares
+/// (`get_share_of(shares, reserve, total_supply)`).
+pub fn get_share_of(amount: u128, total: u128, part: u128) -> Option<u128> {
+    to_balance(
+        U256::from(amount)
+            .checked_mul(U256::from(total))?
+            .checked_div(U256::from(part))?,
+    )
+}
+
+/// Returns the amount of the other token to deposit alongside `amount` to
+/// match the pool ratio of `reserve` to `other_reserve`.
+pub fn get_deposit_amount_for_ratio(
+    amount: u128,
+    reserve: u128,
+    other_reserve: u128,
+) -> Option<u128> {
+    get_share_of(amount, other_reserve, reserve)
+}
+
+/// Returns the part of `amount` to swap against `reserve_in` so that the swap
+/// output and the rest of `amount` can be provided at the post-swap pool ratio.
+pub fn get_swap_amount_for_deposit(amount: u128, reserve_in: u128, fee: u128) -> Option<u128> {
+    // 100% in fee units of 0.001%
+    const FEE_DENOMINATOR: u128 = 100_000;
+
+    // share of the amount in that stays after the LP fee, in fee units of 0.001%
+    let fee_multiplier = U256::from(FEE_DENOMINATOR.checked_sub(fee / 10u128.pow(9))?);
+
+    if fee_multiplier.is_zero() {
+        return None
+    }
+
+    let reserve = U256::from(reserve_in);
+
+    // reserve * (1 + fee multiplier)
+    let reserve_term = reserve.checked_mul(U256::from(FEE_DENOMINATOR) + fee_multiplier)?;
+
+    // (reserve * (1 + fee multiplier))^2 + 4 * amount * reserve * fee multiplier
+    let discriminant = reserve_term.checked_mul(reserve_term)?.checked_add(
+        U256::from(4u128 * FEE_DENOMINATOR)
+            .checked_mul(U256::from(amount))?
+            .checked_mul(reserve)?
+            .checked_mul(fee_multiplier)?,
+    )?;
+
+    to_balance(
+        (discriminant.integer_sqrt() - reserve_term).checked_div(U256::from(2) * fee_multiplier)?,
+    )
+}
+
+/// Amounts of a swap against the pool, as executed by the pair.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SwapAmounts {
+    /// Amount out the slippage is validated against.
+    pub amount_out_before_fees: u128,
+    /// Amount out given to the trader.
+    pub amount_out: u128,
+    /// Traders fee taken from the amount out and sent to the vault.
+    pub vault_fee_out: u128,
+    /// LP fee taken from the amount out and added to the LP fee vault.
+    pub lp_fee: u128,
+    /// Traders fee taken from the amount in and sent to the vault.
+    pub vault_fee_in: u128,
+    /// Reserve of the input token after the swap.
+    pub new_reserve_in: u128,
+    /// Reserve of the output token after the swap.
+    pub new_reserve_out: u128,
+}
+
+/// Returns the amounts of a swap of `amount_in` against the given reserves.
+///
+/// `applied_fee` is the LP fee after the trader PANX discount, used for the
+/// amount out, while the LP fee vault share is taken with the pair `fee`.
+pub fn get_swap_amounts(
+    amount_in: u128,
+    reserve_in: u128,
+    reserve_out: u128,
+    fee: u128,
+    applied_fee: u128,
+) -> Option<SwapAmounts> {
+    let amount_out_before_fees = get_amount_out(amount_in, reserve_in, reserve_out, applied_fee)?;
+
+    let (amount_out, vault_fee_out, lp_fee) = split_amount_out(amount_out_before_fees, fee)?;
+
+    let vault_fee_in = get_traders_fee(amount_in)?;
+
+    // the amount in stays in the pool, except for the traders fee
+    let new_reserve_in = reserve_in.checked_add(amount_in - vault_fee_in)?;
+
+    // the LP fee stays in the pool, the rest of the amount out leaves it
+    let new_reserve_out = reserve_out - amount_out - vault_fee_out;
+
+    Some(SwapAmounts {
+        amount_out_before_fees,
+        amount_out,
+        vault_fee_out,
+        lp_fee,
+        vault_fee_in,
+        new_reserve_in,
+        new_reserve_out,
+    })
+}
+
+/// Amounts of compounding PSP22 staking rewards back into the pool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompoundAmounts {
+    /// Part of the PSP22 rewards swapped into AZERO.
+    pub psp22_amount_to_swap: u128,
+    /// The swap of `psp22_amount_to_swap` PSP22 tokens into AZERO.
+    pub swap: SwapAmounts,
+    /// PSP22 tokens provided alongside the swapped AZERO.
+    pub psp22_amount_to_provide: u128,
+    /// LP shares minted for the provided amounts.
+    pub shares: u128,
+}
+
+/// Returns the amounts of compounding `amount` PSP22 rewards into a pool with
+/// the given reserves (without the rewards) and LP total supply: part of the
+/// rewards is swapped into AZERO exactly like a trader swap, and the swap
+/// output is provided with the rest of the rewards at the post-swap ratio.
+pub fn get_compound_amounts(
+    amount: u128,
+    psp22_reserve: u128,
+    a0_reserve: u128,
+    total_supply: u128,
+    fee: u128,
+    applied_fee: u128,
+) -> Option<CompoundAmounts> {
+    let psp22_amount_to_swap = get_swap_amount_for_deposit(amount, psp22_reserve, fee)?;
+
+    let swap =
+        get_swap_amounts(psp22_amount_to_swap, psp22_reserve, a0_reserve, fee, applied_fee)?;
+
+    let psp22_amount_to_provide = amount - psp22_amount_to_swap;
+
+    let shares = get_deposit_shares(
+        swap.amount_out,
+        psp22_amount_to_provide,
+        swap.new_reserve_out,
+        swap.new_reserve_in,
+        total_supply,
+    )?;
+
+    Some(CompoundAmounts {
+        psp22_amount_to_swap,
+        swap,
+        psp22_amount_to_provide,
+        shares,
+    })
+}
+
+/// Returns the percentage difference (scaled by `PRECISION`) between two
+/// values, relative to the lower one.
+pub fn percentage_difference(value1: u128, value2: u128) -> Option<u128> {
+    let (higher, lower) = if value1 > value2 {
+        (value1, value2)
+    } else {
+        (value2, value1)
+    };
+
+    if higher == lower {
+        return Some(0)
+    }
+
+    let difference = U256::from(higher - lower).checked_mul(U256::from(PRECISION))?;
+
+    to_balance(
+        difference
+            .checked_div(U256::from(lower))?
+            .checked_mul(U256::from(100))?,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ONE: u128 = PRECISION;
+
+    #[test]
+    fn discounted_fee_works() {
+        assert_eq!(discounted_fee(ONE, 0), ONE);
+        assert_eq!(discounted_fee(ONE, 5000), ONE / 2);
+        assert_eq!(discounted_fee(ONE, 10_000), 0);
+        assert_eq!(discounted_fee(3 * ONE, 2500), 3 * ONE - 3 * ONE / 4);
+    }
+
+    #[test]
+    fn discounted_fee_caps_discount_at_100_percent() {
+        assert_eq!(discounted_fee(ONE, u16::MAX), 0);
+    }
+
+    #[test]
+    fn default_panx_discount_bps_halves_fees_up_to_1_4_percent() {
+        assert_eq!(default_panx_discount_bps(0), 5000);
+        assert_eq!(default_panx_discount_bps(ONE), 5000);
+        assert_eq!(default_panx_discount_bps(14 * ONE / 10), 5000);
+    }
+
+    #[test]
+    fn default_panx_discount_bps_takes_1_percent_off_higher_fees() {
+        assert_eq!(default_panx_discount_bps(2 * ONE), 5000);
+        assert_eq!(default_panx_discount_bps(4 * ONE), 2500);
+        assert_eq!(default_panx_discount_bps(3 * ONE), 3333);
+
+        let fee = 15 * ONE / 10;
+        let charged = discounted_fee(fee, default_panx_discount_bps(fee));
+        assert!(charged.abs_diff(fee - ONE) <= fee / 10_000);
+    }
+
+    #[test]
+    fn panx_discount_bps_applies_the_highest_reached_tier() {
+        let tiers = [(1000, 2500), (5000, 7500)];
+
+        assert_eq!(panx_discount_bps(&tiers, 999, 200, 100), 0);
+        assert_eq!(panx_discount_bps(&tiers, 1000, 200, 100), 2500);
+        assert_eq!(panx_discount_bps(&tiers, 4999, 200, 100), 2500);
+        assert_eq!(panx_discount_bps(&tiers, 5000, 200, 100), 7500);
+        assert_eq!(panx_discount_bps(&[], 5000, 200, 100), 0);
+    }
+
+    #[test]
+    fn panx_discount_bps_ignores_expired_locks() {
+        let tiers = [(1000, 2500), (5000, 7500)];
+
+        assert_eq!(panx_discount_bps(&tiers, 5000, 200, 199), 7500);
+        assert_eq!(panx_discount_bps(&tiers, 5000, 200, 200), 0);
+        assert_eq!(panx_discount_bps(&tiers, 5000, 200, 300), 0);
+    }
+
+    #[test]
+    fn get_amount_out_without_fee_is_constant_product() {
+        let (reserve_in, reserve_out) = (1000 * ONE, 2000 * ONE);
+        let amount_out = get_amount_out(1000 * ONE, reserve_in, reserve_out, 0).unwrap();
+
+        assert_eq!(amount_out, 1000 * ONE);
+    }
+
+    #[test]
+    fn get_amount_out_reducts_the_lp_fee() {
+        let (reserve_in, reserve_out) = (1_000_000 * ONE, 1_000_000 * ONE);
+        let without_fee = get_amount_out(ONE, reserve_in, reserve_out, 0).unwrap();
+        let with_fee = get_amount_out(ONE, reserve_in, reserve_out, ONE).unwrap();
+
+        assert!(with_fee < without_fee);
+        // ~1% less for a negligible price impact
+        assert_eq!(with_fee / 10u128.pow(9), 989);
+    }
+
+    #[test]
+    fn get_amount_out_matches_the_pair_formula() {
+        let (amount_in, reserve_in, reserve_out, fee) =
+            (123_456_789u128, 9_876_543_210u128, 5_555_555_555u128, 1_500_000_000_000u128);
+        let amount_in_with_lp_fee = amount_in * (100 * ONE - fee) / ONE;
+        let expected =
+            amount_in_with_lp_fee * reserve_out / (reserve_in * 100 + amount_in_with_lp_fee);
+
+        assert_eq!(
+            get_amount_out(amount_in, reserve_in, reserve_out, fee),
+            Some(expected)
+        );
+    }
+
+    #[test]
+    fn get_amount_out_of_zero_is_zero() {
+        assert_eq!(get_amount_out(0, 1000, 1000, ONE), Some(0));
+    }
+
+    #[test]
+    fn get_amount_out_never_drains_the_reserve() {
+        let amount_out = get_amount_out(u128::MAX, 1, 1000, 0).unwrap();
+
+        assert!(amount_out < 1000);
+    }
+
+    #[test]
+    fn get_amount_out_fails_on_empty_pool() {
+        assert_eq!(get_amount_out(0, 0, 1000, ONE), None);
+    }
+
+    #[test]
+    fn get_amount_out_fails_on_fee_above_100_percent() {
+        assert_eq!(get_amount_out(10, 1000, 1000, 101 * ONE), None);
+    }
+
+    #[test]
+    fn get_amount_out_handles_large_reserves() {
+        let large = 10u128.pow(30);
+        let amount_out = get_amount_out(large, large, 2 * large, 0).unwrap();
+
+        assert_eq!(amount_out, large);
+    }
+
+    #[test]
+    fn get_amount_out_fails_on_overflow() {
+        assert_eq!(get_amount_out(u128::MAX, u128::MAX, u128::MAX, 0), None);
+    }
+
+    #[test]
+    fn get_amount_in_is_the_minimal_amount_for_amount_out() {
+        let (reserve_in, reserve_out) = (7_000_000 * ONE, 3_000_000 * ONE);
+
+        for fee in [0, ONE / 3, ONE, 2 * ONE + 7] {
+            for amount_out in [1, 1000, ONE, 12_345 * ONE, 2_999_999 * ONE] {
+                let amount_in = get_amount_in(amount_out, reserve_in, reserve_out, fee).unwrap();
+
+                assert!(get_amount_out(amount_in, reserve_in, reserve_out, fee).unwrap() >= amount_out);
+                assert!(
+                    get_amount_out(amount_in - 1, reserve_in, reserve_out, fee).unwrap()
+                        < amount_out
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn get_amount_in_fails_for_the_whole_reserve() {
+        assert_eq!(get_amount_in(1000, 1000, 1000, ONE), None);
+        assert_eq!(get_amount_in(1001, 1000, 1000, ONE), None);
+    }
+
+    #[test]
+    fn get_amount_out_after_swap_is_lower() {
+        let (amount_in, reserve_in, reserve_out) = (10_000 * ONE, 100_000 * ONE, 100_000 * ONE);
+        let amount_out = get_amount_out(amount_in, reserve_in, reserve_out, ONE).unwrap();
+        let amount_out_after_swap =
+            get_amount_out_after_swap(amount_in, reserve_in, reserve_out, ONE).unwrap();
+
+        assert!(amount_out_after_swap < amount_out);
+        assert_eq!(
+            amount_out_after_swap,
+            get_amount_out(amount_in, reserve_in + amount_in, reserve_out - amount_out, ONE)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn get_price_impact_grows_with_amount_in() {
+        let (reserve_in, reserve_out) = (100_000 * ONE, 100_000 * ONE);
+        let small = get_price_impact(ONE, reserve_in, reserve_out, ONE).unwrap();
+        let medium = get_price_impact(1000 * ONE, reserve_in, reserve_out, ONE).unwrap();
+        let large = get_price_impact(100_000 * ONE, reserve_in, reserve_out, ONE).unwrap();
+
+        assert!(small < medium);
+        assert!(medium < large);
+    }
+
+    #[test]
+    fn get_price_impact_of_a_pool_sized_swap_is_about_100_percent() {
+        let reserve = 100_000 * ONE;
+        let impact = get_price_impact(reserve, reserve, reserve, 0).unwrap();
+
+        assert_eq!(impact, 100 * ONE);
+    }
+
+    #[test]
+    fn get_price_impact_of_zero_is_zero() {
+        assert_eq!(get_price_impact(0, 1000, 1000, ONE), Some(0));
+    }
+
+    #[test]
+    fn get_traders_fee_works() {
+        assert_eq!(get_traders_fee(1000), Some(2));
+        assert_eq!(get_traders_fee(999), Some(1));
+        assert_eq!(get_traders_fee(0), Some(0));
+        assert_eq!(get_traders_fee(u128::MAX), Some(u128::MAX / 500));
+    }
+
+    #[test]
+    fn get_lp_fee_charges_whole_percents() {
+        assert_eq!(get_lp_fee(1000, ONE), Some(10));
+        assert_eq!(get_lp_fee(1000, 3 * ONE), Some(30));
+        assert_eq!(get_lp_fee(1000, ONE + ONE / 2), Some(10));
+        assert_eq!(get_lp_fee(1000, ONE / 2), Some(0));
+    }
+
+    #[test]
+    fn split_amount_out_adds_up() {
+        for amount_out in [0, 1, 999, 1000, 123_456_789, u128::MAX / 2] {
+            let (for_trader, traders_fee, lp_fee) = split_amount_out(amount_out, 2 * ONE).unwrap();
+
+            assert_eq!(for_trader + traders_fee + lp_fee, amount_out);
+            assert_eq!(traders_fee, get_traders_fee(amount_out).unwrap());
+            assert_eq!(lp_fee, get_lp_fee(amount_out, 2 * ONE).unwrap());
+        }
+    }
+
+    #[test]
+    fn split_amount_out_fails_when_fees_exceed_amount() {
+        assert_eq!(split_amount_out(1000, 100 * ONE), None);
+    }
+
+    #[test]
+    fn get_initial_shares_is_the_geometric_mean_minus_minimum_liquidity() {
+        assert_eq!(get_initial_shares(10 * ONE, 1000 * ONE), Some(100 * ONE - MINIMUM_LIQUIDITY));
+        assert_eq!(get_initial_shares(ONE, ONE), Some(ONE - MINIMUM_LIQUIDITY));
+        assert_eq!(get_initial_shares(2000, 2000), Some(1000));
+        assert_eq!(get_initial_shares(u128::MAX, u128::MAX), Some(u128::MAX - MINIMUM_LIQUIDITY));
+    }
+
+    #[test]
+    fn get_initial_shares_of_dust_is_zero() {
+        assert_eq!(get_initial_shares(1000, 1000), Some(0));
+        assert_eq!(get_initial_shares(1, 1), Some(0));
+        assert_eq!(get_initial_shares(0, ONE), Some(0));
+    }
+
+    #[test]
+    fn minimum_liquidity_keeps_share_price_bounded() {
+        // first depositor provides dust, then donates a large amount to the pool
+        let (a0_reserve, psp22_reserve) = (1001 + 1_000_000 * ONE, 1001 + 1_000_000 * ONE);
+        let total_supply = get_initial_shares(1001, 1001).unwrap() + MINIMUM_LIQUIDITY;
+
+        // the next depositor still gets shares for a deposit much smaller than the donation
+        let shares =
+            get_deposit_shares(1000 * ONE, 1000 * ONE, a0_reserve, psp22_reserve, total_supply)
+                .unwrap();
+
+        assert!(shares > 0);
+    }
+
+    #[test]
+    fn get_deposit_shares_takes_the_lower_side() {
+        let (a0_reserve, psp22_reserve, total_supply) = (100 * ONE, 1000 * ONE, 10_000 * ONE);
+
+        assert_eq!(
+            get_deposit_shares(10 * ONE, 100 * ONE, a0_reserve, psp22_reserve, total_supply),
+            Some(1000 * ONE)
+        );
+        assert_eq!(
+            get_deposit_shares(10 * ONE, 50 * ONE, a0_reserve, psp22_reserve, total_supply),
+            Some(500 * ONE)
+        );
+        assert_eq!(
+            get_deposit_shares(5 * ONE, 100 * ONE, a0_reserve, psp22_reserve, total_supply),
+            Some(500 * ONE)
+        );
+    }
+
+    #[test]
+    fn get_deposit_shares_fails_on_empty_reserve() {
+        assert_eq!(get_deposit_shares(1, 1, 0, 1, 1), None);
+        assert_eq!(get_deposit_shares(1, 1, 1, 0, 1), None);
+    }
+
+    #[test]
+    fn get_share_of_works() {
+        assert_eq!(get_share_of(25, 1000, 100), Some(250));
+        assert_eq!(get_share_of(1, 1, 3), Some(0));
+        assert_eq!(get_share_of(u128::MAX, u128::MAX, u128::MAX), Some(u128::MAX));
+        assert_eq!(get_share_of(1, 1, 0), None);
+    }
+
+    #[test]
+    fn withdrawing_all_shares_gives_the_whole_reserve() {
+        let (reserve, total_supply) = (123_456_789 * ONE, 987_654_321 * ONE);
+
+        assert_eq!(get_share_of(total_supply, reserve, total_supply), Some(reserve));
+    }
+
+    #[test]
+    fn get_deposit_amount_for_ratio_works() {
+        assert_eq!(get_deposit_amount_for_ratio(10, 100, 1000), Some(100));
+        assert_eq!(get_deposit_amount_for_ratio(10, 0, 1000), None);
+    }
+
+    #[test]
+    fn get_swap_amount_for_deposit_matches_post_swap_ratio() {
+        let (reserve_in, reserve_out) = (1_000_000 * ONE, 500_000 * ONE);
+
+        for fee in [0, ONE / 2, ONE, 3 * ONE] {
+            for amount in [ONE, 1000 * ONE, 100_000 * ONE] {
+                let amount_to_swap = get_swap_amount_for_deposit(amount, reserve_in, fee).unwrap();
+                let amount_out =
+                    get_amount_out(amount_to_swap, reserve_in, reserve_out, fee).unwrap();
+
+                // (amount - swapped) / amount out == new reserve in / new reserve out
+                let lhs = U256::from(amount - amount_to_swap) * U256::from(reserve_out - amount_out);
+                let rhs = U256::from(reserve_in + amount_to_swap) * U256::from(amount_out);
+                let difference = if lhs > rhs { lhs - rhs } else { rhs - lhs };
+
+                assert!(difference * U256::from(1000) <= rhs, "fee {fee} amount {amount}");
+            }
+        }
+    }
+
+    #[test]
+    fn get_swap_amount_for_deposit_is_about_half_without_fee_for_small_amounts() {
+        let amount_to_swap = get_swap_amount_for_deposit(ONE, 1_000_000_000 * ONE, 0).unwrap();
+
+        assert!(amount_to_swap.abs_diff(ONE / 2) <= 10u128.pow(6));
+    }
+
+    #[test]
+    fn get_swap_amount_for_deposit_fails_on_fee_of_100_percent() {
+        assert_eq!(get_swap_amount_for_deposit(ONE, ONE, 100 * ONE), None);
+    }
+
+    #[test]
+    fn get_swap_amounts_adds_up() {
+        let (reserve_in, reserve_out) = (1_000_000 * ONE, 500_000 * ONE);
+        let swap = get_swap_amounts(1000 * ONE, reserve_in, reserve_out, ONE, ONE / 2).unwrap();
+
+        assert_eq!(
+            swap.amount_out_before_fees,
+            get_amount_out(1000 * ONE, reserve_in, reserve_out, ONE / 2).unwrap()
+        );
+        assert_eq!(
+            swap.amount_out + swap.vault_fee_out + swap.lp_fee,
+            swap.amount_out_before_fees
+        );
+        assert_eq!(swap.vault_fee_in, 2 * ONE);
+        assert_eq!(swap.new_reserve_in, reserve_in + 1000 * ONE - 2 * ONE);
+        assert_eq!(
+            swap.new_reserve_out,
+            reserve_out - swap.amount_out - swap.vault_fee_out
+        );
+    }
+
+    #[test]
+    fn get_compound_amounts_provides_the_swap_output() {
+        let (psp22_reserve, a0_reserve, total_supply) =
+            (1_000_000 * ONE, 500_000 * ONE, 700_000 * ONE);
+        let amount = 1000 * ONE;
+        let compound =
+            get_compound_amounts(amount, psp22_reserve, a0_reserve, total_supply, ONE, ONE)
+                .unwrap();
+
+        assert_eq!(
+            compound.psp22_amount_to_swap,
+            get_swap_amount_for_deposit(amount, psp22_reserve, ONE).unwrap()
+        );
+        assert_eq!(
+            compound.psp22_amount_to_swap + compound.psp22_amount_to_provide,
+            amount
+        );
+        assert_eq!(
+            compound.shares,
+            get_deposit_shares(
+                compound.swap.amount_out,
+                compound.psp22_amount_to_provide,
+                compound.swap.new_reserve_out,
+                compound.swap.new_reserve_in,
+                total_supply,
+            )
+            .unwrap()
+        );
+
+        // the pool ends up with the rewards and the AZERO reserve minus the traders fees
+        assert_eq!(
+            compound.swap.new_reserve_in + compound.psp22_amount_to_provide,
+            psp22_reserve + amount - compound.swap.vault_fee_in
+        );
+        assert_eq!(
+            compound.swap.new_reserve_out + compound.swap.amount_out,
+            a0_reserve - compound.swap.vault_fee_out
+        );
+    }
+
+    #[test]
+    fn get_compound_amounts_charges_the_swap_fees() {
+        let (psp22_reserve, a0_reserve, total_supply) =
+            (1_000_000 * ONE, 500_000 * ONE, 700_000 * ONE);
+        let with_fees =
+            get_compound_amounts(1000 * ONE, psp22_reserve, a0_reserve, total_supply, ONE, ONE)
+                .unwrap();
+        let discounted =
+            get_compound_amounts(1000 * ONE, psp22_reserve, a0_reserve, total_supply, ONE, 0)
+                .unwrap();
+
+        assert!(with_fees.swap.vault_fee_in > 0);
+        assert!(with_fees.swap.vault_fee_out > 0);
+        assert!(with_fees.swap.lp_fee > 0);
+        // a PANX discount lowers the LP fee of the swap, so more AZERO is provided
+        assert!(discounted.swap.amount_out > with_fees.swap.amount_out);
+        assert!(discounted.shares >= with_fees.shares);
+    }
+
+    #[test]
+    fn percentage_difference_works() {
+        assert_eq!(percentage_difference(100, 100), Some(0));
+        assert_eq!(percentage_difference(110, 100), Some(10 * ONE));
+        assert_eq!(percentage_difference(100, 110), Some(10 * ONE));
+        assert_eq!(percentage_difference(150, 100), Some(50 * ONE));
+    }
+
+    #[test]
+    fn percentage_difference_from_zero_fails() {
+        assert_eq!(percentage_difference(0, 100), None);
+        assert_eq!(percentage_difference(0, 0), Some(0));
+    }
+}
@@ -0,0 +1,620 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[ink::contract]
+pub mod vesting_contract {
+
+    use ink::codegen::EmitEvent;
+    use ink::prelude::vec::Vec;
+    use ink::storage::Mapping;
+    use psp22::PSP22;
+
+    /// Vesting schedule of a beneficiary.
+    ///
+    /// Nothing vests before `start + cliff`, then the tokens vest linearly from `start`
+    /// until `start + duration`. A cliff equal to the duration makes a pure cliff schedule.
+    #[derive(Debug, PartialEq, Eq, Clone, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct VestingSchedule {
+        // Amount of tokens vested at the end of the schedule
+        pub total: Balance,
+        // Amount of tokens already released to the beneficiary
+        pub released: Balance,
+        // Timestamp the vesting starts at, in milliseconds
+        pub start: u64,
+        // Time after the start before which nothing vests, in milliseconds
+        pub cliff: u64,
+        // Time after the start at which everything vested, in milliseconds
+        pub duration: u64,
+        // Whether the owner can revoke the unvested tokens
+        pub revocable: bool,
+        // Whether the schedule was revoked, `total` is then the amount vested at revocation
+        pub revoked: bool,
+    }
+
+    impl VestingSchedule {
+        /// Returns the amount of tokens vested at `timestamp`.
+        pub fn vested_at(&self, timestamp: u64) -> Result<Balance, VestingErrors> {
+            if self.revoked || timestamp >= self.start.saturating_add(self.duration) {
+                return Ok(self.total);
+            }
+            if timestamp < self.start.saturating_add(self.cliff) {
+                return Ok(0);
+            }
+            let elapsed = (timestamp - self.start) as u128;
+            match self.total.checked_mul(elapsed) {
+                Some(result) => Ok(result / self.duration as u128),
+                None => Err(VestingErrors::Overflow),
+            }
+        }
+    }
+
+    #[ink(storage)]
+    pub struct VestingContract {
+        // PSP22 token (PANX) vested by the contract
+        token: AccountId,
+        // Account allowed to create and revoke schedules
+        owner: AccountId,
+        // Vesting schedule of each beneficiary
+        schedules: Mapping<AccountId, VestingSchedule>,
+        // Beneficiaries with a vesting schedule, by creation order of their schedule
+        beneficiaries: Mapping<u64, AccountId>,
+        // Number of beneficiaries with a vesting schedule
+        beneficiary_count: u64,
+    }
+
+    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum VestingErrors {
+        CallerNotOwner,   // Error code for owner only functions called by non owner
+        ScheduleExists,   // Error code for creating a second schedule for a beneficiary
+        NoSchedule,       // Error code for an account without a vesting schedule
+        InvalidSchedule,  // Error code for a zero amount, a zero duration or a cliff longer than the duration
+        NotRevocable,     // Error code for revoking a non revocable schedule
+        AlreadyRevoked,   // Error code for revoking a schedule twice
+        NothingToRelease, // Error code for releasing when no vested tokens are left
+        TransferFailed,   // Error code for a failed PSP22 transfer
+        Overflow,         // Error code for overflow
+    }
+
+    #[ink(event)]
+    pub struct ScheduleCreated {
+        #[ink(topic)]
+        beneficiary: AccountId, // Beneficiary of the schedule
+        total: Balance,  // Amount of tokens vested by the schedule
+        start: u64,      // Vesting start timestamp
+        cliff: u64,      // Cliff after the start
+        duration: u64,   // Vesting duration after the start
+        revocable: bool, // Whether the owner can revoke the schedule
+    }
+
+    #[ink(event)]
+    pub struct TokensReleased {
+        #[ink(topic)]
+        beneficiary: AccountId, // Beneficiary the tokens were released to
+        amount: Balance, // Amount of tokens released
+    }
+
+    #[ink(event)]
+    pub struct ScheduleRevoked {
+        #[ink(topic)]
+        beneficiary: AccountId, // Beneficiary of the revoked schedule
+        vested: Balance,   // Amount of tokens vested at revocation, still releasable by the beneficiary
+        refunded: Balance, // Amount of unvested tokens returned to the owner
+    }
+
+    #[ink(event)]
+    pub struct OwnershipTransferred {
+        #[ink(topic)]
+        previous_owner: AccountId, // Previous owner
+        #[ink(topic)]
+        new_owner: AccountId, // New owner
+    }
+
+    impl VestingContract {
+        #[ink(constructor)]
+        pub fn new(token: AccountId) -> Self {
+            Self {
+                token,
+                owner: Self::env().caller(),
+                schedules: Mapping::default(),
+                beneficiaries: Mapping::default(),
+                beneficiary_count: 0,
+            }
+        }
+
+        /// Creates the vesting schedule of `beneficiary`, funded with `amount` tokens
+        /// transferred from the owner, who has to approve the contract first.
+        #[ink(message)]
+        pub fn create_schedule(
+            &mut self,
+            beneficiary: AccountId,
+            amount: Balance,
+            start: u64,
+            cliff: u64,
+            duration: u64,
+            revocable: bool,
+        ) -> Result<(), VestingErrors> {
+            self.ensure_owner()?;
+            if self.schedules.contains(beneficiary) {
+                return Err(VestingErrors::ScheduleExists);
+            }
+            if amount == 0 || duration == 0 || cliff > duration {
+                return Err(VestingErrors::InvalidSchedule);
+            }
+
+            let beneficiary_count = match self.beneficiary_count.checked_add(1) {
+                Some(result) => result,
+                None => return Err(VestingErrors::Overflow),
+            };
+
+            let mut token: ink::contract_ref!(PSP22) = self.token.into();
+            if token
+                .transfer_from(self.owner, self.env().account_id(), amount, Vec::new())
+                .is_err()
+            {
+                return Err(VestingErrors::TransferFailed);
+            }
+
+            self.schedules.insert(
+                beneficiary,
+                &VestingSchedule {
+                    total: amount,
+                    released: 0,
+                    start,
+                    cliff,
+                    duration,
+                    revocable,
+                    revoked: false,
+                },
+            );
+            self.beneficiaries
+                .insert(self.beneficiary_count, &beneficiary);
+            self.beneficiary_count = beneficiary_count;
+
+            EmitEvent::<VestingContract>::emit_event(self.env(), ScheduleCreated {
+                beneficiary,
+                total: amount,
+                start,
+                cliff,
+                duration,
+                revocable,
+            });
+
+            Ok(())
+        }
+
+        /// Releases the vested tokens of the caller which were not released yet.
+        #[ink(message)]
+        pub fn release(&mut self) -> Result<Balance, VestingErrors> {
+            let beneficiary = self.env().caller();
+            let mut schedule = self.get_schedule_or_err(beneficiary)?;
+
+            let amount = self.releasable_amount(&schedule)?;
+            if amount == 0 {
+                return Err(VestingErrors::NothingToRelease);
+            }
+
+            schedule.released = match schedule.released.checked_add(amount) {
+                Some(result) => result,
+                None => return Err(VestingErrors::Overflow),
+            };
+            self.schedules.insert(beneficiary, &schedule);
+
+            let mut token: ink::contract_ref!(PSP22) = self.token.into();
+            if token.transfer(beneficiary, amount, Vec::new()).is_err() {
+                return Err(VestingErrors::TransferFailed);
+            }
+
+            EmitEvent::<VestingContract>::emit_event(self.env(), TokensReleased {
+                beneficiary,
+                amount,
+            });
+
+            Ok(amount)
+        }
+
+        /// Revokes a revocable schedule. Tokens vested so far stay releasable by the
+        /// beneficiary and the unvested tokens are returned to the owner.
+        #[ink(message)]
+        pub fn revoke(&mut self, beneficiary: AccountId) -> Result<(), VestingErrors> {
+            self.ensure_owner()?;
+            let mut schedule = self.get_schedule_or_err(beneficiary)?;
+            if !schedule.revocable {
+                return Err(VestingErrors::NotRevocable);
+            }
+            if schedule.revoked {
+                return Err(VestingErrors::AlreadyRevoked);
+            }
+
+            let vested = schedule.vested_at(self.get_current_timestamp())?;
+            let refunded = schedule.total - vested;
+
+            schedule.total = vested;
+            schedule.revoked = true;
+            self.schedules.insert(beneficiary, &schedule);
+
+            if refunded > 0 {
+                let mut token: ink::contract_ref!(PSP22) = self.token.into();
+                if token.transfer(self.owner, refunded, Vec::new()).is_err() {
+                    return Err(VestingErrors::TransferFailed);
+                }
+            }
+
+            EmitEvent::<VestingContract>::emit_event(self.env(), ScheduleRevoked {
+                beneficiary,
+                vested,
+                refunded,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn transfer_ownership(&mut self, new_owner: AccountId) -> Result<(), VestingErrors> {
+            self.ensure_owner()?;
+            let previous_owner = self.owner;
+            self.owner = new_owner;
+
+            EmitEvent::<VestingContract>::emit_event(self.env(), OwnershipTransferred {
+                previous_owner,
+                new_owner,
+            });
+
+            Ok(())
+        }
+
+        /// Returns the amount of tokens `account` can release now.
+        #[ink(message)]
+        pub fn releasable(&self, account: AccountId) -> Result<Balance, VestingErrors> {
+            let schedule = self.get_schedule_or_err(account)?;
+            self.releasable_amount(&schedule)
+        }
+
+        /// Returns the amount of tokens of `account` vested at `timestamp`, released or not.
+        #[ink(message)]
+        pub fn vested_amount(
+            &self,
+            account: AccountId,
+            timestamp: u64,
+        ) -> Result<Balance, VestingErrors> {
+            self.get_schedule_or_err(account)?.vested_at(timestamp)
+        }
+
+        #[ink(message)]
+        pub fn get_schedule(&self, account: AccountId) -> Option<VestingSchedule> {
+            self.schedules.get(account)
+        }
+
+        /// Returns the beneficiary of the `index`-th created schedule, starting at 0.
+        #[ink(message)]
+        pub fn get_beneficiary(&self, index: u64) -> Option<AccountId> {
+            self.beneficiaries.get(index)
+        }
+
+        #[ink(message)]
+        pub fn get_beneficiary_count(&self) -> u64 {
+            self.beneficiary_count
+        }
+
+        #[ink(message)]
+        pub fn get_token(&self) -> AccountId {
+            self.token
+        }
+
+        #[ink(message)]
+        pub fn get_owner(&self) -> AccountId {
+            self.owner
+        }
+
+        #[ink(message)]
+        pub fn get_current_timestamp(&self) -> u64 {
+            self.env().block_timestamp()
+        }
+
+        fn releasable_amount(&self, schedule: &VestingSchedule) -> Result<Balance, VestingErrors> {
+            let vested = schedule.vested_at(self.get_current_timestamp())?;
+            Ok(vested.saturating_sub(schedule.released))
+        }
+
+        fn get_schedule_or_err(
+            &self,
+            account: AccountId,
+        ) -> Result<VestingSchedule, VestingErrors> {
+            match self.schedules.get(account) {
+                Some(schedule) => Ok(schedule),
+                None => Err(VestingErrors::NoSchedule),
+            }
+        }
+
+        fn ensure_owner(&self) -> Result<(), VestingErrors> {
+            if self.env().caller() != self.owner {
+                return Err(VestingErrors::CallerNotOwner);
+            }
+            Ok(())
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink::env::{
+            test::{
+                default_accounts,
+                set_caller,
+            },
+            DefaultEnvironment,
+        };
+
+        fn schedule(cliff: u64) -> VestingSchedule {
+            VestingSchedule {
+                total: 1000,
+                released: 0,
+                start: 100,
+                cliff,
+                duration: 1000,
+                revocable: true,
+                revoked: false,
+            }
+        }
+
+        #[ink::test]
+        fn linear_schedule_vests_over_duration() {
+            let schedule = schedule(0);
+
+            assert_eq!(schedule.vested_at(0), Ok(0));
+            assert_eq!(schedule.vested_at(100), Ok(0));
+            assert_eq!(schedule.vested_at(350), Ok(250));
+            assert_eq!(schedule.vested_at(1099), Ok(999));
+            assert_eq!(schedule.vested_at(1100), Ok(1000));
+            assert_eq!(schedule.vested_at(u64::MAX), Ok(1000));
+        }
+
+        #[ink::test]
+        fn nothing_vests_before_cliff() {
+            let schedule = schedule(400);
+
+            assert_eq!(schedule.vested_at(499), Ok(0));
+            assert_eq!(schedule.vested_at(500), Ok(400));
+            assert_eq!(schedule.vested_at(600), Ok(500));
+
+            let cliff_only = VestingSchedule {
+                cliff: schedule.duration,
+                ..schedule
+            };
+            assert_eq!(cliff_only.vested_at(1099), Ok(0));
+            assert_eq!(cliff_only.vested_at(1100), Ok(1000));
+        }
+
+        #[ink::test]
+        fn revoked_schedule_is_fully_vested() {
+            let schedule = VestingSchedule {
+                total: 250,
+                revoked: true,
+                ..schedule(0)
+            };
+
+            assert_eq!(schedule.vested_at(0), Ok(250));
+        }
+
+        #[ink::test]
+        fn owner_only_functions_and_invalid_schedules_fail() {
+            let accounts = default_accounts::<DefaultEnvironment>();
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            let mut vesting = VestingContract::new(accounts.django);
+
+            assert_eq!(
+                vesting.create_schedule(accounts.bob, 0, 0, 0, 1000, false),
+                Err(VestingErrors::InvalidSchedule)
+            );
+            assert_eq!(
+                vesting.create_schedule(accounts.bob, 1000, 0, 0, 0, false),
+                Err(VestingErrors::InvalidSchedule)
+            );
+            assert_eq!(
+                vesting.create_schedule(accounts.bob, 1000, 0, 1001, 1000, false),
+                Err(VestingErrors::InvalidSchedule)
+            );
+            assert_eq!(
+                vesting.releasable(accounts.bob),
+                Err(VestingErrors::NoSchedule)
+            );
+            assert_eq!(vesting.revoke(accounts.bob), Err(VestingErrors::NoSchedule));
+            assert_eq!(vesting.get_beneficiary_count(), 0);
+            assert_eq!(vesting.get_beneficiary(0), None);
+
+            set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                vesting.create_schedule(accounts.bob, 1000, 0, 0, 1000, false),
+                Err(VestingErrors::CallerNotOwner)
+            );
+            assert_eq!(vesting.release(), Err(VestingErrors::NoSchedule));
+            assert_eq!(
+                vesting.transfer_ownership(accounts.bob),
+                Err(VestingErrors::CallerNotOwner)
+            );
+
+            set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(vesting.transfer_ownership(accounts.bob), Ok(()));
+            assert_eq!(vesting.get_owner(), accounts.bob);
+        }
+    }
+
+    /// ink! end-to-end (E2E) tests
+    ///
+    /// cargo test --features e2e-tests -- --nocapture
+    #[cfg(all(test, feature = "e2e-tests"))]
+    mod e2e_tests {
+        use super::*;
+        use ink_e2e::build_message;
+        use psp22_mintable::mintable_token::MintableTokenRef;
+        use std::{
+            thread,
+            time::Duration,
+        };
+
+        type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+        // Supply of the vested token and amount vested by the tested schedule
+        const SUPPLY: Balance = 10_000_000;
+        const AMOUNT: Balance = 1_000_000;
+        // Cliff and duration of the tested schedule, in milliseconds
+        const CLIFF: u64 = 3000;
+        const DURATION: u64 = 60_000;
+
+        fn get_alice_account_id() -> AccountId {
+            ink_e2e::account_id(ink_e2e::AccountKeyring::Alice)
+        }
+
+        fn get_bob_account_id() -> AccountId {
+            ink_e2e::account_id(ink_e2e::AccountKeyring::Bob)
+        }
+
+        /// Tests included in "schedules_release_and_revoke_tokens":
+        /// 1. create_schedule funded with tokens approved by the owner
+        /// 2. get_beneficiary and get_beneficiary_count
+        /// 3. release fails with NothingToRelease before the cliff and releases the vested tokens after it
+        /// 4. revoke returns the unvested tokens to the owner, the vested ones stay releasable
+        #[ink_e2e::test(additional_contracts = "../PSP22_token/mintable/Cargo.toml")]
+        async fn schedules_release_and_revoke_tokens(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let token_constructor = MintableTokenRef::new(SUPPLY, None, None, 12, None);
+            let token_acc_id = client
+                .instantiate("psp22_mintable", &ink_e2e::alice(), token_constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let vesting_constructor = VestingContractRef::new(token_acc_id);
+            let vesting_acc_id = client
+                .instantiate("vesting_contract", &ink_e2e::alice(), vesting_constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let approve = build_message::<MintableTokenRef>(token_acc_id.clone())
+                .call(|token| token.approve(vesting_acc_id, AMOUNT));
+            client
+                .call(&ink_e2e::alice(), approve, 0, None)
+                .await
+                .expect("approve failed");
+
+            // The schedule of bob starts now
+            let get_current_timestamp = build_message::<VestingContractRef>(vesting_acc_id.clone())
+                .call(|vesting| vesting.get_current_timestamp());
+            let start = client
+                .call_dry_run(&ink_e2e::alice(), &get_current_timestamp, 0, None)
+                .await
+                .return_value();
+
+            let create_schedule = build_message::<VestingContractRef>(vesting_acc_id.clone())
+                .call(|vesting| {
+                    vesting.create_schedule(
+                        get_bob_account_id(),
+                        AMOUNT,
+                        start,
+                        CLIFF,
+                        DURATION,
+                        true,
+                    )
+                });
+            let create_schedule_res = client
+                .call(&ink_e2e::alice(), create_schedule, 0, None)
+                .await
+                .expect("create_schedule failed");
+            assert_eq!(create_schedule_res.return_value(), Ok(()));
+
+            let get_beneficiary = build_message::<VestingContractRef>(vesting_acc_id.clone())
+                .call(|vesting| vesting.get_beneficiary(0));
+            let get_beneficiary_res = client
+                .call_dry_run(&ink_e2e::alice(), &get_beneficiary, 0, None)
+                .await;
+            assert_eq!(get_beneficiary_res.return_value(), Some(get_bob_account_id()));
+
+            let get_beneficiary_count = build_message::<VestingContractRef>(vesting_acc_id.clone())
+                .call(|vesting| vesting.get_beneficiary_count());
+            let get_beneficiary_count_res = client
+                .call_dry_run(&ink_e2e::alice(), &get_beneficiary_count, 0, None)
+                .await;
+            assert_eq!(get_beneficiary_count_res.return_value(), 1);
+
+            // Nothing vested before the cliff
+            let release = build_message::<VestingContractRef>(vesting_acc_id.clone())
+                .call(|vesting| vesting.release());
+            let release_res = client
+                .call(&ink_e2e::bob(), release, 0, None)
+                .await
+                .expect("release failed");
+            assert_eq!(
+                release_res.return_value(),
+                Err(VestingErrors::NothingToRelease)
+            );
+
+            thread::sleep(Duration::from_millis(CLIFF + 1000));
+            let release = build_message::<VestingContractRef>(vesting_acc_id.clone())
+                .call(|vesting| vesting.release());
+            let released = client
+                .call(&ink_e2e::bob(), release, 0, None)
+                .await
+                .expect("release failed")
+                .return_value()
+                .expect("nothing released after the cliff");
+            assert!(released > 0 && released < AMOUNT);
+
+            let revoke = build_message::<VestingContractRef>(vesting_acc_id.clone())
+                .call(|vesting| vesting.revoke(get_bob_account_id()));
+            let revoke_res = client
+                .call(&ink_e2e::alice(), revoke, 0, None)
+                .await
+                .expect("revoke failed");
+            assert_eq!(revoke_res.return_value(), Ok(()));
+
+            // The schedule total is now the amount vested at revocation
+            let get_schedule = build_message::<VestingContractRef>(vesting_acc_id.clone())
+                .call(|vesting| vesting.get_schedule(get_bob_account_id()));
+            let vested = client
+                .call_dry_run(&ink_e2e::alice(), &get_schedule, 0, None)
+                .await
+                .return_value()
+                .expect("schedule not found")
+                .total;
+            assert!(vested > released && vested < AMOUNT);
+
+            // Bob can still release the tokens vested until the revocation
+            let release = build_message::<VestingContractRef>(vesting_acc_id.clone())
+                .call(|vesting| vesting.release());
+            let release_res = client
+                .call(&ink_e2e::bob(), release, 0, None)
+                .await
+                .expect("release failed");
+            assert_eq!(release_res.return_value(), Ok(vested - released));
+
+            let bob_balance_of = build_message::<MintableTokenRef>(token_acc_id.clone())
+                .call(|token| token.balance_of(get_bob_account_id()));
+            let bob_balance_of_res = client
+                .call_dry_run(&ink_e2e::alice(), &bob_balance_of, 0, None)
+                .await;
+            assert_eq!(bob_balance_of_res.return_value(), vested);
+
+            // The unvested tokens were returned to alice
+            let alice_balance_of = build_message::<MintableTokenRef>(token_acc_id.clone())
+                .call(|token| token.balance_of(get_alice_account_id()));
+            let alice_balance_of_res = client
+                .call_dry_run(&ink_e2e::alice(), &alice_balance_of, 0, None)
+                .await;
+            assert_eq!(alice_balance_of_res.return_value(), SUPPLY - vested);
+
+            let vesting_balance_of = build_message::<MintableTokenRef>(token_acc_id.clone())
+                .call(|token| token.balance_of(vesting_acc_id));
+            let vesting_balance_of_res = client
+                .call_dry_run(&ink_e2e::alice(), &vesting_balance_of, 0, None)
+                .await;
+            assert_eq!(vesting_balance_of_res.return_value(), 0);
+
+            Ok(())
+        }
+    }
+}